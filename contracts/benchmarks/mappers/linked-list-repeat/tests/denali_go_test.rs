@@ -1,9 +0,0 @@
-#[test]
-fn linked_list_repeat_struct_go() {
-    dharitri_wasm_debug::denali_go("denali/linked_list_repeat_struct.scen.json");
-}
-
-#[test]
-fn linked_list_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/linked_list_repeat.scen.json");
-}
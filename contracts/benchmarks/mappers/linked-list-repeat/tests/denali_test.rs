@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn linked_list_repeat_struct_rs() {
-    dharitri_wasm_debug::denali_rs("denali/linked_list_repeat_struct.scen.json", world());
-}
-
-#[test]
-fn linked_list_repeat_rs() {
-    dharitri_wasm_debug::denali_rs("denali/linked_list_repeat.scen.json", world());
-}
+denali_tests!("denali", world);
@@ -1,9 +0,0 @@
-#[test]
-fn map_repeat_struct_go() {
-    dharitri_wasm_debug::denali_go("denali/map_repeat_struct.scen.json");
-}
-
-#[test]
-fn map_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/map_repeat.scen.json");
-}
@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn map_repeat_struct_rs() {
-    dharitri_wasm_debug::denali_rs("denali/map_repeat_struct.scen.json", world());
-}
-
-#[test]
-fn map_repeat_rs() {
-    dharitri_wasm_debug::denali_rs("denali/map_repeat.scen.json", world());
-}
+denali_tests!("denali", world);
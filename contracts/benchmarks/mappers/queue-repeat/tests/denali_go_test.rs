@@ -1,4 +0,0 @@
-#[test]
-fn queue_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/queue_repeat.scen.json");
-}
@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn queue_repeat_struct_rs() {
-    dharitri_wasm_debug::denali_rs("denali/queue_repeat_struct.scen.json", world());
-}
-
-#[test]
-fn queue_repeat_rs() {
-    dharitri_wasm_debug::denali_rs("denali/queue_repeat.scen.json", world());
-}
+denali_tests!("denali", world, exclude_go: ["queue_repeat_struct"]);
@@ -1,9 +0,0 @@
-#[test]
-fn set_repeat_struct_go() {
-    dharitri_wasm_debug::denali_go("denali/set_repeat_struct.scen.json");
-}
-
-#[test]
-fn set_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/set_repeat.scen.json");
-}
@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn set_repeat_struct_rs() {
-    dharitri_wasm_debug::denali_rs("denali/set_repeat_struct.scen.json", world());
-}
-
-#[test]
-fn set_repeat_rs() {
-    dharitri_wasm_debug::denali_rs("denali/set_repeat.scen.json", world());
-}
+denali_tests!("denali", world);
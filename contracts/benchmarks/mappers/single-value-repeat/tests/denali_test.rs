@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn single_value_repeat_struct_rs() {
-    dharitri_wasm_debug::denali_rs("denali/single_value_repeat_struct.scen.json", world());
-}
-
-#[test]
-fn single_value_repeat_rs() {
-    dharitri_wasm_debug::denali_rs("denali/single_value_repeat.scen.json", world());
-}
+denali_tests!("denali", world);
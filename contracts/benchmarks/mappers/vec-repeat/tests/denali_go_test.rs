@@ -1,9 +0,0 @@
-#[test]
-fn vec_repeat_struct_go() {
-    dharitri_wasm_debug::denali_go("denali/vec_repeat_struct.scen.json");
-}
-
-#[test]
-fn vec_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/vec_repeat.scen.json");
-}
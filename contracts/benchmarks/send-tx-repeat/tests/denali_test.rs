@@ -0,0 +1,12 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/send-tx-repeat.wasm",
+        Box::new(|context| Box::new(send_tx_repeat::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!("denali", world);
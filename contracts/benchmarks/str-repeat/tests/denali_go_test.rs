@@ -1,4 +0,0 @@
-#[test]
-fn str_repeat_go() {
-    dharitri_wasm_debug::denali_go("denali/str_repeat.scen.json");
-}
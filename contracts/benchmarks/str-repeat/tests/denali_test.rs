@@ -9,7 +9,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn test_str_repeat_denali_rs() {
-    dharitri_wasm_debug::denali_rs("denali/str_repeat.scen.json", world());
-}
+denali_tests!("denali", world);
@@ -1,4 +0,0 @@
-#[test]
-fn adder_go() {
-    dharitri_wasm_debug::denali_go("denali/adder.scen.json");
-}
@@ -12,7 +12,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn adder_rs() {
-    dharitri_wasm_debug::denali_rs("denali/adder.scen.json", world());
-}
+denali_tests!("denali", world, wasm: ["adder"]);
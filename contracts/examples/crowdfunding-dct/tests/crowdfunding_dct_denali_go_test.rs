@@ -1,59 +0,0 @@
-#[test]
-fn crowdfunding_claim_failed_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-claim-failed.scen.json");
-}
-
-#[test]
-fn crowdfunding_claim_successful_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-claim-successful.scen.json");
-}
-
-#[test]
-fn crowdfunding_claim_too_early_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-claim-too-early.scen.json");
-}
-
-#[test]
-fn crowdfunding_fund_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-fund.scen.json");
-}
-
-#[test]
-fn crowdfunding_fund_too_late_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-fund-too-late.scen.json");
-}
-
-#[test]
-fn crowdfunding_init_go() {
-    dharitri_wasm_debug::denali_go("denali/crowdfunding-init.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_claim_failed_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-claim-failed.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_claim_successful_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-claim-successful.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_claim_too_early_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-claim-too-early.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_fund_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-fund.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_fund_too_late_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-fund-too-late.scen.json");
-}
-
-#[test]
-fn moax_crowdfunding_init_go() {
-    dharitri_wasm_debug::denali_go("denali/moax-crowdfunding-init.scen.json");
-}
@@ -0,0 +1,23 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/crowdfunding-dct");
+
+    blockchain.register_contract(
+        "file:output/crowdfunding-dct.wasm",
+        Box::new(|context| Box::new(crowdfunding_dct::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    wasm: [
+        "crowdfunding-init",
+        "crowdfunding-fund",
+        "crowdfunding-claim-successful"
+    ]
+);
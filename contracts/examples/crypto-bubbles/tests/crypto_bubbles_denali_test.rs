@@ -0,0 +1,15 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/crypto-bubbles");
+
+    blockchain.register_contract(
+        "file:output/crypto-bubbles.wasm",
+        Box::new(|context| Box::new(crypto_bubbles::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!("denali", world);
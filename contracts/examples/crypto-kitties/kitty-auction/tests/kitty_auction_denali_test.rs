@@ -0,0 +1,18 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+
+    blockchain.register_contract(
+        "file:../kitty-ownership/output/kitty-ownership.wasm",
+        Box::new(|context| Box::new(kitty_ownership::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:output/kitty-auction.wasm",
+        Box::new(|context| Box::new(kitty_auction::contract_obj(context))),
+    );
+
+    blockchain
+}
+denali_tests!("denali", world);
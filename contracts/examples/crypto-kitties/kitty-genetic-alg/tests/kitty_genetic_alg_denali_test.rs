@@ -10,12 +10,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn generate_kitty_genes_rs() {
-    dharitri_wasm_debug::denali_rs("denali/generate-kitty-genes.scen.json", world());
-}
-
-#[test]
-fn init_rs() {
-    dharitri_wasm_debug::denali_rs("denali/init.scen.json", world());
-}
+denali_tests!("denali", world);
@@ -0,0 +1,19 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+
+    blockchain.register_contract(
+        "file:../kitty-genetic-alg/output/kitty-genetic-alg.wasm",
+        Box::new(|context| Box::new(kitty_genetic_alg::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:output/kitty-ownership.wasm",
+        Box::new(|context| Box::new(kitty_ownership::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!("denali", world);
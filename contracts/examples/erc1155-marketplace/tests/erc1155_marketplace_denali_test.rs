@@ -0,0 +1,19 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+#[allow(dead_code)]
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/erc1155-marketplace.wasm",
+        Box::new(|context| Box::new(erc1155_marketplace::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:../erc1155/output/erc1155.wasm",
+        Box::new(|context| Box::new(erc1155::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!("denali", world);
@@ -0,0 +1,18 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/erc1155.wasm",
+        Box::new(|context| Box::new(erc1155::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:../erc1155-user-mock/output/erc1155-user-mock.wasm",
+        Box::new(|context| Box::new(erc1155_user_mock::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!("denali", world);
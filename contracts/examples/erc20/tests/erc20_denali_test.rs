@@ -0,0 +1,15 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/erc20");
+
+    blockchain.register_contract(
+        "file:output/erc20.wasm",
+        Box::new(|context| Box::new(erc20::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!("denali", world);
@@ -0,0 +1,13 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/erc721.wasm",
+        Box::new(|context| Box::new(erc721::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!("denali", world);
@@ -0,0 +1,26 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/lottery-erc20");
+
+    blockchain.register_contract(
+        "file:output/lottery-erc20.wasm",
+        Box::new(|context| Box::new(lottery_erc20::contract_obj(context))),
+    );
+
+    blockchain.register_contract(
+        "file:../erc20/output/erc20.wasm",
+        Box::new(|context| Box::new(erc20::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // TODO: enable after rust-denali supports chaining async calls
+    exclude: ["determine-winner-split-prize-pool"]
+);
@@ -1,9 +0,0 @@
-#[test]
-fn unwrap_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/unwrap_moax.scen.json");
-}
-
-#[test]
-fn wrap_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/wrap_moax.scen.json");
-}
@@ -11,12 +11,4 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
-#[test]
-fn unwrap_moax_rs() {
-    dharitri_wasm_debug::denali_rs("denali/unwrap_moax.scen.json", world());
-}
-
-#[test]
-fn wrap_moax_rs() {
-    dharitri_wasm_debug::denali_rs("denali/wrap_moax.scen.json", world());
-}
+denali_tests!("denali", world);
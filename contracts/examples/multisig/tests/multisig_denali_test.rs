@@ -0,0 +1,37 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/multisig");
+
+    blockchain.register_contract(
+        "file:output/multisig.wasm",
+        Box::new(|context| Box::new(multisig::contract_obj(context))),
+    );
+
+    blockchain.register_contract(
+        "file:test-contracts/adder.wasm",
+        Box::new(|context| Box::new(adder::contract_obj(context))),
+    );
+
+    blockchain.register_contract(
+        "file:test-contracts/factorial.wasm",
+        Box::new(|context| Box::new(factorial::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // the upgrade tests upgrade a contract deployed with code metadata 0x0000, which is not upgradeable
+    exclude_rs: [
+        "call_other_shard-1",
+        "call_other_shard-2",
+        "upgrade",
+        "upgrade_from_source"
+    ],
+    // TODO: investigate gas issue
+    exclude_go: ["sendDct"]
+);
@@ -0,0 +1,22 @@
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/ping-pong-moax");
+
+    blockchain.register_contract(
+        "file:output/ping-pong-moax.wasm",
+        Box::new(|context| Box::new(ping_pong_moax::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // interrupted by running out of gas, which denali-rs does not model
+    exclude_rs: [
+        "ping-pong-call-pong-all-interrupted-1",
+        "ping-pong-call-pong-all-interrupted-2"
+    ]
+);
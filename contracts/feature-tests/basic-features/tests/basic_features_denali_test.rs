@@ -0,0 +1,29 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(
+        "file:output/basic-features.wasm",
+        Box::new(|context| Box::new(basic_features::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // needs the v3 gas schedule, which is not bundled,
+    // run with --ignored and DENALI_GAS_SCHEDULE_DIR pointing to the node gas schedule files
+    exclude_rs: ["out_of_gas"],
+    exclude_go: [
+        // TODO: enable after new VM release is out
+        "crypto_keccak256",
+        "crypto_sha256",
+        // not covered by the Go VM tests yet
+        "managed_address_array",
+        "managed_address_managed_buffer"
+    ]
+);
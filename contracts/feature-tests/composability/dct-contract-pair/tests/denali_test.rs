@@ -0,0 +1,23 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:first-contract/output/first-contract.wasm",
+        Box::new(|context| Box::new(first_contract::contract_obj(context))),
+    );
+
+    blockchain.register_contract(
+        "file:second-contract/output/second-contract.wasm",
+        Box::new(|context| Box::new(second_contract::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // TODO: implement DCTTransfer + async call
+    exclude: ["reject_transfer"]
+);
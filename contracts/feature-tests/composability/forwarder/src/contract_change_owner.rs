@@ -19,6 +19,14 @@ pub trait ChangeOwnerModule {
         self.get_owner_of_vault_contract(child_sc_address)
     }
 
+    #[endpoint(claimDeveloperRewards)]
+    fn claim_developer_rewards(&self, child_sc_address: ManagedAddress) {
+        let () = self
+            .send()
+            .claim_developer_rewards(child_sc_address)
+            .execute_on_dest_context();
+    }
+
     fn get_owner_of_vault_contract(&self, address: ManagedAddress) -> ManagedAddress {
         self.vault_proxy()
             .contract(address)
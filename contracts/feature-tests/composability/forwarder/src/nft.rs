@@ -230,6 +230,23 @@ pub trait ForwarderNftModule: storage::ForwarderStorageModule {
             .dct_local_burn(&token_identifier, nonce, &amount);
     }
 
+    #[endpoint]
+    fn nft_update_attributes(&self, token_identifier: TokenIdentifier, nonce: u64, color: Color) {
+        self.send()
+            .dct_nft_update_attributes(&token_identifier, nonce, &color);
+    }
+
+    #[endpoint]
+    fn nft_add_uris(
+        &self,
+        token_identifier: TokenIdentifier,
+        nonce: u64,
+        #[var_args] uris: ManagedVarArgs<ManagedBuffer>,
+    ) {
+        self.send()
+            .dct_nft_add_uris(&token_identifier, nonce, &uris.to_vec());
+    }
+
     #[endpoint]
     fn transfer_nft_via_async_call(
         &self,
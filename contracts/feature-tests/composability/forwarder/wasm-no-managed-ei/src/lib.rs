@@ -13,6 +13,7 @@ dharitri_wasm_node::wasm_endpoints! {
         callback_data
         callback_data_at_index
         changeOwnerAddress
+        claimDeveloperRewards
         clear_callback_data
         create_and_send
         deploy_contract
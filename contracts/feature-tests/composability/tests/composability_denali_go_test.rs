@@ -1,413 +0,0 @@
-#[test]
-fn forw_raw_async_accept_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_async_accept_moax.scen.json");
-}
-
-#[test]
-fn forw_raw_async_accept_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_async_accept_dct.scen.json");
-}
-
-#[test]
-fn forwarder_get_dct_local_roles_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_get_dct_local_roles.scen.json");
-}
-
-#[test]
-fn forw_raw_async_echo_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_async_echo.scen.json");
-}
-
-#[test]
-fn forw_raw_async_send_and_retrieve_multi_transfer_funds_go() {
-    dharitri_wasm_debug::denali_go(
-        "denali/forw_raw_async_send_and_retrieve_multi_transfer_funds.scen.json",
-    );
-}
-
-#[test]
-fn forw_raw_builtin_nft_local_mint_via_async_call_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_builtin_nft_local_mint_via_async_call.scen.json");
-}
-
-#[test]
-fn forw_raw_builtin_nft_local_mint_via_sync_call_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_builtin_nft_local_mint_via_sync_call.scen.json");
-}
-
-#[test]
-fn forw_raw_call_async_retrieve_multi_transfer_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_call_async_retrieve_multi_transfer.scen.json");
-}
-
-#[test]
-fn forw_raw_contract_deploy_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_contract_deploy.scen.json");
-}
-
-#[test]
-fn forw_raw_contract_upgrade_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_contract_upgrade.scen.json");
-}
-
-#[test]
-fn forw_raw_contract_upgrade_self_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_contract_upgrade_self.scen.json");
-}
-
-#[test]
-fn forw_raw_direct_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_direct_moax.scen.json");
-}
-
-#[test]
-fn forw_raw_direct_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_direct_dct.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_echo_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_echo.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_echo_caller_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_echo_caller.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_moax.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_readonly_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_readonly.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_same_context_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_same_context.scen.json");
-}
-
-#[test]
-fn forw_raw_sync_same_context_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_sync_same_context_moax.scen.json");
-}
-
-#[test]
-fn forw_raw_transf_exec_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forw_raw_transf_exec_moax.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_add_quantity_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_add_quantity.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_burn_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_burn.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_create_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_create.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_create_by_caller_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_create_by_caller.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_local_burn_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_local_burn.scen.json");
-}
-
-#[test]
-fn forwarder_builtin_nft_local_mint_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_builtin_nft_local_mint.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_accept_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_accept_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_accept_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_accept_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_accept_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_accept_nft.scen.json");
-}
-
-#[test]
-fn forwarder_validate_token_identifier_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_validate_token_identifier.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_multi_transfer_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_multi_transfer.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_retrieve_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_retrieve_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_retrieve_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_retrieve_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_async_retrieve_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_async_retrieve_nft.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_multi_transfer_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_multi_transfer.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_nft.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_then_read_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_then_read_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_then_read_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_then_read_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_accept_then_read_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_then_read_nft.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_retrieve_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_retrieve_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_retrieve_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_retrieve_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_sync_retrieve_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_retrieve_nft.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_moax.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_moax_twice_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_moax_twice.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_dct.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_dct_twice_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_dct_twice.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_nft_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_nft.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_return_values_go() {
-    dharitri_wasm_debug::denali_go(
-        "denali/forwarder_call_transf_exec_accept_return_values.scen.json",
-    );
-}
-
-#[test]
-fn forwarder_call_transf_exec_accept_sft_twice_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_accept_sft_twice.scen.json");
-}
-
-#[test]
-fn forwarder_call_transf_exec_multi_transfer_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_call_transf_exec_multi_transfer_dct.scen.json");
-}
-
-#[test]
-fn forwarder_contract_change_owner_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_contract_change_owner.scen.json");
-}
-
-#[test]
-fn forwarder_contract_deploy_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_contract_deploy.scen.json");
-}
-
-#[test]
-fn forwarder_contract_upgrade_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_contract_upgrade.scen.json");
-}
-
-#[test]
-fn forwarder_nft_create_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_create.scen.json");
-}
-
-#[test]
-fn forwarder_nft_create_and_send_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_create_and_send.scen.json");
-}
-
-#[test]
-fn forwarder_nft_current_nonce_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_current_nonce.scen.json");
-}
-
-#[test]
-fn forwarder_nft_decode_complex_attributes_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_decode_complex_attributes.scen.json");
-}
-
-#[test]
-fn forwarder_nft_transfer_async_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_transfer_async.scen.json");
-}
-
-#[test]
-fn forwarder_nft_transfer_exec_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_nft_transfer_exec.scen.json");
-}
-
-#[test]
-fn forwarder_no_endpoint_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_no_endpoint.scen.json");
-}
-
-#[test]
-fn forwarder_send_dct_multi_transfer_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_send_dct_multi_transfer.scen.json");
-}
-
-#[test]
-fn forwarder_send_twice_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_send_twice_moax.scen.json");
-}
-
-#[test]
-fn forwarder_send_twice_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_send_twice_dct.scen.json");
-}
-
-#[test]
-fn forwarder_sync_echo_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_sync_echo.scen.json");
-}
-
-#[test]
-fn forwarder_sync_echo_range_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_sync_echo_range.scen.json");
-}
-
-#[test]
-fn forwarder_tranfer_dct_with_fees_go() {
-    dharitri_wasm_debug::denali_go("denali/forwarder_tranfer_dct_with_fees.scen.json");
-}
-
-#[test]
-fn proxy_test_init_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_init.scen.json");
-}
-
-#[test]
-fn proxy_test_message_othershard_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_message_otherShard.scen.json");
-}
-
-#[test]
-fn proxy_test_message_othershard_callback_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_message_otherShard_callback.scen.json");
-}
-
-#[test]
-fn proxy_test_message_sameshard_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_message_sameShard.scen.json");
-}
-
-#[test]
-fn proxy_test_message_sameshard_callback_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_message_sameShard_callback.scen.json");
-}
-
-#[test]
-fn proxy_test_payment_othershard_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_payment_otherShard.scen.json");
-}
-
-#[test]
-fn proxy_test_payment_othershard_callback_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_payment_otherShard_callback.scen.json");
-}
-
-#[test]
-fn proxy_test_payment_sameshard_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_payment_sameShard.scen.json");
-}
-
-#[test]
-fn proxy_test_payment_sameshard_callback_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_payment_sameShard_callback.scen.json");
-}
-
-#[test]
-fn proxy_test_upgrade_go() {
-    dharitri_wasm_debug::denali_go("denali/proxy_test_upgrade.scen.json");
-}
-
-#[test]
-fn recursive_caller_moax_1_go() {
-    dharitri_wasm_debug::denali_go("denali/recursive_caller_moax_1.scen.json");
-}
-
-#[test]
-fn recursive_caller_dct_1_go() {
-    dharitri_wasm_debug::denali_go("denali/recursive_caller_dct_1.scen.json");
-}
-
-#[test]
-fn send_moax_go() {
-    dharitri_wasm_debug::denali_go("denali/send_moax.scen.json");
-}
-
-#[test]
-fn send_dct_go() {
-    dharitri_wasm_debug::denali_go("denali/send_dct.scen.json");
-}
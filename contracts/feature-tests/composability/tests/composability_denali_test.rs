@@ -0,0 +1,168 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(
+        "file:forwarder/output/forwarder.wasm",
+        Box::new(|context| Box::new(forwarder::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:forwarder-raw/output/forwarder-raw.wasm",
+        Box::new(|context| Box::new(forwarder_raw::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:proxy-test-first/output/proxy-test-first.wasm",
+        Box::new(|context| Box::new(proxy_test_first::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:proxy-test-second/output/proxy-test-second.wasm",
+        Box::new(|context| Box::new(proxy_test_second::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:recursive-caller/output/recursive-caller.wasm",
+        Box::new(|context| Box::new(recursive_caller::contract_obj(context))),
+    );
+    blockchain.register_contract(
+        "file:vault/output/vault.wasm",
+        Box::new(|context| Box::new(vault::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!(
+    "denali",
+    world,
+    // the *_upgrade tests upgrade contracts deployed with CodeMetadata::DEFAULT, which is not upgradeable
+    exclude_rs: [
+        "forw_raw_contract_upgrade",
+        "forw_raw_sync_echo_caller",
+        "forw_raw_sync_same_context",
+        "forw_raw_sync_same_context_moax",
+        "forwarder_builtin_nft_create_by_caller",
+        "forwarder_contract_upgrade",
+        "proxy_test_upgrade"
+    ],
+    exclude_go: ["forw_raw_async_reject_dct"]
+);
+
+#[test]
+fn code_metadata_rs() {
+    dharitri_wasm_debug::denali_rs("denali-unsupported/code_metadata.scen.json", world());
+}
+
+#[test]
+fn dct_system_sc_issue_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/dct_system_sc_issue.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn dct_system_sc_freeze_wipe_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/dct_system_sc_freeze_wipe.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn dct_system_sc_issue_properties_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/dct_system_sc_issue_properties.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn dct_system_sc_pause_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/dct_system_sc_pause.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn dct_token_properties_transfers_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/dct_token_properties_transfers.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn forw_raw_async_callback_fail_moax_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/forw_raw_async_callback_fail_moax.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn forwarder_call_async_retrieve_moax_cross_shard_rs() {
+    let mut world = world();
+    world.set_num_shards(2);
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/forwarder_call_async_retrieve_moax_cross_shard.scen.json",
+        world,
+    );
+}
+
+#[test]
+fn forwarder_call_sync_cross_shard_rs() {
+    let mut world = world();
+    world.set_num_shards(2);
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/forwarder_call_sync_cross_shard.scen.json",
+        world,
+    );
+}
+
+#[test]
+fn recursive_caller_moax_2_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/recursive_caller_moax_2.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn recursive_caller_moax_x_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/recursive_caller_moax_x.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn recursive_caller_dct_2_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/recursive_caller_dct_2.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn recursive_caller_dct_x_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali-unsupported/recursive_caller_dct_x.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn tx_fees_rs() {
+    let mut world = world();
+    world.set_tx_fee_settlement(true);
+    dharitri_wasm_debug::denali_rs("denali-unsupported/tx_fees.scen.json", world);
+}
+
+#[test]
+fn developer_rewards_rs() {
+    let mut world = world();
+    world.set_tx_fee_settlement(true);
+    dharitri_wasm_debug::denali_rs("denali-unsupported/developer_rewards.scen.json", world);
+}
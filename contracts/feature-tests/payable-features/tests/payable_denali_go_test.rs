@@ -1,64 +0,0 @@
-#[test]
-fn call_value_check_go() {
-    dharitri_wasm_debug::denali_go("denali/call-value-check.scen.json");
-}
-
-#[test]
-fn payable_any_1_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_any_1.scen.json");
-}
-
-#[test]
-fn payable_any_2_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_any_2.scen.json");
-}
-
-#[test]
-fn payable_any_3_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_any_3.scen.json");
-}
-
-#[test]
-fn payable_any_4_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_any_4.scen.json");
-}
-
-#[test]
-fn payable_moax_1_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_moax_1.scen.json");
-}
-
-#[test]
-fn payable_moax_2_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_moax_2.scen.json");
-}
-
-#[test]
-fn payable_moax_3_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_moax_3.scen.json");
-}
-
-#[test]
-fn payable_moax_4_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_moax_4.scen.json");
-}
-
-#[test]
-fn payable_token_1_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_token_1.scen.json");
-}
-
-#[test]
-fn payable_token_2_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_token_2.scen.json");
-}
-
-#[test]
-fn payable_token_3_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_token_3.scen.json");
-}
-
-#[test]
-fn payable_token_4_go() {
-    dharitri_wasm_debug::denali_go("denali/payable_token_4.scen.json");
-}
@@ -0,0 +1,14 @@
+use dharitri_wasm::*;
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/payable-features");
+    blockchain.register_contract(
+        "file:output/payable-features.wasm",
+        Box::new(|context| Box::new(payable_features::contract_obj(context))),
+    );
+    blockchain
+}
+
+denali_tests!("denali", world, exclude_go: ["payable_multiple"]);
@@ -27,6 +27,28 @@ pub trait RustTestingFrameworkTester {
         Ok(first + second)
     }
 
+    #[endpoint]
+    fn add_value_owner_only(&self, value: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let owner = self.blockchain().get_owner_address();
+        if caller != owner {
+            self.raw_vm_api().signal_error(b"not owner");
+        }
+
+        self.total_value().update(|val| *val += value);
+    }
+
+    #[endpoint]
+    fn print_values(&self, label: ManagedBuffer, value: BigUint) -> SCResult<()> {
+        self.print().print_managed_buffer(&label);
+        self.print().print_biguint(&value);
+        self.print().print_address(&self.blockchain().get_caller());
+        self.print()
+            .print_fmt(format_args!("non-zero: {}", value > 0u32));
+        require!(value > 0u32, "Non-zero required");
+        Ok(())
+    }
+
     #[endpoint]
     fn get_caller_legacy(&self) -> Address {
         self.blockchain().get_caller_legacy()
@@ -123,6 +145,26 @@ pub trait RustTestingFrameworkTester {
         )
     }
 
+    #[endpoint]
+    fn create_nft_with_metadata(
+        &self,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+        attributes: NftDummyAttributes,
+        royalties: BigUint,
+        uris: ManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        self.send().dct_nft_create(
+            &token_id,
+            &amount,
+            &ManagedBuffer::new(),
+            &royalties,
+            &ManagedBuffer::new(),
+            &attributes,
+            &uris,
+        )
+    }
+
     #[endpoint]
     fn get_block_epoch(&self) -> u64 {
         self.blockchain().get_block_epoch()
@@ -176,6 +218,7 @@ pub trait RustTestingFrameworkTester {
         let caller = self.blockchain().get_caller();
 
         self.total_value().update(|val| *val += &value);
+        self.value_added_event(&caller, &value);
         self.value_per_caller(&caller).update(|val| *val += value);
     }
 
@@ -183,6 +226,9 @@ pub trait RustTestingFrameworkTester {
         self.total_value().get()
     }
 
+    #[event("valueAdded")]
+    fn value_added_event(&self, #[indexed] caller: &ManagedAddress, value: &BigUint);
+
     #[storage_mapper("totalValue")]
     fn total_value(&self) -> SingleValueMapper<BigUint>;
 
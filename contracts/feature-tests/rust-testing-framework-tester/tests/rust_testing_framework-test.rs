@@ -1,14 +1,23 @@
-use dharitri_wasm::types::{
-    BigUint, DctLocalRole, DctTokenPayment, ManagedAddress, SCResult, TokenIdentifier,
+use dharitri_wasm::{
+    api::SendApi,
+    contract_base::ContractBase,
+    types::{
+        Address, BigUint, DctLocalRole, DctTokenPayment, EllipticCurve, ManagedAddress,
+        ManagedArgBuffer, ManagedBuffer, ManagedVec, MessageHashType, SCResult, TokenIdentifier,
+    },
 };
 use dharitri_wasm_debug::{
-    assert_sc_error, managed_address, managed_biguint, managed_token_id, rust_biguint,
-    testing_framework::*, tx_mock::TxInputDCT,
+    address_hex, assert_sc_error, managed_address, managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::*,
+    tx_mock::TxInputDCT,
+    world_mock::{DctTokenProperties, GasSchedule, DHARITRI_API_COST},
+    DebugApi,
 };
 use rust_testing_framework_tester::*;
 
 const TEST_OUTPUT_PATH: &'static str = "test.scen.json";
 const TEST_MULTIPLE_SC_OUTPUT_PATH: &'static str = "test_multiple_sc.scen.json";
+const TEST_SNAPSHOT_OUTPUT_PATH: &'static str = "test_snapshot.scen.json";
 const SC_WASM_PATH: &'static str = "output/rust-testing-framework-tester.wasm";
 const ADDER_WASM_PATH: &'static str = "../../examples/adder/output/adder.wasm";
 
@@ -22,14 +31,16 @@ fn test_add() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let first = managed_biguint!(1000);
-        let second = managed_biguint!(2000);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let first = managed_biguint!(1000);
+            let second = managed_biguint!(2000);
 
-        let expected_result = first.clone() + second.clone();
-        let actual_result = sc.sum(first, second);
-        assert_eq!(expected_result, actual_result);
-    });
+            let expected_result = first.clone() + second.clone();
+            let actual_result = sc.sum(first, second);
+            assert_eq!(expected_result, actual_result);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -42,14 +53,16 @@ fn test_sc_result_ok() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let first = managed_biguint!(1000);
-        let second = managed_biguint!(2000);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let first = managed_biguint!(1000);
+            let second = managed_biguint!(2000);
 
-        let expected_result = SCResult::Ok(first.clone() + second.clone());
-        let actual_result = sc.sum_sc_result(first, second);
-        assert_eq!(expected_result, actual_result);
-    });
+            let expected_result = SCResult::Ok(first.clone() + second.clone());
+            let actual_result = sc.sum_sc_result(first, second);
+            assert_eq!(expected_result, actual_result);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -62,13 +75,15 @@ fn test_sc_result_err() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let first = managed_biguint!(0);
-        let second = managed_biguint!(2000);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let first = managed_biguint!(0);
+            let second = managed_biguint!(2000);
 
-        let actual_result = sc.sum_sc_result(first, second);
-        assert_sc_error!(actual_result, b"Non-zero required");
-    });
+            let actual_result = sc.sum_sc_result(first, second);
+            assert_sc_error!(actual_result, b"Non-zero required");
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -83,13 +98,15 @@ fn test_sc_payment_ok() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
-        let actual_payment = sc.receive_moax();
-        let expected_payment = managed_biguint!(1_000);
-        assert_eq!(actual_payment, expected_payment);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+            let actual_payment = sc.receive_moax();
+            let expected_payment = managed_biguint!(1_000);
+            assert_eq!(actual_payment, expected_payment);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_moax_balance(&caller_addr, &rust_biguint!(0));
     wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(3_000));
@@ -107,13 +124,15 @@ fn test_sc_payment_reverted() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
-        let actual_payment = sc.receive_moax();
-        let expected_payment = managed_biguint!(1_000);
-        assert_eq!(actual_payment, expected_payment);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+            let actual_payment = sc.receive_moax();
+            let expected_payment = managed_biguint!(1_000);
+            assert_eq!(actual_payment, expected_payment);
 
-        StateChange::Revert
-    });
+            StateChange::Revert
+        })
+        .assert_ok();
 
     wrapper.check_moax_balance(&caller_addr, &rust_biguint!(1_000));
     wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(2_000));
@@ -131,16 +150,193 @@ fn test_sc_half_payment() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
-        sc.recieve_moax_half();
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+            sc.recieve_moax_half();
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_moax_balance(&caller_addr, &rust_biguint!(500));
     wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(2_500));
 }
 
+#[test]
+fn test_sc_error_reverts_state() {
+    let mut wrapper = BlockchainStateWrapper::new();
+
+    let owner_addr = wrapper.create_user_account(&rust_biguint!(0));
+    let user_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_addr),
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+        sc.add_value_owner_only(managed_biguint!(100));
+
+        StateChange::Commit
+    });
+    tx_result.assert_user_error("not owner");
+
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(1_000));
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(0));
+
+    wrapper
+        .execute_tx(&owner_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_value_owner_only(managed_biguint!(100));
+
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(sc.total_value().get(), managed_biguint!(100));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_sc_payment_insufficient_funds() {
+    let mut wrapper = BlockchainStateWrapper::new();
+
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(2_000), |sc| {
+        sc.receive_moax();
+
+        StateChange::Commit
+    });
+    tx_result.assert_error(10, "failed transfer (insufficient funds)");
+
+    wrapper.check_moax_balance(&caller_addr, &rust_biguint!(1_000));
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(0));
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn test_failed_assertion_in_closure_is_not_a_tx_error() {
+    let mut wrapper = BlockchainStateWrapper::new();
+
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let _ = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+        let actual_payment = sc.receive_moax();
+        assert_eq!(actual_payment, managed_biguint!(2_000));
+
+        StateChange::Commit
+    });
+}
+
+#[test]
+fn test_gas_metering() {
+    let mut wrapper = BlockchainStateWrapper::new();
+
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let mut gas_schedule = GasSchedule::zero();
+    gas_schedule.set_cost(DHARITRI_API_COST, "GetBlockNonce", 100);
+    wrapper.set_gas_schedule(gas_schedule);
+    wrapper.set_tx_gas_limit(150);
+
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+        sc.get_block_nonce();
+
+        StateChange::Commit
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.gas_used, 100);
+
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+        sc.get_block_nonce();
+        sc.get_block_nonce();
+
+        StateChange::Commit
+    });
+    tx_result.assert_error(5, "not enough gas");
+    assert_eq!(tx_result.gas_used, 150);
+}
+
+#[test]
+fn test_tx_fees() {
+    let mut wrapper = BlockchainStateWrapper::new();
+
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(10_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let mut gas_schedule = GasSchedule::zero();
+    gas_schedule.set_cost(DHARITRI_API_COST, "GetBlockNonce", 100);
+    wrapper.set_gas_schedule(gas_schedule);
+    wrapper.set_tx_gas_limit(150);
+    wrapper.set_tx_gas_price(10);
+
+    // 100 gas used, the other 50 are refunded, 30% of the fee goes to the contract developer rewards
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+        sc.get_block_nonce();
+
+        StateChange::Commit
+    });
+    tx_result.assert_ok();
+    wrapper.check_moax_balance(&caller_addr, &rust_biguint!(9_000));
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(0));
+    wrapper.check_developer_rewards(sc_wrapper.address_ref(), &rust_biguint!(300));
+
+    // failed transactions consume the entire gas limit
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+        sc.get_block_nonce();
+        sc.get_block_nonce();
+
+        StateChange::Commit
+    });
+    tx_result.assert_error(5, "not enough gas");
+    wrapper.check_moax_balance(&caller_addr, &rust_biguint!(7_500));
+    wrapper.check_developer_rewards(sc_wrapper.address_ref(), &rust_biguint!(300));
+
+    // queries are free, even though the contract has no MOAX to pay for them
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            sc.get_block_nonce();
+        })
+        .assert_ok();
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(0));
+    wrapper.check_developer_rewards(sc_wrapper.address_ref(), &rust_biguint!(300));
+
+    // callers who cannot pay for the gas do not get to execute the transaction
+    let poor_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let tx_result = wrapper.execute_tx(&poor_addr, &sc_wrapper, &rust_biguint!(0), |_| {
+        panic!("not executed")
+    });
+    tx_result.assert_error(10, "insufficient funds for gas");
+    wrapper.check_moax_balance(&poor_addr, &rust_biguint!(1_000));
+}
+
 #[test]
 fn test_dct_balance() {
     let mut wrapper = BlockchainStateWrapper::new();
@@ -155,13 +351,15 @@ fn test_dct_balance() {
     wrapper.set_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let managed_id = managed_token_id!(token_id);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let managed_id = managed_token_id!(token_id);
 
-        let actual_balance = sc.get_dct_balance(managed_id, 0);
-        let expected_balance = managed_biguint!(1_000);
-        assert_eq!(expected_balance, actual_balance);
-    });
+            let actual_balance = sc.get_dct_balance(managed_id, 0);
+            let expected_balance = managed_biguint!(1_000);
+            assert_eq!(expected_balance, actual_balance);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -181,22 +379,24 @@ fn test_dct_payment_ok() {
     wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
     wrapper.set_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
 
-    wrapper.execute_dct_transfer(
-        &caller_addr,
-        &sc_wrapper,
-        token_id,
-        0,
-        &rust_biguint!(1_000),
-        |sc| {
-            let (actual_token_id, actual_payment) = sc.receive_dct();
-            let expected_payment = managed_biguint!(1_000);
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let (actual_token_id, actual_payment) = sc.receive_dct();
+                let expected_payment = managed_biguint!(1_000);
 
-            assert_eq!(actual_token_id, managed_token_id!(token_id));
-            assert_eq!(actual_payment, expected_payment);
+                assert_eq!(actual_token_id, managed_token_id!(token_id));
+                assert_eq!(actual_payment, expected_payment);
 
-            StateChange::Commit
-        },
-    );
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
 
     wrapper.check_dct_balance(&caller_addr, token_id, &rust_zero);
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(3_000));
@@ -219,25 +419,85 @@ fn test_dct_payment_reverted() {
     wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
     wrapper.set_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
 
-    wrapper.execute_dct_transfer(
-        &caller_addr,
-        &sc_wrapper,
-        token_id,
-        0,
-        &rust_biguint!(1_000),
-        |sc| {
-            let (actual_token_id, actual_payment) = sc.receive_dct();
-            let expected_payment = managed_biguint!(1_000);
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let (actual_token_id, actual_payment) = sc.receive_dct();
+                let expected_payment = managed_biguint!(1_000);
 
-            assert_eq!(actual_token_id, managed_token_id!(token_id));
-            assert_eq!(actual_payment, expected_payment);
+                assert_eq!(actual_token_id, managed_token_id!(token_id));
+                assert_eq!(actual_payment, expected_payment);
 
-            StateChange::Revert
+                StateChange::Revert
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
+}
+
+#[test]
+fn test_dct_payment_paused_or_frozen() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_token_properties(
+        token_id,
+        DctTokenProperties {
+            paused: true,
+            ..Default::default()
         },
     );
 
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "dct token is paused");
+
+    wrapper.set_dct_token_properties(token_id, DctTokenProperties::default());
+    wrapper.set_dct_frozen(&caller_addr, token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "account is frozen for this dct token");
+
     wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
-    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_zero);
 }
 
 #[test]
@@ -272,13 +532,15 @@ fn test_nft_balance() {
         &nft_attributes,
     );
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let managed_id = managed_token_id!(token_id);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let managed_id = managed_token_id!(token_id);
 
-        let actual_balance = sc.get_dct_balance(managed_id, nft_nonce);
-        let expected_balance = managed_biguint!(1_000);
-        assert_eq!(expected_balance, actual_balance);
-    });
+            let actual_balance = sc.get_dct_balance(managed_id, nft_nonce);
+            let expected_balance = managed_biguint!(1_000);
+            assert_eq!(expected_balance, actual_balance);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -314,14 +576,16 @@ fn test_sc_send_nft_to_user() {
         &nft_attributes,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_addr = managed_address!(&caller_addr);
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(400);
-        sc.send_nft(managed_addr, managed_id, nft_nonce, managed_amt);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_addr = managed_address!(&caller_addr);
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(400);
+            sc.send_nft(managed_addr, managed_id, nft_nonce, managed_amt);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_nft_balance(
         &caller_addr,
@@ -357,23 +621,27 @@ fn test_sc_dct_mint_burn() {
         &[DctLocalRole::Mint, DctLocalRole::Burn][..],
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(400);
-        sc.mint_dct(managed_id, 0, managed_amt);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(400);
+            sc.mint_dct(managed_id, 0, managed_amt);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(400));
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(100);
-        sc.burn_dct(managed_id, 0, managed_amt);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(100);
+            sc.burn_dct(managed_id, 0, managed_amt);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(300));
 }
@@ -404,22 +672,24 @@ fn test_sc_nft() {
         ][..],
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(100);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(100);
 
-        let nft_nonce = sc.create_nft(
-            managed_id.clone(),
-            managed_amt.clone(),
-            nft_attributes.clone(),
-        );
-        assert_eq!(nft_nonce, 1u64);
+            let nft_nonce = sc.create_nft(
+                managed_id.clone(),
+                managed_amt.clone(),
+                nft_attributes.clone(),
+            );
+            assert_eq!(nft_nonce, 1u64);
 
-        let nft_nonce_second = sc.create_nft(managed_id, managed_amt, nft_attributes.clone());
-        assert_eq!(nft_nonce_second, 2u64);
+            let nft_nonce_second = sc.create_nft(managed_id, managed_amt, nft_attributes.clone());
+            assert_eq!(nft_nonce_second, 2u64);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_nft_balance(
         sc_wrapper.address_ref(),
@@ -436,13 +706,15 @@ fn test_sc_nft() {
         &nft_attributes,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(100);
-        sc.mint_dct(managed_id, 1, managed_amt);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(100);
+            sc.mint_dct(managed_id, 1, managed_amt);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_nft_balance(
         sc_wrapper.address_ref(),
@@ -459,13 +731,15 @@ fn test_sc_nft() {
         &nft_attributes,
     );
 
-    wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
-        let managed_id = managed_token_id!(token_id);
-        let managed_amt = managed_biguint!(50);
-        sc.burn_dct(managed_id, 2, managed_amt);
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let managed_id = managed_token_id!(token_id);
+            let managed_amt = managed_biguint!(50);
+            sc.burn_dct(managed_id, 2, managed_amt);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_nft_balance(
         sc_wrapper.address_ref(),
@@ -483,6 +757,108 @@ fn test_sc_nft() {
     );
 }
 
+#[test]
+fn test_sc_nft_metadata() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+    let nft_attributes = NftDummyAttributes {
+        creation_epoch: 666,
+        cool_factor: 101,
+    };
+    let uris = vec![
+        b"www.cool-nft.com/1.png".to_vec(),
+        b"www.cool-nft.com/1.json".to_vec(),
+    ];
+
+    wrapper.set_dct_local_roles(
+        sc_wrapper.address_ref(),
+        token_id,
+        &[DctLocalRole::NftCreate, DctLocalRole::NftAddUri][..],
+    );
+
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+            let mut managed_uris = ManagedVec::new();
+            for uri in &uris {
+                managed_uris.push(ManagedBuffer::new_from_bytes(uri));
+            }
+            let nft_nonce = sc.create_nft_with_metadata(
+                managed_token_id!(token_id),
+                managed_biguint!(1),
+                nft_attributes.clone(),
+                managed_biguint!(1_000),
+                managed_uris,
+            );
+            assert_eq!(nft_nonce, 1u64);
+
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper.check_nft_balance_with_properties(
+        sc_wrapper.address_ref(),
+        token_id,
+        1,
+        &rust_biguint!(1),
+        &nft_attributes,
+        1_000,
+        Some(sc_wrapper.address_ref()),
+        None,
+        Some(&[][..]),
+        &uris,
+    );
+
+    // royalties are capped at 100%
+    let tx_result = wrapper.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(0), |sc| {
+        let _ = sc.create_nft_with_metadata(
+            managed_token_id!(token_id),
+            managed_biguint!(1),
+            nft_attributes.clone(),
+            managed_biguint!(10_001),
+            ManagedVec::new(),
+        );
+
+        StateChange::Commit
+    });
+    tx_result.assert_error(
+        10,
+        "invalid arguments to process built-in function, invalid max royality value",
+    );
+
+    // metadata set directly in the world state round-trips as well
+    wrapper.set_nft_balance_all_properties(
+        &caller_addr,
+        token_id,
+        2,
+        &rust_biguint!(1),
+        &nft_attributes,
+        5_000,
+        Some(&caller_addr),
+        Some(&b"cool nft"[..]),
+        Some(&b"nft hash"[..]),
+        &uris,
+    );
+    wrapper.check_nft_balance_with_properties(
+        &caller_addr,
+        token_id,
+        2,
+        &rust_biguint!(1),
+        &nft_attributes,
+        5_000,
+        Some(&caller_addr),
+        Some(&b"cool nft"[..]),
+        Some(&b"nft hash"[..]),
+        &uris,
+    );
+}
+
 #[test]
 fn test_dct_multi_transfer() {
     let mut wrapper = BlockchainStateWrapper::new();
@@ -513,42 +889,44 @@ fn test_dct_multi_transfer() {
         },
     ];
 
-    wrapper.execute_dct_multi_transfer(&caller_addr, &sc_wrapper, &transfers, |sc| {
-        let mut expected_transfers = Vec::new();
-        expected_transfers.push(DctTokenPayment::new(
-            managed_token_id!(token_id_1),
-            0,
-            managed_biguint!(100),
-        ));
-        expected_transfers.push(DctTokenPayment::new(
-            managed_token_id!(token_id_2),
-            nft_nonce,
-            managed_biguint!(1),
-        ));
-
-        let actual_transfers = sc.receive_multi_dct().into_vec();
-        assert_eq!(
-            expected_transfers[0].token_identifier,
-            actual_transfers[0].token_identifier
-        );
-        assert_eq!(
-            expected_transfers[0].token_nonce,
-            actual_transfers[0].token_nonce
-        );
-        assert_eq!(expected_transfers[0].amount, actual_transfers[0].amount);
-
-        assert_eq!(
-            expected_transfers[1].token_identifier,
-            actual_transfers[1].token_identifier
-        );
-        assert_eq!(
-            expected_transfers[1].token_nonce,
-            actual_transfers[1].token_nonce
-        );
-        assert_eq!(expected_transfers[1].amount, actual_transfers[1].amount);
+    wrapper
+        .execute_dct_multi_transfer(&caller_addr, &sc_wrapper, &transfers, |sc| {
+            let mut expected_transfers = Vec::new();
+            expected_transfers.push(DctTokenPayment::new(
+                managed_token_id!(token_id_1),
+                0,
+                managed_biguint!(100),
+            ));
+            expected_transfers.push(DctTokenPayment::new(
+                managed_token_id!(token_id_2),
+                nft_nonce,
+                managed_biguint!(1),
+            ));
+
+            let actual_transfers = sc.receive_multi_dct().into_vec();
+            assert_eq!(
+                expected_transfers[0].token_identifier,
+                actual_transfers[0].token_identifier
+            );
+            assert_eq!(
+                expected_transfers[0].token_nonce,
+                actual_transfers[0].token_nonce
+            );
+            assert_eq!(expected_transfers[0].amount, actual_transfers[0].amount);
+
+            assert_eq!(
+                expected_transfers[1].token_identifier,
+                actual_transfers[1].token_identifier
+            );
+            assert_eq!(
+                expected_transfers[1].token_nonce,
+                actual_transfers[1].token_nonce
+            );
+            assert_eq!(expected_transfers[1].amount, actual_transfers[1].amount);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id_1, &rust_biguint!(100));
     wrapper.check_nft_balance(
@@ -590,44 +968,313 @@ fn storage_check_test() {
     );
 
     // simulate deploy
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.init();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let total_before = sc.total_value().get();
+            let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
+
+            assert_eq!(total_before, managed_biguint!(1));
+            assert_eq!(per_caller_before, managed_biguint!(0));
+
+            let added_value = managed_biguint!(50);
+            sc.add(added_value.clone());
 
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        let total_before = sc.total_value().get();
-        let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let expected_total_after = total_before + added_value.clone();
+            let expected_per_caller_after = per_caller_before + added_value;
 
-        assert_eq!(total_before, managed_biguint!(1));
-        assert_eq!(per_caller_before, managed_biguint!(0));
+            let actual_total_after = sc.total_value().get();
+            let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        let added_value = managed_biguint!(50);
-        sc.add(added_value.clone());
+            assert_eq!(expected_total_after, actual_total_after);
+            assert_eq!(expected_per_caller_after, actual_per_caller_after);
 
-        let expected_total_after = total_before + added_value.clone();
-        let expected_per_caller_after = per_caller_before + added_value;
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let expected_total = managed_biguint!(51);
+            let expected_per_caller = managed_biguint!(50);
 
-        let actual_total_after = sc.total_value().get();
-        let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total = sc.total_value().get();
+            let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_total_after, actual_total_after);
-        assert_eq!(expected_per_caller_after, actual_per_caller_after);
+            assert_eq!(expected_total, actual_total);
+            assert_eq!(expected_per_caller, actual_per_caller);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn events_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(50));
+
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    let logs = wrapper.get_last_tx_logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].event_identifier(), b"valueAdded");
+    assert_eq!(&logs[0].address, sc_wrapper.address_ref());
+    assert_eq!(logs[0].decode_topic::<Address>(0), user_addr);
+    assert_eq!(logs[0].decode_data::<u64>(), 50);
+
+    wrapper.check_last_tx_events(&sc_wrapper, |sc| {
+        sc.value_added_event(&managed_address!(&user_addr), &managed_biguint!(50));
+    });
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            assert_eq!(
+                logs[0].decode_data::<BigUint<DebugApi>>(),
+                managed_biguint!(50)
+            );
+
+            let _ = sc.get_total_value();
+        })
+        .assert_ok();
+    assert!(wrapper.get_last_tx_logs().is_empty());
+}
+
+#[test]
+fn print_messages_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        let _ = sc.print_values(ManagedBuffer::new_from_bytes(b"value"), managed_biguint!(5));
 
         StateChange::Commit
     });
+    tx_result.assert_ok();
+    let messages = tx_result.print_messages;
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[0], "value");
+    assert!(messages[1].ends_with("dec: \"5\" }"));
+    assert_eq!(messages[2], address_hex(&user_addr));
+    assert_eq!(messages[3], "non-zero: true");
+    assert_eq!(wrapper.get_last_tx_print_messages(), messages);
+
+    // buffers that are not valid UTF-8 are printed as hex
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        let result = sc.print_values(ManagedBuffer::new_from_bytes(&[0xff]), managed_biguint!(0));
+        assert_sc_error!(result, b"Non-zero required");
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let expected_total = managed_biguint!(51);
-        let expected_per_caller = managed_biguint!(50);
+        StateChange::Revert
+    });
+    tx_result.assert_ok();
+    assert_eq!(tx_result.print_messages[0], "0xff");
+    assert_eq!(tx_result.print_messages[3], "non-zero: false");
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let _ = sc.get_total_value();
+        })
+        .assert_ok();
+    assert!(wrapper.get_last_tx_print_messages().is_empty());
+}
 
-        let actual_total = sc.total_value().get();
-        let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
+#[test]
+fn storage_helpers_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
 
-        assert_eq!(expected_total, actual_total);
-        assert_eq!(expected_per_caller, actual_per_caller);
+    wrapper.set_storage(sc_wrapper.address_ref(), b"totalValue", &100u64);
+    assert_eq!(
+        wrapper.get_storage::<u64>(sc_wrapper.address_ref(), b"totalValue"),
+        100
+    );
+
+    wrapper.set_sc_storage(&sc_wrapper, |sc| {
+        sc.value_per_caller(&managed_address!(&user_addr))
+            .set(&managed_biguint!(50));
     });
+
+    let mut per_caller_key = b"valuePerCaller".to_vec();
+    per_caller_key.extend_from_slice(user_addr.as_bytes());
+    assert_eq!(
+        wrapper.get_storage_raw(sc_wrapper.address_ref(), &per_caller_key),
+        vec![50u8]
+    );
+
+    let total_value = wrapper.get_sc_storage(&sc_wrapper, |sc| sc.total_value().get().to_u64());
+    assert_eq!(total_value, Some(100));
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(10));
+
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    let per_caller = wrapper.get_sc_storage(&sc_wrapper, |sc| {
+        sc.value_per_caller(&managed_address!(&user_addr))
+            .get()
+            .to_u64()
+            .unwrap()
+    });
+    assert_eq!(per_caller, 60);
+    assert_eq!(
+        wrapper.get_storage::<u64>(sc_wrapper.address_ref(), b"totalValue"),
+        110
+    );
+}
+
+#[test]
+fn snapshot_and_fork_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    wrapper.set_storage(sc_wrapper.address_ref(), b"totalValue", &1u64);
+    wrapper.save_snapshot("setup");
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_biguint!(400), |sc| {
+            sc.add(managed_biguint!(50));
+            sc.receive_moax();
+
+            StateChange::Commit
+        })
+        .assert_ok();
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(600));
+
+    let mut forked_wrapper = wrapper.fork();
+
+    wrapper.load_snapshot("setup");
+    wrapper.check_moax_balance(&user_addr, &rust_biguint!(1_000));
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_zero);
+    assert_eq!(
+        wrapper.get_storage::<u64>(sc_wrapper.address_ref(), b"totalValue"),
+        1
+    );
+
+    // the fork is not affected by the rollback and can execute independently
+    forked_wrapper.check_moax_balance(&user_addr, &rust_biguint!(600));
+    forked_wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.add(managed_biguint!(10));
+
+            StateChange::Commit
+        })
+        .assert_ok();
+    assert_eq!(
+        forked_wrapper.get_storage::<u64>(sc_wrapper.address_ref(), b"totalValue"),
+        61
+    );
+    assert_eq!(
+        wrapper.get_storage::<u64>(sc_wrapper.address_ref(), b"totalValue"),
+        1
+    );
+
+    // snapshots are not supported by the Go VM
+    wrapper.write_denali_output(TEST_SNAPSHOT_OUTPUT_PATH);
+    let denali_output =
+        std::fs::read_to_string(format!("denali/{}", TEST_SNAPSHOT_OUTPUT_PATH)).unwrap();
+    assert!(denali_output.contains("only runs in denali-rs"));
+}
+
+#[test]
+fn cross_shard_async_call_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    wrapper.set_num_shards(2);
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let adder_wrapper =
+        wrapper.create_sc_account(&rust_zero, None, adder::contract_obj, ADDER_WASM_PATH);
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let mut arg_buffer = ManagedArgBuffer::new_empty();
+            arg_buffer.push_arg(5u32);
+            sc.raw_vm_api().async_call_raw(
+                &managed_address!(adder_wrapper.address_ref()),
+                &managed_biguint!(0),
+                &ManagedBuffer::from(&b"add"[..]),
+                &arg_buffer,
+            )
+        })
+        .assert_ok();
+    assert_eq!(
+        wrapper.get_storage::<u64>(adder_wrapper.address_ref(), b"sum"),
+        0
+    );
+
+    // the pending calls are part of the snapshot
+    wrapper.save_snapshot("async-call-pending");
+
+    let results = wrapper.execute_cross_shard_calls();
+    assert_eq!(results.len(), 1);
+    results[0].assert_ok();
+    assert_eq!(
+        wrapper.get_storage::<u64>(adder_wrapper.address_ref(), b"sum"),
+        5
+    );
+
+    // the callback
+    let results = wrapper.execute_cross_shard_calls();
+    assert_eq!(results.len(), 1);
+    results[0].assert_ok();
+    assert!(wrapper.execute_cross_shard_calls().is_empty());
+
+    wrapper.load_snapshot("async-call-pending");
+    assert_eq!(
+        wrapper.get_storage::<u64>(adder_wrapper.address_ref(), b"sum"),
+        0
+    );
+    assert_eq!(wrapper.execute_cross_shard_calls().len(), 1);
+    assert_eq!(
+        wrapper.get_storage::<u64>(adder_wrapper.address_ref(), b"sum"),
+        5
+    );
 }
 
 #[test]
@@ -643,44 +1290,50 @@ fn storage_revert_test() {
     );
 
     // simulate deploy
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.init();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        let total_before = sc.total_value().get();
-        let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let total_before = sc.total_value().get();
+            let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(total_before, managed_biguint!(1));
-        assert_eq!(per_caller_before, managed_biguint!(0));
+            assert_eq!(total_before, managed_biguint!(1));
+            assert_eq!(per_caller_before, managed_biguint!(0));
 
-        let added_value = managed_biguint!(50);
-        sc.add(added_value.clone());
+            let added_value = managed_biguint!(50);
+            sc.add(added_value.clone());
 
-        let expected_total_after = total_before + added_value.clone();
-        let expected_per_caller_after = per_caller_before + added_value;
+            let expected_total_after = total_before + added_value.clone();
+            let expected_per_caller_after = per_caller_before + added_value;
 
-        let actual_total_after = sc.total_value().get();
-        let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total_after = sc.total_value().get();
+            let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_total_after, actual_total_after);
-        assert_eq!(expected_per_caller_after, actual_per_caller_after);
+            assert_eq!(expected_total_after, actual_total_after);
+            assert_eq!(expected_per_caller_after, actual_per_caller_after);
 
-        StateChange::Revert
-    });
+            StateChange::Revert
+        })
+        .assert_ok();
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let expected_total = managed_biguint!(1);
-        let expected_per_caller = managed_biguint!(0);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let expected_total = managed_biguint!(1);
+            let expected_per_caller = managed_biguint!(0);
 
-        let actual_total = sc.total_value().get();
-        let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total = sc.total_value().get();
+            let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_total, actual_total);
-        assert_eq!(expected_per_caller, actual_per_caller);
-    });
+            assert_eq!(expected_total, actual_total);
+            assert_eq!(expected_per_caller, actual_per_caller);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -696,29 +1349,35 @@ fn storage_set_test() {
     );
 
     // simulate deploy
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.init();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.total_value().set(&managed_biguint!(50));
-        sc.value_per_caller(&managed_address!(&user_addr))
-            .set(&managed_biguint!(50));
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.total_value().set(&managed_biguint!(50));
+            sc.value_per_caller(&managed_address!(&user_addr))
+                .set(&managed_biguint!(50));
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let expected_value = managed_biguint!(50);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let expected_value = managed_biguint!(50);
 
-        let actual_total = sc.total_value().get();
-        let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total = sc.total_value().get();
+            let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_value, actual_total);
-        assert_eq!(expected_value, actual_per_caller);
-    });
+            assert_eq!(expected_value, actual_total);
+            assert_eq!(expected_value, actual_per_caller);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -740,15 +1399,17 @@ fn blockchain_state_test() {
     wrapper.set_block_nonce(expected_nonce);
     wrapper.set_block_timestamp(expected_timestamp);
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let actual_epoch = sc.get_block_epoch();
-        let actual_nonce = sc.get_block_nonce();
-        let actual_timestamp = sc.get_block_timestamp();
-
-        assert_eq!(expected_epoch, actual_epoch);
-        assert_eq!(expected_nonce, actual_nonce);
-        assert_eq!(expected_timestamp, actual_timestamp);
-    });
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let actual_epoch = sc.get_block_epoch();
+            let actual_nonce = sc.get_block_nonce();
+            let actual_timestamp = sc.get_block_timestamp();
+
+            assert_eq!(expected_epoch, actual_epoch);
+            assert_eq!(expected_nonce, actual_nonce);
+            assert_eq!(expected_timestamp, actual_timestamp);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -769,20 +1430,24 @@ fn execute_on_dest_context_query_test() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
-        sc.total_value().set(&managed_biguint!(5));
-        StateChange::Commit
-    });
-
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let expected_result = managed_biguint!(5);
-        let actual_result =
-            sc.call_other_contract_execute_on_dest(managed_address!(&other_sc_wrapper
-                .address_ref()
-                .clone()));
-
-        assert_eq!(expected_result, actual_result);
-    });
+    wrapper
+        .execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
+            sc.total_value().set(&managed_biguint!(5));
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let expected_result = managed_biguint!(5);
+            let actual_result =
+                sc.call_other_contract_execute_on_dest(managed_address!(&other_sc_wrapper
+                    .address_ref()
+                    .clone()));
+
+            assert_eq!(expected_result, actual_result);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -803,25 +1468,31 @@ fn execute_on_dest_context_change_state_test() {
         SC_WASM_PATH,
     );
 
-    wrapper.execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
-        sc.total_value().set(&managed_biguint!(5));
-        StateChange::Commit
-    });
-
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.execute_on_dest_add_value(
-            managed_address!(&other_sc_wrapper.address_ref().clone()),
-            managed_biguint!(5),
-        );
-        StateChange::Commit
-    });
+    wrapper
+        .execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
+            sc.total_value().set(&managed_biguint!(5));
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.execute_on_dest_add_value(
+                managed_address!(&other_sc_wrapper.address_ref().clone()),
+                managed_biguint!(5),
+            );
+            StateChange::Commit
+        })
+        .assert_ok();
 
-    wrapper.execute_query(&other_sc_wrapper, |sc| {
-        let expected_result = managed_biguint!(10);
-        let actual_result = sc.get_val();
+    wrapper
+        .execute_query(&other_sc_wrapper, |sc| {
+            let expected_result = managed_biguint!(10);
+            let actual_result = sc.get_val();
 
-        assert_eq!(expected_result, actual_result);
-    });
+            assert_eq!(expected_result, actual_result);
+        })
+        .assert_ok();
 }
 
 #[test]
@@ -837,11 +1508,13 @@ fn test_denali_generation() {
     );
 
     // simulate deploy
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        sc.init();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.init();
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
     wrapper.add_denali_set_account(sc_wrapper.address_ref());
     wrapper.add_denali_check_account(sc_wrapper.address_ref());
 
@@ -853,27 +1526,29 @@ fn test_denali_generation() {
     let tx_expect = TxExpectDenali::new(0);
     wrapper.add_denali_sc_call(sc_call_denali, Some(tx_expect));
 
-    wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
-        let total_before = sc.total_value().get();
-        let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            let total_before = sc.total_value().get();
+            let per_caller_before = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(total_before, managed_biguint!(1));
-        assert_eq!(per_caller_before, managed_biguint!(0));
+            assert_eq!(total_before, managed_biguint!(1));
+            assert_eq!(per_caller_before, managed_biguint!(0));
 
-        let added_value = managed_biguint!(50);
-        sc.add(added_value.clone());
+            let added_value = managed_biguint!(50);
+            sc.add(added_value.clone());
 
-        let expected_total_after = total_before + added_value.clone();
-        let expected_per_caller_after = per_caller_before + added_value;
+            let expected_total_after = total_before + added_value.clone();
+            let expected_per_caller_after = per_caller_before + added_value;
 
-        let actual_total_after = sc.total_value().get();
-        let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total_after = sc.total_value().get();
+            let actual_per_caller_after = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_total_after, actual_total_after);
-        assert_eq!(expected_per_caller_after, actual_per_caller_after);
+            assert_eq!(expected_total_after, actual_total_after);
+            assert_eq!(expected_per_caller_after, actual_per_caller_after);
 
-        StateChange::Commit
-    });
+            StateChange::Commit
+        })
+        .assert_ok();
     wrapper.add_denali_check_account(sc_wrapper.address_ref());
 
     let expected_value = rust_biguint!(51);
@@ -884,16 +1559,18 @@ fn test_denali_generation() {
 
     wrapper.add_denali_sc_query(sc_query_denali, Some(query_expect));
 
-    wrapper.execute_query(&sc_wrapper, |sc| {
-        let expected_total = managed_biguint!(51);
-        let expected_per_caller = managed_biguint!(50);
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let expected_total = managed_biguint!(51);
+            let expected_per_caller = managed_biguint!(50);
 
-        let actual_total = sc.total_value().get();
-        let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
+            let actual_total = sc.total_value().get();
+            let actual_per_caller = sc.value_per_caller(&managed_address!(&user_addr)).get();
 
-        assert_eq!(expected_total, actual_total);
-        assert_eq!(expected_per_caller, actual_per_caller);
-    });
+            assert_eq!(expected_total, actual_total);
+            assert_eq!(expected_per_caller, actual_per_caller);
+        })
+        .assert_ok();
 
     wrapper.write_denali_output(TEST_OUTPUT_PATH);
 }
@@ -917,3 +1594,85 @@ fn test_multiple_contracts() {
 
     wrapper.write_denali_output(TEST_MULTIPLE_SC_OUTPUT_PATH);
 }
+
+#[test]
+fn test_signature_verification() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let message = b"signed voucher";
+    let other_message = b"forged voucher";
+
+    let ed25519_keys = wrapper.create_ed25519_keypair();
+    let ed25519_sig = wrapper.sign_ed25519(&ed25519_keys.secret_key, message);
+
+    let secp256k1_keys = wrapper.create_secp256k1_keypair();
+    let secp256k1_sig = wrapper.sign_secp256k1(
+        &secp256k1_keys.secret_key,
+        message,
+        MessageHashType::ECDSADoubleSha256,
+    );
+    let secp256k1_keccak_sig = wrapper.sign_secp256k1(
+        &secp256k1_keys.secret_key,
+        message,
+        MessageHashType::ECDSAKeccak256,
+    );
+
+    let bls_keys = wrapper.create_bls_keypair();
+    let bls_sig = wrapper.sign_bls(&bls_keys.secret_key, message);
+
+    wrapper
+        .execute_query(&sc_wrapper, |sc| {
+            let crypto = sc.crypto();
+
+            assert!(crypto.verify_ed25519(&ed25519_keys.public_key, message, &ed25519_sig));
+            assert!(!crypto.verify_ed25519(&ed25519_keys.public_key, other_message, &ed25519_sig));
+
+            assert!(crypto.verify_secp256k1(&secp256k1_keys.public_key, message, &secp256k1_sig));
+            assert!(crypto.verify_custom_secp256k1(
+                &secp256k1_keys.public_key,
+                message,
+                &secp256k1_keccak_sig,
+                MessageHashType::ECDSAKeccak256,
+            ));
+            assert!(!crypto.verify_secp256k1(
+                &secp256k1_keys.public_key,
+                message,
+                &secp256k1_keccak_sig
+            ));
+
+            assert!(crypto.verify_bls(&bls_keys.public_key, message, &bls_sig));
+            assert!(!crypto.verify_bls(&bls_keys.public_key, other_message, &bls_sig));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn test_elliptic_curve_key_exchange() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper
+        .execute_query(&sc_wrapper, |_sc| {
+            let ec = EllipticCurve::<DebugApi>::from_bitsize(256).unwrap();
+            let (first_x, first_y, first_secret) = ec.generate_key();
+            let (second_x, second_y, second_secret) = ec.generate_key();
+            assert_ne!(first_secret, second_secret);
+            assert!(ec.is_on_curve(first_x.clone(), first_y.clone()));
+
+            let (first_shared_x, _) = ec.scalar_mult(second_x, second_y, first_secret.as_slice());
+            let (second_shared_x, _) = ec.scalar_mult(first_x, first_y, second_secret.as_slice());
+            assert_eq!(first_shared_x, second_shared_x);
+        })
+        .assert_ok();
+}
@@ -12,6 +12,7 @@ dharitri_wasm_node::wasm_endpoints! {
         burn_dct
         call_other_contract_execute_on_dest
         create_nft
+        create_nft_with_metadata
         execute_on_dest_add_value
         getTotalValue
         get_block_epoch
@@ -21,6 +22,7 @@ dharitri_wasm_node::wasm_endpoints! {
         get_moax_balance
         get_dct_balance
         mint_dct
+        print_values
         receive_moax
         receive_dct
         receive_dct_half
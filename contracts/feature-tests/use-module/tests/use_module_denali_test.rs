@@ -0,0 +1,50 @@
+mod user_builtin {
+    dharitri_wasm::imports!();
+
+    #[dharitri_wasm::proxy]
+    pub trait UserBuiltin {
+        #[endpoint(SetUserName)]
+        fn set_user_name(&self, name: &BoxedBytes) -> BigUint;
+    }
+}
+
+mod dns_mock {
+    dharitri_wasm::imports!();
+
+    #[dharitri_wasm::contract]
+    pub trait DnsMock {
+        #[proxy]
+        fn user_builtin_proxy(&self, to: ManagedAddress) -> super::user_builtin::Proxy<Self::Api>;
+
+        #[payable("MOAX")]
+        #[endpoint]
+        fn register(&self, name: BoxedBytes, #[payment] _payment: BigUint) -> AsyncCall {
+            let address = self.blockchain().get_caller();
+            self.user_builtin_proxy(address)
+                .set_user_name(&name)
+                .async_call()
+        }
+    }
+}
+
+use dharitri_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/use-module.wasm",
+        Box::new(|context| Box::new(use_module::contract_obj(context))),
+    );
+
+    blockchain.register_contract(
+        "file:test-wasm/dns.wasm",
+        Box::new(|context| Box::new(dns_mock::contract_obj(context))),
+    );
+
+    blockchain
+}
+
+denali_tests!("denali", world);
+
+// Governance module tests
+denali_tests!("denali/use_module_governance", world);
@@ -1,17 +1,11 @@
 #!/bin/bash
 
-## Prerequisites: `sudo npm install -g json-fmt`
+## Checks all Denali scenario files in the repository, reporting problems and unformatted files.
+## Nothing gets written, unless `--write` is passed, in which case the unformatted files are rewritten in place.
 
-DENALI_SCEN_FILES=$(find . -name "*.scen.json")
-DENALI_STEP_FILES=$(find . -name "*.step.json")
-DENALI_STEPS_FILES=$(find . -name "*.steps.json")
-DENALI_ALL_FILES="$DENALI_SCEN_FILES $DENALI_STEP_FILES $DENALI_STEPS_FILES"
-
-TEMP_FILE=denali-fmt-temp.scen.json
-for DENALI_FILE in $DENALI_ALL_FILES
-do
-    echo $DENALI_FILE
-    json-fmt $DENALI_FILE --indent "    " --prettify --output $TEMP_FILE || exit 1
-    echo >> $TEMP_FILE # adds missing newline
-    mv $TEMP_FILE $DENALI_FILE
-done
+if [ "$1" == "--write" ]; then
+    shift
+    cargo run --release --quiet --package denali --bin denali-fmt -- "$@" .
+else
+    cargo run --release --quiet --package denali --bin denali-fmt -- --check "$@" .
+fi
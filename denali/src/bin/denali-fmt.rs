@@ -0,0 +1,126 @@
+use denali::{
+    scenario_check::check_scenario_file,
+    scenario_fmt::{format_scenario_file, is_scenario_file_formatted},
+};
+use std::{
+    env, fs, panic,
+    path::{Path, PathBuf},
+    process,
+};
+
+const USAGE: &str = "Usage: denali-fmt [--check] <file or directory>...
+
+Checks and formats Denali scenario files (*.scen.json, *.step.json, *.steps.json).
+Directories are searched recursively, skipping `target` and hidden directories.
+
+    --check    only report problems and unformatted files, do not write anything";
+
+const SCENARIO_FILE_SUFFIXES: &[&str] = &[".scen.json", ".step.json", ".steps.json"];
+
+fn main() {
+    let mut check_only = false;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check_only = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    if paths.is_empty() {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    // malformed expressions are reported by the checker, not by the panic hook
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut files = Vec::new();
+    for path in &paths {
+        collect_scenario_files(path, &mut files);
+    }
+
+    let mut failed = false;
+    for file in &files {
+        let errors = check_scenario_file(file);
+        if !errors.is_empty() {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            failed = true;
+            continue;
+        }
+
+        if check_only {
+            match is_scenario_file_formatted(file) {
+                Ok(true) => {},
+                Ok(false) => {
+                    eprintln!("{}: not formatted", file.display());
+                    failed = true;
+                },
+                Err(e) => {
+                    eprintln!("{}: {}", file.display(), e);
+                    failed = true;
+                },
+            }
+        } else {
+            match format_scenario_file(file) {
+                Ok(true) => println!("formatted {}", file.display()),
+                Ok(false) => {},
+                Err(e) => {
+                    eprintln!("{}: {}", file.display(), e);
+                    failed = true;
+                },
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn is_scenario_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map_or(false, |name| {
+            SCENARIO_FILE_SUFFIXES
+                .iter()
+                .any(|suffix| name.ends_with(suffix))
+        })
+}
+
+fn collect_scenario_files(path: &Path, files: &mut Vec<PathBuf>) {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return;
+    }
+
+    let mut entries: Vec<PathBuf> = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect(),
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return;
+        },
+    };
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            let dir_name = entry
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("");
+            if dir_name != "target" && !dir_name.starts_with('.') {
+                collect_scenario_files(&entry, files);
+            }
+        } else if is_scenario_file(&entry) {
+            files.push(entry);
+        }
+    }
+}
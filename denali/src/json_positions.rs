@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+/// Line and column (both 1-based) of a JSON element in the source text.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct JsonPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Maps JSON pointers (e.g. `/steps/2/tx/from`) to their positions in the source text.
+/// Object members point to their key, array items to the start of the item.
+///
+/// The scan is lenient: it stops at the first syntax error,
+/// since the JSON parser reports those anyway.
+pub struct JsonPositions {
+    positions: HashMap<String, JsonPosition>,
+}
+
+impl JsonPositions {
+    pub fn scan(source: &str) -> Self {
+        let mut scanner = Scanner {
+            source,
+            bytes: source.as_bytes(),
+            pos: 0,
+            line: 1,
+            line_start: 0,
+            positions: HashMap::new(),
+        };
+        let mut pointer = String::new();
+        scanner.skip_whitespace();
+        scanner.record(&pointer);
+        let _ = scanner.value(&mut pointer);
+        JsonPositions {
+            positions: scanner.positions,
+        }
+    }
+
+    /// Position of the element at the given pointer,
+    /// or of its closest ancestor if the element itself is not in the source.
+    pub fn get(&self, pointer: &str) -> JsonPosition {
+        let mut pointer = pointer;
+        loop {
+            if let Some(position) = self.positions.get(pointer) {
+                return *position;
+            }
+            match pointer.rfind('/') {
+                Some(index) => pointer = &pointer[..index],
+                None => return JsonPosition { line: 1, column: 1 },
+            }
+        }
+    }
+}
+
+/// Appends a reference token to a JSON pointer, escaping it as per RFC 6901.
+pub fn push_pointer_token(pointer: &mut String, token: &str) {
+    pointer.push('/');
+    pointer.push_str(token.replace('~', "~0").replace('/', "~1").as_str());
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    line_start: usize,
+    positions: HashMap<String, JsonPosition>,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b) = self.peek() {
+            match b {
+                b'\n' => {
+                    self.pos += 1;
+                    self.line += 1;
+                    self.line_start = self.pos;
+                },
+                b' ' | b'\t' | b'\r' => self.pos += 1,
+                _ => return,
+            }
+        }
+    }
+
+    fn position(&self) -> JsonPosition {
+        JsonPosition {
+            line: self.line,
+            column: self.source[self.line_start..self.pos].chars().count() + 1,
+        }
+    }
+
+    fn record(&mut self, pointer: &str) {
+        let position = self.position();
+        self.positions.insert(pointer.to_string(), position);
+    }
+
+    fn expect(&mut self, expected: u8) -> Option<()> {
+        self.skip_whitespace();
+        if self.peek()? == expected {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn value(&mut self, pointer: &mut String) -> Option<()> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'{' => self.object(pointer),
+            b'[' => self.array(pointer),
+            b'"' => self.string().map(|_| ()),
+            _ => {
+                while let Some(b) = self.peek() {
+                    if matches!(b, b',' | b']' | b'}' | b' ' | b'\t' | b'\r' | b'\n') {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Some(())
+            },
+        }
+    }
+
+    /// Returns the decoded string contents.
+    fn string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    break;
+                },
+                _ => self.pos += 1,
+            }
+        }
+        serde_json::from_str(&self.source[start..self.pos]).ok()
+    }
+
+    fn object(&mut self, pointer: &mut String) -> Option<()> {
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.peek()? == b'}' {
+            self.pos += 1;
+            return Some(());
+        }
+        loop {
+            self.skip_whitespace();
+            let pointer_len = pointer.len();
+            let key_position = self.position();
+            let key = self.string()?;
+            push_pointer_token(pointer, key.as_str());
+            self.positions.insert(pointer.clone(), key_position);
+            self.expect(b':')?;
+            self.value(pointer)?;
+            pointer.truncate(pointer_len);
+            self.skip_whitespace();
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b'}' => {
+                    self.pos += 1;
+                    return Some(());
+                },
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &mut String) -> Option<()> {
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.peek()? == b']' {
+            self.pos += 1;
+            return Some(());
+        }
+        let mut index = 0usize;
+        loop {
+            self.skip_whitespace();
+            let pointer_len = pointer.len();
+            push_pointer_token(pointer, index.to_string().as_str());
+            self.record(pointer);
+            self.value(pointer)?;
+            pointer.truncate(pointer_len);
+            index += 1;
+            self.skip_whitespace();
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b']' => {
+                    self.pos += 1;
+                    return Some(());
+                },
+                _ => return None,
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 pub mod interpret_trait;
+mod json_positions;
 pub mod model;
 mod parse_util;
+pub mod scenario_check;
+pub mod scenario_fmt;
 pub mod serde_raw;
 pub mod value_interpreter;
 
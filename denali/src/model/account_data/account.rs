@@ -15,6 +15,8 @@ pub struct Account {
     pub username: Option<BytesValue>,
     pub storage: BTreeMap<BytesKey, BytesValue>,
     pub code: Option<BytesValue>,
+    pub code_metadata: Option<BytesValue>,
+    pub developer_rewards: Option<BigUintValue>,
     pub owner: Option<AddressValue>,
 }
 
@@ -50,6 +52,12 @@ impl InterpretableFrom<AccountRaw> for Account {
                 })
                 .collect(),
             code: from.code.map(|c| BytesValue::interpret_from(c, context)),
+            code_metadata: from
+                .code_metadata
+                .map(|c| BytesValue::interpret_from(c, context)),
+            developer_rewards: from
+                .developer_rewards
+                .map(|b| BigUintValue::interpret_from(b, context)),
             owner: from.owner.map(|v| AddressValue::interpret_from(v, context)),
         }
     }
@@ -13,6 +13,8 @@ pub struct CheckAccount {
     pub username: CheckValue<BytesValue>,
     pub storage: CheckStorage,
     pub code: CheckValue<BytesValue>,
+    pub code_metadata: CheckValue<BytesValue>,
+    pub developer_rewards: CheckValue<BigUintValue>,
     pub async_call_data: CheckValue<BytesValue>,
 }
 
@@ -26,6 +28,11 @@ impl InterpretableFrom<Box<CheckAccountRaw>> for CheckAccount {
             username: CheckValue::<BytesValue>::interpret_from(from.username, context),
             storage: CheckStorage::interpret_from(from.storage, context),
             code: CheckValue::<BytesValue>::interpret_from(from.code, context),
+            code_metadata: CheckValue::<BytesValue>::interpret_from(from.code_metadata, context),
+            developer_rewards: CheckValue::<BigUintValue>::interpret_from(
+                from.developer_rewards,
+                context,
+            ),
             async_call_data: CheckValue::<BytesValue>::interpret_from(
                 from.async_call_data,
                 context,
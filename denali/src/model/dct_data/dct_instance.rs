@@ -1,6 +1,6 @@
 use crate::{
     interpret_trait::{InterpretableFrom, InterpreterContext},
-    model::{BigUintValue, BytesValue, CheckValue, U64Value},
+    model::{BigUintValue, BytesValue, CheckValue, CheckValueList, U64Value},
     serde_raw::CheckDctInstanceRaw,
 };
 
@@ -11,7 +11,7 @@ pub struct CheckDctInstance {
     pub creator: CheckValue<BytesValue>,
     pub royalties: CheckValue<U64Value>,
     pub hash: CheckValue<BytesValue>,
-    pub uri: CheckValue<BytesValue>,
+    pub uri: CheckValueList,
     pub attributes: CheckValue<BytesValue>,
 }
 
@@ -23,7 +23,7 @@ impl InterpretableFrom<CheckDctInstanceRaw> for CheckDctInstance {
             creator: CheckValue::<BytesValue>::interpret_from(from.creator, context),
             royalties: CheckValue::<U64Value>::interpret_from(from.royalties, context),
             hash: CheckValue::<BytesValue>::interpret_from(from.hash, context),
-            uri: CheckValue::<BytesValue>::interpret_from(from.uri, context),
+            uri: CheckValueList::interpret_from(from.uri, context),
             attributes: CheckValue::<BytesValue>::interpret_from(from.attributes, context),
         }
     }
@@ -7,6 +7,7 @@ mod dct_map_check;
 mod dct_map_contents_check;
 mod dct_object;
 mod instance;
+mod token_properties;
 
 pub use dct::*;
 pub use dct_check::*;
@@ -17,3 +18,4 @@ pub use dct_map_check::*;
 pub use dct_map_contents_check::*;
 pub use dct_object::*;
 pub use instance::*;
+pub use token_properties::*;
@@ -0,0 +1,30 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext},
+    model::U64Value,
+    serde_raw::TokenPropertiesRaw,
+};
+
+/// Token-level settings, shared by all the accounts holding the token.
+#[derive(Debug, Default)]
+pub struct TokenProperties {
+    pub paused: Option<U64Value>,
+    pub can_freeze: Option<U64Value>,
+    pub can_wipe: Option<U64Value>,
+    /// Only accounts having the transfer role can send or receive the token.
+    pub limited_transfer: Option<U64Value>,
+}
+
+impl InterpretableFrom<TokenPropertiesRaw> for TokenProperties {
+    fn interpret_from(from: TokenPropertiesRaw, context: &InterpreterContext) -> Self {
+        TokenProperties {
+            paused: from.paused.map(|v| U64Value::interpret_from(v, context)),
+            can_freeze: from
+                .can_freeze
+                .map(|v| U64Value::interpret_from(v, context)),
+            can_wipe: from.can_wipe.map(|v| U64Value::interpret_from(v, context)),
+            limited_transfer: from
+                .limited_transfer
+                .map(|v| U64Value::interpret_from(v, context)),
+        }
+    }
+}
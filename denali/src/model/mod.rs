@@ -6,6 +6,7 @@ mod scenario;
 mod step;
 mod storage_check;
 mod storage_details_check;
+mod storage_value_check;
 mod transaction;
 mod value;
 
@@ -17,5 +18,6 @@ pub use scenario::*;
 pub use step::*;
 pub use storage_check::*;
 pub use storage_details_check::*;
+pub use storage_value_check::*;
 pub use transaction::*;
 pub use value::*;
@@ -10,6 +10,8 @@ pub struct Scenario {
     pub name: Option<String>,
     pub comment: Option<String>,
     pub check_gas: Option<bool>,
+    pub trace_gas: Option<bool>,
+    pub gas_schedule: Option<String>,
     pub steps: Vec<Step>,
 }
 
@@ -19,6 +21,8 @@ impl InterpretableFrom<ScenarioRaw> for Scenario {
             name: from.name,
             comment: from.comment,
             check_gas: from.check_gas,
+            trace_gas: from.trace_gas,
+            gas_schedule: from.gas_schedule,
             steps: from
                 .steps
                 .into_iter()
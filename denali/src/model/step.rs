@@ -6,8 +6,8 @@ use crate::{
 use std::collections::BTreeMap;
 
 use super::{
-    Account, AddressKey, BlockInfo, BytesValue, CheckAccounts, NewAddress, TxCall, TxDeploy,
-    TxExpect, TxQuery, TxTransfer, TxValidatorReward,
+    Account, AddressKey, BlockInfo, BytesKey, BytesValue, CheckAccounts, NewAddress,
+    TokenProperties, TxCall, TxDeploy, TxExpect, TxQuery, TxTransfer, TxValidatorReward,
 };
 
 #[derive(Debug)]
@@ -19,6 +19,7 @@ pub enum Step {
         comment: Option<String>,
         accounts: BTreeMap<AddressKey, Account>,
         new_addresses: Vec<NewAddress>,
+        token_properties: BTreeMap<BytesKey, TokenProperties>,
         block_hashes: Vec<BytesValue>,
         previous_block_info: Box<Option<BlockInfo>>,
         current_block_info: Box<Option<BlockInfo>>,
@@ -57,6 +58,18 @@ pub enum Step {
     },
     DumpState {
         comment: Option<String>,
+        path: Option<String>,
+    },
+    SaveSnapshot {
+        comment: Option<String>,
+        name: String,
+    },
+    LoadSnapshot {
+        comment: Option<String>,
+        name: String,
+    },
+    ExecuteCrossShardCalls {
+        comment: Option<String>,
     },
 }
 
@@ -68,6 +81,7 @@ impl InterpretableFrom<StepRaw> for Step {
                 comment,
                 accounts,
                 new_addresses,
+                token_properties,
                 block_hashes,
                 previous_block_info,
                 current_block_info,
@@ -86,6 +100,15 @@ impl InterpretableFrom<StepRaw> for Step {
                     .into_iter()
                     .map(|t| NewAddress::interpret_from(t, context))
                     .collect(),
+                token_properties: token_properties
+                    .into_iter()
+                    .map(|(k, v)| {
+                        (
+                            BytesKey::interpret_from(k, context),
+                            TokenProperties::interpret_from(v, context),
+                        )
+                    })
+                    .collect(),
                 block_hashes: block_hashes
                     .into_iter()
                     .map(|t| BytesValue::interpret_from(t, context))
@@ -147,7 +170,10 @@ impl InterpretableFrom<StepRaw> for Step {
                 comment,
                 accounts: CheckAccounts::interpret_from(accounts, context),
             },
-            StepRaw::DumpState { comment } => Step::DumpState { comment },
+            StepRaw::DumpState { comment, path } => Step::DumpState { comment, path },
+            StepRaw::SaveSnapshot { comment, name } => Step::SaveSnapshot { comment, name },
+            StepRaw::LoadSnapshot { comment, name } => Step::LoadSnapshot { comment, name },
+            StepRaw::ExecuteCrossShardCalls { comment } => Step::ExecuteCrossShardCalls { comment },
         }
     }
 }
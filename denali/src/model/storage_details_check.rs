@@ -1,6 +1,6 @@
 use crate::{
     interpret_trait::{InterpretableFrom, InterpreterContext},
-    model::{BytesValue, CheckValue},
+    model::CheckStorageValue,
     serde_raw::CheckStorageDetailsRaw,
 };
 
@@ -8,25 +8,46 @@ use std::collections::BTreeMap;
 
 use super::BytesKey;
 
+/// Keys ending in this suffix match all storage keys starting with the given prefix, e.g. `"str:users|*"`.
+const KEY_PREFIX_PATTERN_SUFFIX: &str = "|*";
+
 #[derive(Debug)]
 pub struct CheckStorageDetails {
-    pub storages: BTreeMap<BytesKey, CheckValue<BytesValue>>,
+    pub storages: BTreeMap<BytesKey, CheckStorageValue>,
+    /// Checks applying to all storage keys that start with a given prefix.
+    pub key_prefixes: BTreeMap<BytesKey, CheckStorageValue>,
+    /// Set by the `"+": ""` entry. When enabled, the storage keys that are not mentioned are not checked.
     pub other_storages_allowed: bool,
 }
 
+impl CheckStorageDetails {
+    /// All the key prefix checks that apply to a storage key.
+    pub fn key_prefix_checks<'a>(
+        &'a self,
+        key: &'a [u8],
+    ) -> impl Iterator<Item = (&'a BytesKey, &'a CheckStorageValue)> {
+        self.key_prefixes
+            .iter()
+            .filter(move |(prefix, _)| key.starts_with(prefix.value.as_slice()))
+    }
+}
+
 impl InterpretableFrom<CheckStorageDetailsRaw> for CheckStorageDetails {
     fn interpret_from(from: CheckStorageDetailsRaw, context: &InterpreterContext) -> Self {
+        let mut storages = BTreeMap::new();
+        let mut key_prefixes = BTreeMap::new();
+        for (k, v) in from.storages.into_iter() {
+            let value = CheckStorageValue::interpret_from(v, context);
+            if let Some(prefix) = k.strip_suffix(KEY_PREFIX_PATTERN_SUFFIX) {
+                let key = BytesKey::interpret_from(prefix.to_string(), context);
+                key_prefixes.insert(key, value);
+            } else {
+                storages.insert(BytesKey::interpret_from(k, context), value);
+            }
+        }
         CheckStorageDetails {
-            storages: from
-                .storages
-                .into_iter()
-                .map(|(k, v)| {
-                    (
-                        BytesKey::interpret_from(k, context),
-                        CheckValue::<BytesValue>::interpret_from(v, context),
-                    )
-                })
-                .collect(),
+            storages,
+            key_prefixes,
             other_storages_allowed: from.other_storages_allowed,
         }
     }
@@ -0,0 +1,146 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext},
+    serde_raw::{CheckBytesValueRaw, ValueSubTree},
+};
+
+use super::{BigUintValue, BytesValue, CheckValue, Checkable};
+use num_bigint::BigUint;
+use std::fmt;
+
+/// Checks a storage value.
+/// Besides the regular checks, storage values can also be compared as unsigned numbers:
+/// `">=X"`, `">X"`, `"<=X"`, `"<X"` and inclusive ranges `"X..=Y"`.
+#[derive(Debug)]
+pub enum CheckStorageValue {
+    Value(CheckValue<BytesValue>),
+    GreaterOrEqual(BigUintValue),
+    Greater(BigUintValue),
+    LessOrEqual(BigUintValue),
+    Less(BigUintValue),
+    Range(BigUintValue, BigUintValue),
+}
+
+impl Default for CheckStorageValue {
+    fn default() -> Self {
+        CheckStorageValue::Value(CheckValue::default())
+    }
+}
+
+fn interpret_bound(s: &str, context: &InterpreterContext) -> BigUintValue {
+    BigUintValue::interpret_from(ValueSubTree::Str(s.trim().to_string()), context)
+}
+
+fn interpret_numeric_matcher(s: &str, context: &InterpreterContext) -> Option<CheckStorageValue> {
+    if let Some(bound) = s.strip_prefix(">=") {
+        return Some(CheckStorageValue::GreaterOrEqual(interpret_bound(
+            bound, context,
+        )));
+    }
+    if let Some(bound) = s.strip_prefix('>') {
+        return Some(CheckStorageValue::Greater(interpret_bound(bound, context)));
+    }
+    if let Some(bound) = s.strip_prefix("<=") {
+        return Some(CheckStorageValue::LessOrEqual(interpret_bound(
+            bound, context,
+        )));
+    }
+    if let Some(bound) = s.strip_prefix('<') {
+        return Some(CheckStorageValue::Less(interpret_bound(bound, context)));
+    }
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Some((min, max)) = s.split_once("..=") {
+            return Some(CheckStorageValue::Range(
+                interpret_bound(min, context),
+                interpret_bound(max, context),
+            ));
+        }
+    }
+    None
+}
+
+impl InterpretableFrom<CheckBytesValueRaw> for CheckStorageValue {
+    fn interpret_from(from: CheckBytesValueRaw, context: &InterpreterContext) -> Self {
+        if let CheckBytesValueRaw::Equal(ValueSubTree::Str(s)) = &from {
+            if let Some(numeric_matcher) = interpret_numeric_matcher(s, context) {
+                return numeric_matcher;
+            }
+        }
+        CheckStorageValue::Value(CheckValue::interpret_from(from, context))
+    }
+}
+
+impl Checkable<&[u8]> for CheckStorageValue {
+    fn check(&self, value: &[u8]) -> bool {
+        let number = || BigUint::from_bytes_be(value);
+        match self {
+            CheckStorageValue::Value(check_value) => check_value.check(value),
+            CheckStorageValue::GreaterOrEqual(bound) => number() >= bound.value,
+            CheckStorageValue::Greater(bound) => number() > bound.value,
+            CheckStorageValue::LessOrEqual(bound) => number() <= bound.value,
+            CheckStorageValue::Less(bound) => number() < bound.value,
+            CheckStorageValue::Range(min, max) => {
+                let number = number();
+                min.value <= number && number <= max.value
+            },
+        }
+    }
+}
+
+/// The bounds are always interpreted from strings, which are displayed without quotes.
+fn bound_str(bound: &BigUintValue) -> String {
+    match &bound.original {
+        ValueSubTree::Str(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl fmt::Display for CheckStorageValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckStorageValue::Value(check_value) => check_value.fmt(f),
+            CheckStorageValue::GreaterOrEqual(bound) => write!(f, ">={}", bound_str(bound)),
+            CheckStorageValue::Greater(bound) => write!(f, ">{}", bound_str(bound)),
+            CheckStorageValue::LessOrEqual(bound) => write!(f, "<={}", bound_str(bound)),
+            CheckStorageValue::Less(bound) => write!(f, "<{}", bound_str(bound)),
+            CheckStorageValue::Range(min, max) => {
+                write!(f, "{}..={}", bound_str(min), bound_str(max))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interpret_trait::{InterpretableFrom, InterpreterContext},
+        model::{CheckStorageValue, Checkable},
+        serde_raw::{CheckBytesValueRaw, ValueSubTree},
+    };
+
+    fn check_storage_value(s: &str) -> CheckStorageValue {
+        CheckStorageValue::interpret_from(
+            CheckBytesValueRaw::Equal(ValueSubTree::Str(s.to_string())),
+            &InterpreterContext::default(),
+        )
+    }
+
+    #[test]
+    fn check_numeric_matchers() {
+        assert!(check_storage_value(">=5").check(&[5u8][..]));
+        assert!(!check_storage_value(">=5").check(&[4u8][..]));
+        assert!(check_storage_value(">5").check(&[1u8, 0u8][..]));
+        assert!(!check_storage_value(">5").check(&[5u8][..]));
+        assert!(check_storage_value("<=5").check(&[][..]));
+        assert!(!check_storage_value("<5").check(&[5u8][..]));
+        assert!(check_storage_value("1..=1,000").check(&[3u8, 0xe8][..]));
+        assert!(!check_storage_value("1..=1,000").check(&[][..]));
+    }
+
+    #[test]
+    fn check_regular_values() {
+        assert!(check_storage_value("str:1..=2").check(&b"1..=2"[..]));
+        assert!(check_storage_value("5").check(&[5u8][..]));
+        assert!(!check_storage_value("5").check(&[6u8][..]));
+        assert_eq!(check_storage_value(">= 0x10").to_string(), ">=0x10");
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     interpret_trait::{InterpretableFrom, InterpreterContext},
-    model::{BytesValue, CheckLogs, CheckValue, U64Value},
+    model::{BigUintValue, BytesValue, CheckLogs, CheckValue, U64Value},
     serde_raw::{CheckBytesValueRaw, TxExpectRaw},
 };
 
@@ -11,7 +11,7 @@ pub struct TxExpect {
     pub message: CheckValue<BytesValue>,
     pub logs: CheckLogs,
     pub gas: Option<CheckValue<U64Value>>,
-    pub refund: CheckValue<U64Value>,
+    pub refund: CheckValue<BigUintValue>,
 }
 
 impl InterpretableFrom<TxExpectRaw> for TxExpect {
@@ -30,7 +30,7 @@ impl InterpretableFrom<TxExpectRaw> for TxExpect {
             } else {
                 Some(CheckValue::<U64Value>::interpret_from(from.gas, context))
             },
-            refund: CheckValue::<U64Value>::interpret_from(from.refund, context),
+            refund: CheckValue::<BigUintValue>::interpret_from(from.refund, context),
         }
     }
 }
@@ -2,6 +2,7 @@ mod address;
 mod address_value;
 mod value_basic;
 mod value_check;
+mod value_check_list;
 mod value_key_bytes;
 mod value_key_u64;
 
@@ -9,5 +10,6 @@ pub use address::*;
 pub use address_value::*;
 pub use value_basic::*;
 pub use value_check::*;
+pub use value_check_list::*;
 pub use value_key_bytes::*;
 pub use value_key_u64::*;
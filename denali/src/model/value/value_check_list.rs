@@ -0,0 +1,61 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext},
+    serde_raw::CheckValueListRaw,
+};
+
+use super::{BytesValue, CheckValue, Checkable};
+use std::fmt;
+
+#[derive(Debug, Default)]
+pub enum CheckValueList {
+    #[default]
+    Star,
+    Equal(Vec<CheckValue<BytesValue>>),
+}
+
+impl CheckValueList {
+    pub fn is_star(&self) -> bool {
+        matches!(self, CheckValueList::Star)
+    }
+}
+
+impl InterpretableFrom<CheckValueListRaw> for CheckValueList {
+    fn interpret_from(from: CheckValueListRaw, context: &InterpreterContext) -> Self {
+        match from {
+            CheckValueListRaw::Unspecified => CheckValueList::Star,
+            CheckValueListRaw::Star => CheckValueList::Star,
+            CheckValueListRaw::CheckList(list) => CheckValueList::Equal(
+                list.into_iter()
+                    .map(|check_raw| CheckValue::<BytesValue>::interpret_from(check_raw, context))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for CheckValueList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckValueList::Star => write!(f, "*"),
+            CheckValueList::Equal(list) => {
+                write!(f, "[")?;
+                for (i, check_value) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    check_value.fmt(f)?;
+                }
+                write!(f, "]")
+            },
+        }
+    }
+}
+
+impl Checkable<&[Vec<u8>]> for CheckValueList {
+    fn check(&self, values: &[Vec<u8>]) -> bool {
+        match self {
+            CheckValueList::Star => true,
+            CheckValueList::Equal(list) => list.check(values),
+        }
+    }
+}
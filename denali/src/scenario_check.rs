@@ -0,0 +1,326 @@
+use crate::{
+    interpret_trait::{InterpretableFrom, InterpreterContext},
+    json_positions::{push_pointer_token, JsonPositions},
+    model::Step,
+    serde_raw::{ScenarioRaw, StepRaw},
+    value_interpreter::interpret_string,
+};
+use serde_json::Value;
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+/// Fields whose values are plain strings, not value expressions.
+const NON_EXPRESSION_FIELDS: &[&str] = &[
+    "name",
+    "comment",
+    "gasSchedule",
+    "step",
+    "txId",
+    "path",
+    "function",
+    "roles",
+];
+
+/// Fields holding maps keyed by value expressions.
+const EXPRESSION_KEY_MAPS: &[&str] = &["accounts", "storage", "dct", "tokenProperties"];
+
+/// Marks storage values, which can also be numeric matchers. Not a real field name.
+const STORAGE_VALUE: &str = "storage value";
+
+/// Problem found in a scenario file, with the position of the offending JSON element.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ScenarioError {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.path.display(),
+            self.line,
+            self.column,
+            self.message
+        )
+    }
+}
+
+/// Checks a scenario file without running it.
+/// Reports JSON syntax errors, unknown fields, malformed value expressions,
+/// duplicate tx ids and missing external step files.
+pub fn check_scenario_file<P: AsRef<Path>>(path: P) -> Vec<ScenarioError> {
+    match fs::read_to_string(path.as_ref()) {
+        Ok(contents) => check_scenario(contents.as_str(), path.as_ref()),
+        Err(e) => vec![ScenarioError {
+            path: path.as_ref().to_path_buf(),
+            line: 1,
+            column: 1,
+            message: format!("could not read file: {}", e),
+        }],
+    }
+}
+
+/// Checks scenario JSON. The path is used for resolving relative file references and in the reports.
+pub fn check_scenario(contents: &str, path: &Path) -> Vec<ScenarioError> {
+    let json: Value = match serde_json::from_str(contents) {
+        Ok(json) => json,
+        Err(e) => return vec![json_error(path, &e)],
+    };
+    let raw: ScenarioRaw = match serde_json::from_str(contents) {
+        Ok(raw) => raw,
+        Err(e) => return vec![json_error(path, &e)],
+    };
+
+    let mut issues = Vec::<(String, String)>::new();
+    let reserialized = serde_json::to_value(&raw).unwrap();
+    check_unknown_fields(&json, &reserialized, &mut String::new(), &mut issues);
+
+    let positions = JsonPositions::scan(contents);
+    let scenario_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let context = InterpreterContext::new(scenario_dir.into());
+    let mut tx_ids = HashMap::<String, String>::new();
+    for (index, step) in raw.steps.into_iter().enumerate() {
+        let step_pointer = format!("/steps/{}", index);
+        if let Some(tx_id) = step_tx_id(&step) {
+            let tx_id_pointer = format!("{}/txId", step_pointer);
+            if let Some(first_pointer) = tx_ids.get(tx_id) {
+                issues.push((
+                    tx_id_pointer,
+                    format!(
+                        "duplicate tx id `{}`, first used at line {}",
+                        tx_id,
+                        positions.get(first_pointer).line
+                    ),
+                ));
+            } else {
+                tx_ids.insert(tx_id.to_string(), tx_id_pointer);
+            }
+        }
+
+        if let StepRaw::ExternalSteps { path, .. } = &step {
+            if !scenario_dir.join(path).is_file() {
+                issues.push((
+                    format!("{}/path", step_pointer),
+                    format!("external steps file not found: {}", path),
+                ));
+            }
+        }
+
+        let interpreted =
+            panic::catch_unwind(AssertUnwindSafe(|| Step::interpret_from(step, &context)));
+        if let Err(payload) = interpreted {
+            let mut pointer = step_pointer.clone();
+            let step_json = &json["steps"][index];
+            issues.push(
+                match find_malformed_expression(step_json, None, &context, &mut pointer) {
+                    Some((expr, message)) => (
+                        pointer,
+                        format!("malformed value expression `{}`: {}", expr, message),
+                    ),
+                    None => (
+                        step_pointer,
+                        format!("invalid step: {}", panic_message(payload.as_ref())),
+                    ),
+                },
+            );
+        }
+    }
+
+    let mut errors: Vec<ScenarioError> = issues
+        .into_iter()
+        .map(|(pointer, message)| {
+            let position = positions.get(pointer.as_str());
+            ScenarioError {
+                path: path.to_path_buf(),
+                line: position.line,
+                column: position.column,
+                message,
+            }
+        })
+        .collect();
+    errors.sort_by_key(|error| (error.line, error.column));
+    errors
+}
+
+fn json_error(path: &Path, error: &serde_json::Error) -> ScenarioError {
+    // the error message ends with its position, which is reported separately
+    let message = error.to_string();
+    let message = match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message,
+    };
+    ScenarioError {
+        path: path.to_path_buf(),
+        line: error.line(),
+        column: error.column(),
+        message,
+    }
+}
+
+fn step_tx_id(step: &StepRaw) -> Option<&str> {
+    match step {
+        StepRaw::ScCall { tx_id, .. }
+        | StepRaw::ScQuery { tx_id, .. }
+        | StepRaw::ScDeploy { tx_id, .. }
+        | StepRaw::Transfer { tx_id, .. }
+        | StepRaw::ValidatorReward { tx_id, .. }
+            if !tx_id.is_empty() =>
+        {
+            Some(tx_id.as_str())
+        },
+        _ => None,
+    }
+}
+
+/// The raw model ignores fields it does not know, so they are missing when serializing it back.
+fn check_unknown_fields(
+    original: &Value,
+    reserialized: &Value,
+    pointer: &mut String,
+    issues: &mut Vec<(String, String)>,
+) {
+    match (original, reserialized) {
+        (Value::Object(original_map), Value::Object(reserialized_map)) => {
+            for (key, original_value) in original_map {
+                let pointer_len = pointer.len();
+                push_pointer_token(pointer, key.as_str());
+                match reserialized_map.get(key) {
+                    Some(reserialized_value) => {
+                        check_unknown_fields(original_value, reserialized_value, pointer, issues)
+                    },
+                    None if !is_blank(original_value) => {
+                        issues.push((pointer.clone(), format!("unknown field `{}`", key)))
+                    },
+                    None => {},
+                }
+                pointer.truncate(pointer_len);
+            }
+        },
+        (Value::Array(original_items), Value::Array(reserialized_items)) => {
+            for (index, (original_item, reserialized_item)) in original_items
+                .iter()
+                .zip(reserialized_items.iter())
+                .enumerate()
+            {
+                let pointer_len = pointer.len();
+                push_pointer_token(pointer, index.to_string().as_str());
+                check_unknown_fields(original_item, reserialized_item, pointer, issues);
+                pointer.truncate(pointer_len);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Empty values are legitimately skipped when serializing.
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+/// Locates the first value expression in the JSON subtree that fails to interpret.
+/// On success, the pointer is left pointing at the offending element.
+fn find_malformed_expression(
+    json: &Value,
+    parent_field: Option<&str>,
+    context: &InterpreterContext,
+    pointer: &mut String,
+) -> Option<(String, String)> {
+    let pointer_len = pointer.len();
+    match json {
+        Value::String(s) => {
+            for expr in expression_parts(s, parent_field == Some(STORAGE_VALUE)) {
+                if let Err(message) = try_interpret(expr, context) {
+                    return Some((s.clone(), message));
+                }
+            }
+        },
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                push_pointer_token(pointer, index.to_string().as_str());
+                let found = find_malformed_expression(item, parent_field, context, pointer);
+                if found.is_some() {
+                    return found;
+                }
+                pointer.truncate(pointer_len);
+            }
+        },
+        Value::Object(map) => {
+            let keys_are_expressions =
+                parent_field.map_or(false, |field| EXPRESSION_KEY_MAPS.contains(&field));
+            for (key, value) in map {
+                push_pointer_token(pointer, key.as_str());
+                if keys_are_expressions && key != "+" {
+                    let key_expr = key.strip_suffix("|*").unwrap_or(key);
+                    if let Err(message) = try_interpret(key_expr, context) {
+                        return Some((key.clone(), message));
+                    }
+                }
+                if keys_are_expressions || !NON_EXPRESSION_FIELDS.contains(&key.as_str()) {
+                    let field = match parent_field {
+                        Some("storage") => Some(STORAGE_VALUE),
+                        _ if keys_are_expressions => None,
+                        _ => Some(key.as_str()),
+                    };
+                    let found = find_malformed_expression(value, field, context, pointer);
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+                pointer.truncate(pointer_len);
+            }
+        },
+        _ => {},
+    }
+    None
+}
+
+/// Splits off the storage numeric matcher syntax, leaving only the value expressions.
+fn expression_parts(s: &str, is_storage_value: bool) -> Vec<&str> {
+    if s == "*" {
+        return Vec::new();
+    }
+    if is_storage_value {
+        for operator in [">=", "<=", ">", "<"] {
+            if let Some(bound) = s.strip_prefix(operator) {
+                return vec![bound.trim()];
+            }
+        }
+        if s.starts_with(|c: char| c.is_ascii_digit()) {
+            if let Some((min, max)) = s.split_once("..=") {
+                return vec![min.trim(), max.trim()];
+            }
+        }
+    }
+    vec![s]
+}
+
+fn try_interpret(expr: &str, context: &InterpreterContext) -> Result<(), String> {
+    panic::catch_unwind(|| interpret_string(expr, context))
+        .map(|_| ())
+        .map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown error".to_string()
+    }
+}
@@ -0,0 +1,51 @@
+use crate::serde_raw::ScenarioRaw;
+use serde::Serialize;
+use serde_json::Value;
+use std::{fs, io, path::Path};
+
+/// Serializes a scenario generated from the raw model:
+/// 4-space indentation, fields in the order of the raw model, sorted map keys and a trailing newline.
+pub fn serialize_scenario_raw(scenario: &ScenarioRaw) -> String {
+    to_pretty_string(scenario)
+}
+
+/// Parses scenario JSON and re-serializes it in the Denali layout:
+/// 4-space indentation, one field or array item per line and a trailing newline.
+///
+/// Only the layout changes: keys keep the order they were written in,
+/// and value expressions are kept as they are.
+/// The contents must be a valid scenario, but fields unknown to the raw model are not dropped,
+/// use `scenario_check` to report them.
+pub fn format_scenario(contents: &str) -> Result<String, serde_json::Error> {
+    let _: ScenarioRaw = serde_json::from_str(contents)?;
+    let json: Value = serde_json::from_str(contents)?;
+    Ok(to_pretty_string(&json))
+}
+
+fn to_pretty_string<T: Serialize>(value: &T) -> String {
+    let buf = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
+    value.serialize(&mut ser).unwrap();
+    let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+    serialized.push('\n');
+    serialized
+}
+
+/// Formats a scenario file in place. Returns `true` if the contents changed.
+pub fn format_scenario_file<P: AsRef<Path>>(path: P) -> io::Result<bool> {
+    let contents = fs::read_to_string(path.as_ref())?;
+    let formatted = format_scenario(contents.as_str())?;
+    if formatted == contents {
+        return Ok(false);
+    }
+    fs::write(path.as_ref(), formatted)?;
+    Ok(true)
+}
+
+/// Returns `true` if the scenario file is already formatted.
+pub fn is_scenario_file_formatted<P: AsRef<Path>>(path: P) -> io::Result<bool> {
+    let contents = fs::read_to_string(path.as_ref())?;
+    let formatted = format_scenario(contents.as_str())?;
+    Ok(formatted == contents)
+}
@@ -7,6 +7,7 @@ mod dct_map_raw_check;
 mod dct_raw;
 mod dct_raw_check;
 mod instance_raw;
+mod token_properties_raw;
 
 pub use dct_data_raw_check::*;
 pub use dct_full_raw::*;
@@ -17,3 +18,4 @@ pub use dct_map_raw_check::*;
 pub use dct_raw::*;
 pub use dct_raw_check::*;
 pub use instance_raw::*;
+pub use token_properties_raw::*;
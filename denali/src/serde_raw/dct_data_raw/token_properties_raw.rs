@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serde_raw::ValueSubTree;
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPropertiesRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_freeze: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_wipe: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limited_transfer: Option<ValueSubTree>,
+}
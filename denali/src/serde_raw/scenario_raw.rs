@@ -19,6 +19,10 @@ pub struct ScenarioRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub check_gas: Option<bool>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_gas: Option<bool>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_schedule: Option<String>,
@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use crate::serde_raw::{
-    AccountRaw, BlockInfoRaw, CheckAccountsRaw, NewAddressRaw, TxCallRaw, TxDeployRaw, TxExpectRaw,
-    TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
+    AccountRaw, BlockInfoRaw, CheckAccountsRaw, NewAddressRaw, TokenPropertiesRaw, TxCallRaw,
+    TxDeployRaw, TxExpectRaw, TxQueryRaw, TxTransferRaw, TxValidatorRewardRaw, ValueSubTree,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -33,6 +33,10 @@ pub enum StepRaw {
         #[serde(skip_serializing_if = "Vec::is_empty")]
         new_addresses: Vec<NewAddressRaw>,
 
+        #[serde(default)]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        token_properties: BTreeMap<String, TokenPropertiesRaw>,
+
         #[serde(default)]
         #[serde(skip_serializing_if = "Vec::is_empty")]
         block_hashes: Vec<ValueSubTree>,
@@ -142,5 +146,31 @@ pub enum StepRaw {
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         comment: Option<String>,
+
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
+
+    SaveSnapshot {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+
+        name: String,
+    },
+
+    LoadSnapshot {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+
+        name: String,
+    },
+
+    ExecuteCrossShardCalls {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
     },
 }
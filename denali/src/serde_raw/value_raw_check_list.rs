@@ -0,0 +1,89 @@
+use super::CheckBytesValueRaw;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+use std::fmt;
+
+/// A list of checked values, e.g. the URIs of an NFT.
+/// Can be either a JSON list or the "*" wildcard.
+#[derive(Default)]
+pub enum CheckValueListRaw {
+    #[default]
+    Unspecified,
+    Star,
+    CheckList(Vec<CheckBytesValueRaw>),
+}
+
+impl CheckValueListRaw {
+    pub fn is_star(&self) -> bool {
+        matches!(self, CheckValueListRaw::Star)
+    }
+
+    pub fn is_unspecified(&self) -> bool {
+        matches!(self, CheckValueListRaw::Unspecified)
+    }
+}
+
+impl Serialize for CheckValueListRaw {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            CheckValueListRaw::Unspecified => serializer.serialize_str(""),
+            CheckValueListRaw::Star => serializer.serialize_str("*"),
+            CheckValueListRaw::CheckList(list) => {
+                let mut seq = serializer.serialize_seq(Some(list.len()))?;
+                for item in list {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            },
+        }
+    }
+}
+
+struct CheckValueListRawVisitor;
+
+impl<'de> Visitor<'de> for CheckValueListRawVisitor {
+    type Value = CheckValueListRaw;
+
+    // Format a message stating what data this Visitor expects to receive.
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("serialized CheckValueListRaw")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value == "*" {
+            Ok(CheckValueListRaw::Star)
+        } else {
+            Err(de::Error::custom("only '*' allowed as single value"))
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = Vec::<CheckBytesValueRaw>::new();
+
+        while let Some(item) = seq.next_element()? {
+            list.push(item);
+        }
+
+        Ok(CheckValueListRaw::CheckList(list))
+    }
+}
+
+impl<'de> Deserialize<'de> for CheckValueListRaw {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(CheckValueListRawVisitor)
+    }
+}
@@ -1,3 +1,4 @@
+use bech32::FromBase32;
 use sha3::{Digest, Keccak256};
 
 const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
@@ -61,3 +62,18 @@ pub(crate) fn address_expression(input: &str) -> Vec<u8> {
 pub(crate) fn sc_address_expression(input: &str) -> Vec<u8> {
     create_address_optional_shard_id(input, SC_ADDRESS_NUM_LEADING_ZEROS)
 }
+
+/// Decodes a bech32 address, as they appear on the real blockchain (e.g. `moa1...`).
+pub(crate) fn bech32_address_expression(input: &str) -> Vec<u8> {
+    let (_, data, _) = bech32::decode(input)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{}`: {}", input, err));
+    let address = Vec::<u8>::from_base32(&data)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{}`: {}", input, err));
+    assert!(
+        address.len() == 32,
+        "bech32 address `{}` should decode to 32 bytes, got {}",
+        input,
+        address.len()
+    );
+    address
+}
@@ -59,6 +59,10 @@ pub fn interpret_string(s: &str, context: &InterpreterContext) -> Vec<u8> {
         return sc_address_expression(stripped);
     }
 
+    if let Some(stripped) = s.strip_prefix(BECH32_PREFIX) {
+        return bech32_address_expression(stripped);
+    }
+
     if let Some(stripped) = s.strip_prefix(FILE_PREFIX) {
         return load_file(stripped, context);
     }
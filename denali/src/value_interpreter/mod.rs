@@ -3,5 +3,7 @@ mod functions;
 mod interpreter;
 mod parse_num;
 mod prefixes;
+mod reconstructor;
 
 pub use interpreter::{interpret_string, interpret_subtree};
+pub use reconstructor::{reconstruct, ExprReconstructorHint};
@@ -2,6 +2,7 @@ pub(super) const STR_PREFIXES: &[&str] = &["str:", "``", "''"];
 
 pub(super) const ADDR_PREFIX: &str = "address:";
 pub(super) const SC_ADDR_PREFIX: &str = "sc:";
+pub(super) const BECH32_PREFIX: &str = "bech32:";
 pub(super) const FILE_PREFIX: &str = "file:";
 pub(super) const KECCAK256_PREFIX: &str = "keccak256:";
 
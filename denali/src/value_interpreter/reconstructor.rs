@@ -0,0 +1,205 @@
+use bech32::{ToBase32, Variant};
+use num_bigint::BigUint;
+
+use crate::interpret_trait::InterpreterContext;
+
+use super::{interpreter::interpret_string, prefixes::*};
+
+/// What is known about the value being reconstructed, if anything.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExprReconstructorHint {
+    NoHint,
+    UnsignedNumber,
+    Address,
+    Str,
+}
+
+const ADDRESS_LENGTH: usize = 32;
+const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
+const BECH32_HRP: &str = "moa";
+
+/// Values longer than this are not considered plausible numbers, unless hinted otherwise.
+const MAX_PLAUSIBLE_NUMBER_LENGTH: usize = 16;
+
+/// Strings shorter than this are more likely to be small numbers, unless hinted otherwise.
+const MIN_PLAUSIBLE_STR_LENGTH: usize = 3;
+
+/// The reverse of the value interpreter: renders bytes as the most plausible denali expression.
+/// The result always interprets back to the same bytes. Hex is the fallback if nothing more readable fits.
+pub fn reconstruct(value: &[u8], hint: ExprReconstructorHint) -> String {
+    if value.is_empty() {
+        return String::new();
+    }
+
+    let candidate = match hint {
+        ExprReconstructorHint::NoHint => reconstruct_address_expr(value)
+            .or_else(|| reconstruct_plausible_str(value))
+            .or_else(|| reconstruct_str_concat(value))
+            .or_else(|| reconstruct_nested(value))
+            .or_else(|| reconstruct_fixed_width(value))
+            .or_else(|| reconstruct_number(value, MAX_PLAUSIBLE_NUMBER_LENGTH)),
+        ExprReconstructorHint::UnsignedNumber => reconstruct_number(value, usize::MAX),
+        ExprReconstructorHint::Address => {
+            reconstruct_address_expr(value).or_else(|| reconstruct_bech32(value))
+        },
+        ExprReconstructorHint::Str => reconstruct_str(value),
+    };
+
+    candidate
+        .filter(|expr| interprets_to(expr, value))
+        .unwrap_or_else(|| hex_expr(value))
+}
+
+fn interprets_to(expr: &str, value: &[u8]) -> bool {
+    interpret_string(expr, &InterpreterContext::default()).as_slice() == value
+}
+
+fn hex_expr(value: &[u8]) -> String {
+    format!("0x{}", hex::encode(value))
+}
+
+/// Characters that have no special meaning inside an expression.
+fn is_plain_char(c: u8) -> bool {
+    (c.is_ascii_graphic() || c == b' ') && c != b'|' && c != b'#'
+}
+
+fn reconstruct_str(value: &[u8]) -> Option<String> {
+    if !value.iter().all(|c| is_plain_char(*c)) {
+        return None;
+    }
+    Some(format!(
+        "{}{}",
+        STR_PREFIXES[0],
+        core::str::from_utf8(value).ok()?
+    ))
+}
+
+/// Without a hint, only strings that contain letters and are not too short are considered.
+fn reconstruct_plausible_str(value: &[u8]) -> Option<String> {
+    if value.len() < MIN_PLAUSIBLE_STR_LENGTH || !value.iter().any(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    reconstruct_str(value)
+}
+
+/// Values such as storage keys often consist of a readable prefix followed by some encoded data.
+/// Keys ending in an address are common enough to be checked for first.
+fn reconstruct_str_concat(value: &[u8]) -> Option<String> {
+    if value.len() > ADDRESS_LENGTH {
+        let (prefix, address) = value.split_at(value.len() - ADDRESS_LENGTH);
+        if let Some(prefix_expr) = reconstruct_plausible_str(prefix) {
+            let address_expr = reconstruct(address, ExprReconstructorHint::Address);
+            return Some(format!("{}|{}", prefix_expr, address_expr));
+        }
+    }
+
+    let prefix_length = value.iter().take_while(|c| is_plain_char(**c)).count();
+    let (prefix, rest) = value.split_at(prefix_length);
+    if rest.is_empty() {
+        return None;
+    }
+    let prefix_expr = reconstruct_plausible_str(prefix)?;
+    let rest_expr = reconstruct(rest, ExprReconstructorHint::NoHint);
+    Some(format!("{}|{}", prefix_expr, rest_expr))
+}
+
+/// Addresses generated by `address:` and `sc:` expressions.
+fn reconstruct_address_expr(value: &[u8]) -> Option<String> {
+    if value.len() != ADDRESS_LENGTH {
+        return None;
+    }
+    let (prefix, name) = if value[..SC_ADDRESS_NUM_LEADING_ZEROS]
+        .iter()
+        .all(|b| *b == 0)
+    {
+        (SC_ADDR_PREFIX, &value[SC_ADDRESS_NUM_LEADING_ZEROS..])
+    } else {
+        (ADDR_PREFIX, value)
+    };
+
+    let (name, shard_id) = if name.iter().all(|c| is_plain_char(*c)) {
+        (name, None)
+    } else {
+        let (last, name) = name.split_last()?;
+        (name, Some(*last))
+    };
+    if !name.iter().all(|c| is_plain_char(*c)) {
+        return None;
+    }
+
+    let name = core::str::from_utf8(name).ok()?.trim_end_matches('_');
+    match shard_id {
+        Some(shard_id) => Some(format!("{}{}#{:02x}", prefix, name, shard_id)),
+        None => Some(format!("{}{}", prefix, name)),
+    }
+}
+
+fn reconstruct_bech32(value: &[u8]) -> Option<String> {
+    if value.len() != ADDRESS_LENGTH {
+        return None;
+    }
+    let bech32_address = bech32::encode(BECH32_HRP, value.to_base32(), Variant::Bech32).ok()?;
+    Some(format!("{}{}", BECH32_PREFIX, bech32_address))
+}
+
+/// Numbers are only rendered as such if they have the minimal big endian encoding.
+fn reconstruct_number(value: &[u8], max_length: usize) -> Option<String> {
+    if value.len() > max_length || value[0] == 0 {
+        return None;
+    }
+    Some(BigUint::from_bytes_be(value).to_string())
+}
+
+/// Fixed width numbers have leading zeros, which regular numbers don't.
+fn reconstruct_fixed_width(value: &[u8]) -> Option<String> {
+    let prefix = match value.len() {
+        8 => U64_PREFIX,
+        4 => U32_PREFIX,
+        _ => return None,
+    };
+    if value[0] != 0 {
+        return None;
+    }
+    Some(format!("{}{}", prefix, BigUint::from_bytes_be(value)))
+}
+
+/// Splits the value into length-prefixed items, then renders each of them as `nested:` or `biguint:`.
+fn reconstruct_nested(value: &[u8]) -> Option<String> {
+    let mut items = Vec::new();
+    let mut remaining = value;
+    while !remaining.is_empty() {
+        if remaining.len() < 4 {
+            return None;
+        }
+        let (length_bytes, rest) = remaining.split_at(4);
+        let length = u32::from_be_bytes([
+            length_bytes[0],
+            length_bytes[1],
+            length_bytes[2],
+            length_bytes[3],
+        ]) as usize;
+        if rest.len() < length {
+            return None;
+        }
+        let (item, rest) = rest.split_at(length);
+        items.push(reconstruct_nested_item(item)?);
+        remaining = rest;
+    }
+    Some(items.join("|"))
+}
+
+fn reconstruct_nested_item(item: &[u8]) -> Option<String> {
+    if item.is_empty() {
+        return Some(NESTED_PREFIX.to_string());
+    }
+    if let Some(number) = reconstruct_number(item, MAX_PLAUSIBLE_NUMBER_LENGTH) {
+        if reconstruct_plausible_str(item).is_none() {
+            return Some(format!("{}{}", BIGUINT_PREFIX, number));
+        }
+    }
+    let inner = reconstruct_address_expr(item)
+        .or_else(|| reconstruct_str(item))
+        .or_else(|| reconstruct_fixed_width(item))
+        .unwrap_or_else(|| hex_expr(item));
+    Some(format!("{}{}", NESTED_PREFIX, inner))
+}
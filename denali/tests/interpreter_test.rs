@@ -126,6 +126,29 @@ fn test_sc_address_with_shard_id() {
     );
 }
 
+#[test]
+fn test_bech32() {
+    let context = &InterpreterContext::default();
+
+    assert_eq!(
+        hex::decode("000000000000000000010000000000000000000000000000000000000002ffff").unwrap(),
+        interpret_string(
+            "bech32:moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxv",
+            context
+        )
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_bech32_bad_checksum() {
+    let context = &InterpreterContext::default();
+    interpret_string(
+        "bech32:moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxw",
+        context,
+    );
+}
+
 #[test]
 fn test_unsigned_number() {
     let context = &InterpreterContext::default();
@@ -0,0 +1,131 @@
+use denali::{
+    interpret_trait::InterpreterContext,
+    value_interpreter::{interpret_string, reconstruct, ExprReconstructorHint},
+};
+
+extern crate denali;
+
+fn reconstruct_no_hint(s: &str) -> String {
+    let value = interpret_string(s, &InterpreterContext::default());
+    reconstruct(value.as_slice(), ExprReconstructorHint::NoHint)
+}
+
+fn reconstruct_with_hint(s: &str, hint: ExprReconstructorHint) -> String {
+    let value = interpret_string(s, &InterpreterContext::default());
+    reconstruct(value.as_slice(), hint)
+}
+
+#[test]
+fn test_reconstruct_empty() {
+    assert_eq!("", reconstruct(&[], ExprReconstructorHint::NoHint));
+    assert_eq!("", reconstruct(&[], ExprReconstructorHint::Address));
+}
+
+#[test]
+fn test_reconstruct_str() {
+    assert_eq!("str:value-a", reconstruct_no_hint("str:value-a"));
+    assert_eq!("str:hello world", reconstruct_no_hint("str:hello world"));
+    // too short to be considered a string without a hint
+    assert_eq!("25185", reconstruct_no_hint("str:ba"));
+    assert_eq!(
+        "str:ba",
+        reconstruct_with_hint("str:ba", ExprReconstructorHint::Str)
+    );
+    // the separator cannot appear in a string expression
+    assert_eq!(
+        "0x617c62",
+        reconstruct_with_hint("0x617c62", ExprReconstructorHint::Str)
+    );
+}
+
+#[test]
+fn test_reconstruct_address() {
+    assert_eq!("address:owner", reconstruct_no_hint("address:owner"));
+    assert_eq!("sc:adder", reconstruct_no_hint("sc:adder"));
+    assert_eq!("address:a#05", reconstruct_no_hint("address:a#05"));
+    assert_eq!("sc:a#05", reconstruct_no_hint("sc:a#05"));
+    assert_eq!(
+        "bech32:moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxv",
+        reconstruct_with_hint(
+            "0x000000000000000000010000000000000000000000000000000000000002ffff",
+            ExprReconstructorHint::Address
+        )
+    );
+    // no hint, no bech32
+    assert_eq!(
+        "0x000000000000000000010000000000000000000000000000000000000002ffff",
+        reconstruct_no_hint("0x000000000000000000010000000000000000000000000000000000000002ffff")
+    );
+}
+
+#[test]
+fn test_reconstruct_numbers() {
+    assert_eq!("5", reconstruct_no_hint("5"));
+    assert_eq!("1000", reconstruct_no_hint("1,000"));
+    assert_eq!("u64:5", reconstruct_no_hint("u64:5"));
+    assert_eq!("u32:1", reconstruct_no_hint("u32:1"));
+    assert_eq!("0x00", reconstruct_no_hint("0x00"));
+    assert_eq!(
+        "1000000000000000000000000000000000000",
+        reconstruct_with_hint(
+            "1000000000000000000000000000000000000",
+            ExprReconstructorHint::UnsignedNumber
+        )
+    );
+}
+
+#[test]
+fn test_reconstruct_nested() {
+    assert_eq!("biguint:5", reconstruct_no_hint("biguint:5"));
+    assert_eq!(
+        "nested:str:abc|biguint:1000|nested:",
+        reconstruct_no_hint("nested:str:abc|biguint:1000|nested:")
+    );
+    assert_eq!(
+        "nested:address:owner|nested:u64:7",
+        reconstruct_no_hint("nested:address:owner|nested:u64:7")
+    );
+}
+
+#[test]
+fn test_reconstruct_round_trip() {
+    let context = &InterpreterContext::default();
+    for expr in [
+        "0x00000005",
+        "0x0000000100",
+        "0xff",
+        "str:a|0x00",
+        "nested:nested:str:abc",
+        "u64:0|u32:7",
+        "address:a#ff",
+    ] {
+        let value = interpret_string(expr, context);
+        for hint in [
+            ExprReconstructorHint::NoHint,
+            ExprReconstructorHint::UnsignedNumber,
+            ExprReconstructorHint::Address,
+            ExprReconstructorHint::Str,
+        ] {
+            let reconstructed = reconstruct(value.as_slice(), hint);
+            assert_eq!(value, interpret_string(&reconstructed, context));
+        }
+    }
+}
+
+#[test]
+fn test_reconstruct_str_concat() {
+    assert_eq!(
+        "str:users.item|u32:1",
+        reconstruct_no_hint("str:users.item|u32:1")
+    );
+    assert_eq!(
+        "str:balance|sc:contract",
+        reconstruct_no_hint("str:balance|sc:contract")
+    );
+    assert_eq!(
+        "str:balance|bech32:moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxv",
+        reconstruct_no_hint(
+            "str:balance|0x000000000000000000010000000000000000000000000000000000000002ffff"
+        )
+    );
+}
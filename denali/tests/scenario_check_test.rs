@@ -0,0 +1,190 @@
+use denali::scenario_check::{check_scenario, ScenarioError};
+use std::path::Path;
+
+fn check(contents: &str) -> Vec<ScenarioError> {
+    check_scenario(contents, Path::new("./test.scen.json"))
+}
+
+fn positions_and_messages(errors: &[ScenarioError]) -> Vec<(usize, usize, &str)> {
+    errors
+        .iter()
+        .map(|error| (error.line, error.column, error.message.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_check_valid() {
+    let contents = r#"{
+    "name": "valid",
+    "traceGas": true,
+    "steps": [
+        {
+            "step": "externalSteps",
+            "path": "serialized.scen.json"
+        },
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "0",
+                    "balance": "1,000",
+                    "storage": {
+                        "str:counter": "5"
+                    }
+                }
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "*",
+                    "balance": "1,000",
+                    "storage": {
+                        "str:counter": ">=1",
+                        "str:user|*": "1..=10"
+                    },
+                    "code": "*"
+                }
+            }
+        }
+    ]
+}"#;
+    assert!(check(contents).is_empty());
+}
+
+#[test]
+fn test_check_syntax_error() {
+    let contents = "{\n    \"steps\": [\n        {,\n    ]\n}";
+    let errors = check(contents);
+    assert_eq!(errors.len(), 1);
+    assert_eq!((errors[0].line, errors[0].column), (3, 10));
+    assert_eq!(errors[0].message, "key must be a string");
+}
+
+#[test]
+fn test_check_unknown_fields() {
+    let contents = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {
+                    "nonce": "0",
+                    "balanse": "1,000"
+                }
+            }
+        }
+    ],
+    "gasTrace": true
+}"#;
+    let errors = check(contents);
+    assert_eq!(
+        positions_and_messages(&errors),
+        vec![
+            (8, 21, "unknown field `balanse`"),
+            (13, 5, "unknown field `gasTrace`"),
+        ]
+    );
+}
+
+#[test]
+fn test_check_malformed_expressions() {
+    let contents = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:owner": {
+                    "balance": "1,000",
+                    "storage": {
+                        "str:counter": "5x"
+                    }
+                }
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "addres:owner": {
+                    "storage": {
+                        "str:counter": ">=0xzz"
+                    }
+                }
+            }
+        }
+    ]
+}"#;
+    let errors = check(contents);
+    assert_eq!(
+        positions_and_messages(&errors),
+        vec![
+            (
+                9,
+                25,
+                "malformed value expression `5x`: Could not parse base 10 number: 5x"
+            ),
+            (
+                17,
+                17,
+                "malformed value expression `addres:owner`: Could not parse base 10 number: addres:owner"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_check_duplicate_tx_ids() {
+    let contents = r#"{
+    "steps": [
+        {
+            "step": "transfer",
+            "txId": "1",
+            "tx": {
+                "from": "address:a",
+                "to": "address:b",
+                "value": "1"
+            }
+        },
+        {
+            "step": "transfer",
+            "txId": "1",
+            "tx": {
+                "from": "address:a",
+                "to": "address:b",
+                "value": "2"
+            }
+        }
+    ]
+}"#;
+    let errors = check(contents);
+    assert_eq!(
+        positions_and_messages(&errors),
+        vec![(14, 13, "duplicate tx id `1`, first used at line 5")]
+    );
+}
+
+#[test]
+fn test_check_missing_external_steps() {
+    let contents = r#"{
+    "steps": [
+        {
+            "step": "externalSteps",
+            "path": "steps/missing.steps.json"
+        }
+    ]
+}"#;
+    let errors = check(contents);
+    assert_eq!(
+        positions_and_messages(&errors),
+        vec![(
+            5,
+            13,
+            "external steps file not found: steps/missing.steps.json"
+        )]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "./test.scen.json:5:13: external steps file not found: steps/missing.steps.json"
+    );
+}
@@ -0,0 +1,63 @@
+use denali::scenario_fmt::format_scenario;
+use std::fs;
+
+#[test]
+fn test_format_canonical_unchanged() {
+    let contents = fs::read_to_string("./serialized.scen.json").unwrap();
+    assert_eq!(format_scenario(contents.as_str()).unwrap(), contents);
+}
+
+#[test]
+fn test_format() {
+    let contents = r#"{"steps":[{"step":"setState","accounts":{"address:b":{"balance":"2","nonce":"0"},
+"address:a":{"nonce":"0","balance":"1"}}},{"txId":"1","step":"transfer","tx":{"value":"1","from":"address:a","to":"address:b"}}]}"#;
+    let expected = r#"{
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:b": {
+                    "balance": "2",
+                    "nonce": "0"
+                },
+                "address:a": {
+                    "nonce": "0",
+                    "balance": "1"
+                }
+            }
+        },
+        {
+            "txId": "1",
+            "step": "transfer",
+            "tx": {
+                "value": "1",
+                "from": "address:a",
+                "to": "address:b"
+            }
+        }
+    ]
+}
+"#;
+    assert_eq!(format_scenario(contents).unwrap(), expected);
+}
+
+#[test]
+fn test_format_keeps_unknown_fields_and_expressions() {
+    let contents = r#"{"traceGas":true,"steps":[{"step":"setState","accounts":{"address:a":{"balance":"1,000","unknown":"0x01"}}}]}"#;
+    let expected = r#"{
+    "traceGas": true,
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:a": {
+                    "balance": "1,000",
+                    "unknown": "0x01"
+                }
+            }
+        }
+    ]
+}
+"#;
+    assert_eq!(format_scenario(contents).unwrap(), expected);
+}
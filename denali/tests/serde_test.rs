@@ -30,6 +30,7 @@ fn test_ser() {
         name: None,
         comment: Some("comment".to_string()),
         check_gas: Some(false),
+        trace_gas: None,
         gas_schedule: Some("dummy".to_string()),
         steps: vec![StepRaw::ExternalSteps {
             comment: None,
@@ -0,0 +1,218 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, LitStr, Token,
+};
+
+const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+
+pub struct DenaliTestsInput {
+    dir: LitStr,
+    world: syn::Path,
+    exclude: Vec<LitStr>,
+    exclude_rs: Vec<LitStr>,
+    exclude_go: Vec<LitStr>,
+    wasm: Vec<LitStr>,
+}
+
+impl Parse for DenaliTestsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let dir = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let world = input.parse()?;
+        let mut result = DenaliTestsInput {
+            dir,
+            world,
+            exclude: Vec::new(),
+            exclude_rs: Vec::new(),
+            exclude_go: Vec::new(),
+            wasm: Vec::new(),
+        };
+
+        while input.parse::<Option<Token![,]>>()?.is_some() {
+            if input.is_empty() {
+                break;
+            }
+            let option: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let content;
+            bracketed!(content in input);
+            let names: Punctuated<LitStr, Token![,]> = Punctuated::parse_terminated(&content)?;
+            let names = names.into_iter().collect();
+            match option.to_string().as_str() {
+                "exclude" => result.exclude = names,
+                "exclude_rs" => result.exclude_rs = names,
+                "exclude_go" => result.exclude_go = names,
+                "wasm" => result.wasm = names,
+                _ => {
+                    return Err(syn::Error::new(
+                        option.span(),
+                        "expected `exclude`, `exclude_rs`, `exclude_go` or `wasm`",
+                    ))
+                },
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn scenario_dir_path(dir: &LitStr) -> PathBuf {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let mut dir_path = PathBuf::from(manifest_dir);
+    dir_path.push(dir.value());
+    dir_path
+}
+
+/// Scenario names, i.e. file names without the `.scen.json` suffix, in alphabetical order.
+fn list_scenarios(dir_path: &Path, dir: &LitStr) -> syn::Result<Vec<String>> {
+    let read_dir = fs::read_dir(dir_path).map_err(|err| {
+        syn::Error::new(
+            dir.span(),
+            format!("could not read directory {}: {}", dir_path.display(), err),
+        )
+    })?;
+
+    let mut names: Vec<String> = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            file_name
+                .strip_suffix(SCENARIO_FILE_SUFFIX)
+                .map(|name| name.to_string())
+        })
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+fn check_listed_exist(listed: &[LitStr], names: &[String]) -> syn::Result<()> {
+    for name in listed {
+        if !names.contains(&name.value()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!(
+                    "scenario not found: {}{}",
+                    name.value(),
+                    SCENARIO_FILE_SUFFIX
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_listed(listed: &[LitStr], name: &str) -> bool {
+    listed.iter().any(|listed_name| listed_name.value() == name)
+}
+
+/// Same naming as the hand-written tests, e.g. `multisig-add-board-member` -> `multisig_add_board_member`.
+fn test_name_prefix(name: &str) -> String {
+    let mut prefix: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if prefix.starts_with(|c: char| c.is_ascii_digit()) {
+        prefix.insert(0, '_');
+    }
+    prefix
+}
+
+fn ignore_attribute(ignored: bool) -> TokenStream {
+    if ignored {
+        quote! { #[ignore] }
+    } else {
+        quote! {}
+    }
+}
+
+pub fn denali_tests_impl(input: DenaliTestsInput) -> syn::Result<TokenStream> {
+    let dir_path = scenario_dir_path(&input.dir);
+    let names = list_scenarios(&dir_path, &input.dir)?;
+    check_listed_exist(&input.exclude, &names)?;
+    check_listed_exist(&input.exclude_rs, &names)?;
+    check_listed_exist(&input.exclude_go, &names)?;
+    check_listed_exist(&input.wasm, &names)?;
+
+    let dir = input.dir.value();
+    let world = &input.world;
+    let tests = names.iter().map(|name| {
+        let path = format!("{}/{}{}", dir, name, SCENARIO_FILE_SUFFIX);
+        let prefix = test_name_prefix(name);
+        let rs_name = Ident::new(format!("{}_rs", prefix).as_str(), Span::call_site());
+        let go_name = Ident::new(format!("{}_go", prefix).as_str(), Span::call_site());
+        let rs_ignore =
+            ignore_attribute(is_listed(&input.exclude, name) || is_listed(&input.exclude_rs, name));
+        let go_ignore =
+            ignore_attribute(is_listed(&input.exclude, name) || is_listed(&input.exclude_go, name));
+        let wasm_test = if is_listed(&input.wasm, name) {
+            let wasm_name = Ident::new(format!("{}_wasm", prefix).as_str(), Span::call_site());
+            let wasm_ignore = ignore_attribute(
+                is_listed(&input.exclude, name) || cfg!(not(feature = "denali-wasm-tests")),
+            );
+            quote! {
+                #[test]
+                #wasm_ignore
+                fn #wasm_name() {
+                    dharitri_wasm_debug::denali_wasm(#path, #world());
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #[test]
+            #rs_ignore
+            fn #rs_name() {
+                dharitri_wasm_debug::denali_rs(#path, #world());
+            }
+
+            #wasm_test
+
+            #[test]
+            #go_ignore
+            fn #go_name() {
+                dharitri_wasm_debug::denali_go(#path);
+            }
+        }
+    });
+
+    // the compiler only tracks files included in the generated code,
+    // so that removing or renaming a scenario triggers a rebuild
+    let scenario_file_paths = names.iter().map(|name| {
+        dir_path
+            .join(format!("{}{}", name, SCENARIO_FILE_SUFFIX))
+            .display()
+            .to_string()
+    });
+
+    let list_check_name = Ident::new(
+        format!("{}_scenario_list_up_to_date", test_name_prefix(&dir)).as_str(),
+        Span::call_site(),
+    );
+    Ok(quote! {
+        #(#tests)*
+
+        const _: &[&[u8]] = &[#(include_bytes!(#scenario_file_paths)),*];
+
+        #[test]
+        fn #list_check_name() {
+            dharitri_wasm_debug::check_denali_scenario_list(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(#dir),
+                &[#(#names),*],
+            );
+        }
+    })
+}
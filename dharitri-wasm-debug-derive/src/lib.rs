@@ -0,0 +1,34 @@
+extern crate proc_macro;
+
+mod denali_tests;
+
+use proc_macro::TokenStream;
+
+/// Generates one test per `*.scen.json` file in a directory, for each of the Rust and Go denali runners.
+///
+/// The directory is relative to the crate root. Example:
+///
+/// ```ignore
+/// denali_tests!("denali", world);
+/// denali_tests!("denali", world, exclude: ["only_in_go"], exclude_go: ["only_in_rust"]);
+/// denali_tests!("denali", world, wasm: ["also_compiled"]);
+/// ```
+///
+/// The `world` function provides the `BlockchainMock` for the Rust tests.
+/// Excluded scenarios still get their tests, but marked `#[ignore]`:
+/// `exclude` applies to both runners, `exclude_rs` and `exclude_go` to just one of them.
+///
+/// Scenarios listed in `wasm` also get a test running them against the compiled contracts, with `denali_wasm`.
+/// These need the contracts built first, so they are ignored unless the `denali-wasm-tests` feature
+/// of `dharitri-wasm-debug` is enabled.
+///
+/// Scenario files are listed when compiling. Changing or removing one of them triggers a rebuild,
+/// but adding a new one cannot be detected by the compiler, so an additional test checks at runtime
+/// that no scenario was added since, in which case the test file needs to be touched.
+#[proc_macro]
+pub fn denali_tests(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as denali_tests::DenaliTestsInput);
+    denali_tests::denali_tests_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
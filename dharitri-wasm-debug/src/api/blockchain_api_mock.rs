@@ -1,18 +1,20 @@
 use crate::{
-    world_mock::{is_smart_contract_address, DctInstance},
+    world_mock::{is_smart_contract_address, DctInstance, DHARITRI_API_COST},
     DebugApi,
 };
 use dharitri_wasm::types::{
-    Address, BigUint, DctLocalRole, DctLocalRoleFlags, DctTokenData, DctTokenType,
-    ManagedAddress, ManagedBuffer, ManagedVec, TokenIdentifier, H256,
+    Address, BigUint, DctLocalRole, DctLocalRoleFlags, DctTokenData, DctTokenType, ManagedAddress,
+    ManagedBuffer, ManagedVec, TokenIdentifier, H256,
 };
 
 impl dharitri_wasm::api::BlockchainApi for DebugApi {
     fn get_sc_address_legacy(&self) -> Address {
+        self.use_gas_for(DHARITRI_API_COST, "GetSCAddress");
         self.input_ref().to.clone()
     }
 
     fn get_owner_address_legacy(&self) -> Address {
+        self.use_gas_for(DHARITRI_API_COST, "GetOwnerAddress");
         self.with_contract_account(|account| {
             account
                 .contract_owner
@@ -21,19 +23,23 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
         })
     }
 
-    fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
-        panic!("get_shard_of_address not implemented")
+    fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
+        self.use_gas_for(DHARITRI_API_COST, "GetShardOfAddress");
+        self.blockchain_ref().shard_of_address(address)
     }
 
     fn is_smart_contract_legacy(&self, address: &Address) -> bool {
+        self.use_gas_for(DHARITRI_API_COST, "IsSmartContract");
         is_smart_contract_address(address)
     }
 
     fn get_caller_legacy(&self) -> Address {
+        self.use_gas_for(DHARITRI_API_COST, "GetCaller");
         self.input_ref().from.clone()
     }
 
     fn get_balance_legacy(&self, address: &Address) -> BigUint<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetExternalBalance");
         assert!(
             address == &self.get_sc_address_legacy(),
             "get balance not yet implemented for accounts other than the contract itself"
@@ -43,34 +49,42 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
     }
 
     fn get_state_root_hash_legacy(&self) -> H256 {
+        self.use_gas_for(DHARITRI_API_COST, "GetStateRootHash");
         panic!("get_state_root_hash_legacy not yet implemented")
     }
 
     fn get_tx_hash_legacy(&self) -> H256 {
+        self.use_gas_for(DHARITRI_API_COST, "GetOriginalTxHash");
         self.input_ref().tx_hash.clone()
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.use_gas_for(DHARITRI_API_COST, "GetGasLeft");
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockTimeStamp");
         self.blockchain_ref().current_block_info.block_timestamp
     }
 
     fn get_block_nonce(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockNonce");
         self.blockchain_ref().current_block_info.block_nonce
     }
 
     fn get_block_round(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockRound");
         self.blockchain_ref().current_block_info.block_round
     }
 
     fn get_block_epoch(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockEpoch");
         self.blockchain_ref().current_block_info.block_epoch
     }
 
     fn get_block_random_seed_legacy(&self) -> Box<[u8; 48]> {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockRandomSeed");
         self.blockchain_ref()
             .current_block_info
             .block_random_seed
@@ -78,22 +92,27 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
     }
 
     fn get_prev_block_timestamp(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockTimeStamp");
         self.blockchain_ref().previous_block_info.block_timestamp
     }
 
     fn get_prev_block_nonce(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockNonce");
         self.blockchain_ref().previous_block_info.block_nonce
     }
 
     fn get_prev_block_round(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockRound");
         self.blockchain_ref().previous_block_info.block_round
     }
 
     fn get_prev_block_epoch(&self) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockEpoch");
         self.blockchain_ref().previous_block_info.block_epoch
     }
 
     fn get_prev_block_random_seed_legacy(&self) -> Box<[u8; 48]> {
+        self.use_gas_for(DHARITRI_API_COST, "GetBlockRandomSeed");
         self.blockchain_ref()
             .previous_block_info
             .block_random_seed
@@ -105,6 +124,7 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
         address: &ManagedAddress<Self>,
         token: &TokenIdentifier<Self>,
     ) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetCurrentDCTNFTNonce");
         assert!(
             address == &self.get_sc_address(),
             "get_current_dct_nft_nonce not yet implemented for accounts other than the contract itself"
@@ -124,6 +144,7 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
         token: &TokenIdentifier<Self>,
         nonce: u64,
     ) -> BigUint<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetExternalBalance");
         assert!(
             address == &self.get_sc_address(),
             "get_dct_balance not yet implemented for accounts other than the contract itself"
@@ -143,21 +164,25 @@ impl dharitri_wasm::api::BlockchainApi for DebugApi {
         token: &TokenIdentifier<Self>,
         nonce: u64,
     ) -> DctTokenData<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetExternalBalance");
         self.blockchain_cache()
             .with_account(&address.to_address(), |account| {
+                let token_identifier = token.to_dct_identifier();
+                let frozen = account.dct.is_frozen(token_identifier.as_slice(), nonce);
                 let instance = account
                     .dct
-                    .get_by_identifier(token.to_dct_identifier().as_slice())
+                    .get_by_identifier(token_identifier.as_slice())
                     .unwrap()
                     .instances
                     .get_by_nonce(nonce)
                     .unwrap();
 
-                self.dct_token_data_from_instance(nonce, instance)
+                self.dct_token_data_from_instance(nonce, instance, frozen)
             })
     }
 
     fn get_dct_local_roles(&self, token_id: &TokenIdentifier<Self>) -> DctLocalRoleFlags {
+        self.use_gas_for(DHARITRI_API_COST, "StorageLoad");
         let sc_address = self.input_ref().to.clone();
         self.blockchain_cache()
             .with_account(&sc_address, |account| {
@@ -181,6 +206,7 @@ impl DebugApi {
         &self,
         nonce: u64,
         instance: &DctInstance,
+        frozen: bool,
     ) -> DctTokenData<Self> {
         let creator = if let Some(creator) = &instance.metadata.creator {
             ManagedAddress::from_address(creator)
@@ -189,14 +215,14 @@ impl DebugApi {
         };
 
         let mut uris = ManagedVec::new();
-        if let Some(uri) = &instance.metadata.uri {
+        for uri in &instance.metadata.uris {
             uris.push(ManagedBuffer::new_from_bytes(uri.as_slice()));
         }
 
         DctTokenData {
             token_type: DctTokenType::based_on_token_nonce(nonce),
             amount: self.insert_new_big_uint(instance.balance.clone()),
-            frozen: false,
+            frozen,
             hash: self
                 .insert_new_managed_buffer(instance.metadata.hash.clone().unwrap_or_default()),
             name: self.insert_new_managed_buffer(instance.metadata.name.clone()),
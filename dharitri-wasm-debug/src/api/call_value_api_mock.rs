@@ -1,4 +1,4 @@
-use crate::{tx_mock::TxPanic, DebugApi};
+use crate::{tx_mock::TxPanic, world_mock::DHARITRI_API_COST, DebugApi};
 use dharitri_wasm::{
     api::CallValueApi,
     err_msg,
@@ -34,6 +34,7 @@ impl CallValueApi for DebugApi {
 
     #[inline]
     fn moax_value(&self) -> BigUint<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetCallValue");
         self.insert_new_big_uint(self.input_ref().moax_value.clone())
     }
 
@@ -63,11 +64,13 @@ impl CallValueApi for DebugApi {
 
     #[inline]
     fn dct_num_transfers(&self) -> usize {
+        self.use_gas_for(DHARITRI_API_COST, "GetNumDCTTransfers");
         self.input_ref().dct_values.len()
     }
 
     #[inline]
     fn dct_value_by_index(&self, index: usize) -> BigUint<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetDCTValueByIndex");
         if let Some(dct_value) = self.input_ref().dct_values.get(index) {
             self.insert_new_big_uint(dct_value.value.clone())
         } else {
@@ -77,6 +80,7 @@ impl CallValueApi for DebugApi {
 
     #[inline]
     fn token_by_index(&self, index: usize) -> TokenIdentifier<Self> {
+        self.use_gas_for(DHARITRI_API_COST, "GetDCTTokenNameByIndex");
         if let Some(dct_value) = self.input_ref().dct_values.get(index) {
             TokenIdentifier::from(
                 self.insert_new_managed_buffer(dct_value.token_identifier.clone()),
@@ -88,6 +92,7 @@ impl CallValueApi for DebugApi {
 
     #[inline]
     fn dct_token_nonce_by_index(&self, index: usize) -> u64 {
+        self.use_gas_for(DHARITRI_API_COST, "GetDCTTokenNonceByIndex");
         if let Some(dct_value) = self.input_ref().dct_values.get(index) {
             dct_value.nonce
         } else {
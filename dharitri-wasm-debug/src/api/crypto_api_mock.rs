@@ -1,13 +1,15 @@
-use crate::DebugApi;
+use crate::{crypto, world_mock::CRYPTO_API_COST, DebugApi};
 use dharitri_wasm::{
     api::CryptoApi,
     types::{BoxedBytes, MessageHashType, H256},
 };
+use ripemd160::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
 impl CryptoApi for DebugApi {
     fn sha256_legacy(&self, data: &[u8]) -> H256 {
+        self.use_gas_for(CRYPTO_API_COST, "SHA256");
         let mut hasher = Sha256::new();
         hasher.update(data);
         let hash: [u8; 32] = hasher.finalize().into();
@@ -15,39 +17,50 @@ impl CryptoApi for DebugApi {
     }
 
     fn keccak256_legacy(&self, data: &[u8]) -> H256 {
+        self.use_gas_for(CRYPTO_API_COST, "Keccak256");
         let mut hasher = Keccak256::new();
         hasher.update(data);
         let hash: [u8; 32] = hasher.finalize().into();
         hash.into()
     }
 
-    fn ripemd160(&self, _data: &[u8]) -> Box<[u8; 20]> {
-        panic!("ripemd160 not implemented yet!")
+    fn ripemd160(&self, data: &[u8]) -> Box<[u8; 20]> {
+        self.use_gas_for(CRYPTO_API_COST, "Ripemd160");
+        let mut hasher = Ripemd160::new();
+        hasher.update(data);
+        let hash: [u8; 20] = hasher.finalize().into();
+        Box::new(hash)
     }
 
-    fn verify_bls(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_bls not implemented yet!")
+    fn verify_bls(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.use_gas_for(CRYPTO_API_COST, "VerifyBLS");
+        crypto::verify_bls(key, message, signature)
     }
 
-    fn verify_ed25519(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_ed25519 not implemented yet!")
+    fn verify_ed25519(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.use_gas_for(CRYPTO_API_COST, "VerifyEd25519");
+        crypto::verify_ed25519(key, message, signature)
     }
 
-    fn verify_secp256k1(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+    /// The node hashes the message twice with SHA-256 before verifying it.
+    fn verify_secp256k1(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.use_gas_for(CRYPTO_API_COST, "VerifySecp256k1");
+        crypto::verify_secp256k1(key, message, signature, MessageHashType::ECDSADoubleSha256)
     }
 
     fn verify_custom_secp256k1(
         &self,
-        _key: &[u8],
-        _message: &[u8],
-        _signature: &[u8],
-        _hash_type: MessageHashType,
+        key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        self.use_gas_for(CRYPTO_API_COST, "VerifySecp256k1");
+        crypto::verify_secp256k1(key, message, signature, hash_type)
     }
 
-    fn encode_secp256k1_der_signature(&self, _r: &[u8], _s: &[u8]) -> BoxedBytes {
-        panic!("encode_secp256k1_signature not implemented yet!")
+    fn encode_secp256k1_der_signature(&self, r: &[u8], s: &[u8]) -> BoxedBytes {
+        self.use_gas_for(CRYPTO_API_COST, "EncodeDERSig");
+        BoxedBytes::from(crypto::encode_secp256k1_der_signature(r, s))
     }
 }
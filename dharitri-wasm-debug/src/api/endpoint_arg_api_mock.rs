@@ -1,4 +1,4 @@
-use crate::{tx_mock::TxPanic, DebugApi};
+use crate::{tx_mock::TxPanic, world_mock::DHARITRI_API_COST, DebugApi};
 use alloc::vec::Vec;
 use dharitri_wasm::{
     api::{EndpointArgumentApi, Handle},
@@ -11,6 +11,7 @@ use num_traits::cast::ToPrimitive;
 /// The smart contract code doesn't have access to these methods directly.
 impl EndpointArgumentApi for DebugApi {
     fn get_num_arguments(&self) -> i32 {
+        self.use_gas_for(DHARITRI_API_COST, "GetNumArguments");
         self.input_ref().args.len() as i32
     }
 
@@ -24,6 +25,7 @@ impl EndpointArgumentApi for DebugApi {
     }
 
     fn get_argument_vec_u8(&self, arg_index: i32) -> Vec<u8> {
+        self.use_gas_for(DHARITRI_API_COST, "GetArgument");
         let arg_idx_usize = arg_index as usize;
         assert!(
             arg_idx_usize < self.input_ref().args.len(),
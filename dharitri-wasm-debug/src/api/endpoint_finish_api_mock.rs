@@ -1,4 +1,4 @@
-use crate::DebugApi;
+use crate::{world_mock::DHARITRI_API_COST, DebugApi};
 use dharitri_wasm::api::{BigIntApi, EndpointFinishApi, Handle, ManagedBufferApi};
 use num_bigint::{BigInt, BigUint};
 
@@ -6,6 +6,7 @@ use num_bigint::{BigInt, BigUint};
 /// The smart contract code doesn't have access to these methods directly.
 impl EndpointFinishApi for DebugApi {
     fn finish_slice_u8(&self, slice: &[u8]) {
+        self.use_gas_for(DHARITRI_API_COST, "Finish");
         let mut v = vec![0u8; slice.len()];
         v.copy_from_slice(slice);
         let mut tx_result = self.result_borrow_mut();
@@ -13,12 +14,14 @@ impl EndpointFinishApi for DebugApi {
     }
 
     fn finish_big_int_raw(&self, handle: Handle) {
+        self.use_gas_for(DHARITRI_API_COST, "Finish");
         let bi_bytes = self.bi_get_signed_bytes(handle);
         let mut tx_result = self.result_borrow_mut();
         tx_result.result_values.push(bi_bytes.into_vec());
     }
 
     fn finish_big_uint_raw(&self, handle: Handle) {
+        self.use_gas_for(DHARITRI_API_COST, "Finish");
         let bu_bytes = self.bi_get_unsigned_bytes(handle);
         let mut tx_result = self.result_borrow_mut();
         tx_result.result_values.push(bu_bytes.into_vec());
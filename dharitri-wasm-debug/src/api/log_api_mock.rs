@@ -5,12 +5,13 @@ use dharitri_wasm::{
     },
 };
 
-use crate::{tx_mock::TxLog, DebugApi};
+use crate::{tx_mock::TxLog, world_mock::DHARITRI_API_COST, DebugApi};
 
 /// Interface to only be used by code generated by the macros.
 /// The smart contract code doesn't have access to these methods directly.
 impl LogApi for DebugApi {
     fn write_event_log(&self, topics_buffer: &ArgBuffer, data: &[u8]) {
+        self.use_gas_for(DHARITRI_API_COST, "Log");
         let arg_data_buffer = topics_buffer.arg_data();
         let arg_data_lengths = topics_buffer.arg_lengths();
 
@@ -35,6 +36,7 @@ impl LogApi for DebugApi {
     }
 
     fn write_legacy_log(&self, topics: &[[u8; 32]], data: &[u8]) {
+        self.use_gas_for(DHARITRI_API_COST, "Log");
         let topics_vec = topics.iter().map(|array| array.to_vec()).collect();
 
         let mut tx_result = self.result_borrow_mut();
@@ -1,4 +1,4 @@
-use crate::DebugApi;
+use crate::{world_mock::BIG_INT_API_COST, DebugApi};
 
 use core::{
     cmp::Ordering,
@@ -22,8 +22,9 @@ fn assert_positive(bi: &BigInt) {
 }
 
 macro_rules! binary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_name:expr) => {
         fn $method_name(&self, dest: Handle, x: Handle, y: Handle) {
+            self.use_gas_for(BIG_INT_API_COST, $gas_cost_name);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             let bi_y = managed_types.big_int_map.get(y);
@@ -34,8 +35,9 @@ macro_rules! binary_op_method {
 }
 
 macro_rules! binary_bitwise_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_name:expr) => {
         fn $method_name(&self, dest: Handle, x: Handle, y: Handle) {
+            self.use_gas_for(BIG_INT_API_COST, $gas_cost_name);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             assert_positive(&bi_x);
@@ -48,8 +50,9 @@ macro_rules! binary_bitwise_op_method {
 }
 
 macro_rules! unary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_name:expr) => {
         fn $method_name(&self, dest: Handle, x: Handle) {
+            self.use_gas_for(BIG_INT_API_COST, $gas_cost_name);
             let mut managed_types = self.m_types_borrow_mut();
             let bi_x = managed_types.big_int_map.get(x);
             let result = bi_x.$rust_op_name();
@@ -60,6 +63,7 @@ macro_rules! unary_op_method {
 
 impl BigIntApi for DebugApi {
     fn bi_new(&self, value: i64) -> Handle {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntNew");
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .big_int_map
@@ -71,6 +75,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_get_unsigned_bytes(&self, handle: Handle) -> BoxedBytes {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntGetUnsignedBytes");
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(handle);
         if bi.is_zero() {
@@ -82,6 +87,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_set_unsigned_bytes(&self, dest: Handle, bytes: &[u8]) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntSetUnsignedBytes");
         let mut managed_types = self.m_types_borrow_mut();
         let result = BigInt::from_bytes_be(num_bigint::Sign::Plus, bytes);
         managed_types.big_int_map.insert(dest, result);
@@ -92,6 +98,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_get_signed_bytes(&self, handle: Handle) -> BoxedBytes {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntGetSignedBytes");
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(handle);
         if bi.is_zero() {
@@ -102,21 +109,24 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_set_signed_bytes(&self, dest: Handle, bytes: &[u8]) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntSetSignedBytes");
         let mut managed_types = self.m_types_borrow_mut();
         let result = BigInt::from_signed_bytes_be(bytes);
         managed_types.big_int_map.insert(dest, result);
     }
 
     fn bi_to_i64(&self, handle: Handle) -> Option<i64> {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntGetInt64");
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(handle);
         big_int_to_i64(bi)
     }
 
-    binary_op_method! {bi_add, add}
-    binary_op_method! {bi_sub, sub}
+    binary_op_method! {bi_add, add, "BigIntAdd"}
+    binary_op_method! {bi_sub, sub, "BigIntSub"}
 
     fn bi_sub_unsigned(&self, dest: Handle, x: Handle, y: Handle) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntSub");
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
@@ -127,14 +137,15 @@ impl BigIntApi for DebugApi {
         managed_types.big_int_map.insert(dest, result);
     }
 
-    binary_op_method! {bi_mul, mul}
-    binary_op_method! {bi_t_div, div}
-    binary_op_method! {bi_t_mod, rem}
+    binary_op_method! {bi_mul, mul, "BigIntMul"}
+    binary_op_method! {bi_t_div, div, "BigIntTDiv"}
+    binary_op_method! {bi_t_mod, rem, "BigIntTMod"}
 
-    unary_op_method! {bi_abs, abs}
-    unary_op_method! {bi_neg, neg}
+    unary_op_method! {bi_abs, abs, "BigIntAbs"}
+    unary_op_method! {bi_neg, neg, "BigIntNeg"}
 
     fn bi_sign(&self, x: Handle) -> dharitri_wasm::api::Sign {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntSign");
         let managed_types = self.m_types_borrow();
         let bi = managed_types.big_int_map.get(x);
         match bi.sign() {
@@ -145,15 +156,17 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_cmp(&self, x: Handle, y: Handle) -> Ordering {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntCmp");
         let managed_types = self.m_types_borrow();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
         bi_x.cmp(bi_y)
     }
 
-    unary_op_method! {bi_sqrt, sqrt}
+    unary_op_method! {bi_sqrt, sqrt, "BigIntSqrt"}
 
     fn bi_pow(&self, dest: Handle, x: Handle, y: Handle) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntPow");
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         let bi_y = managed_types.big_int_map.get(y);
@@ -163,16 +176,18 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_log2(&self, x: Handle) -> u32 {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntLog");
         let managed_types = self.m_types_borrow();
         let bi_x = managed_types.big_int_map.get(x);
         bi_x.bits() as u32 - 1
     }
 
-    binary_bitwise_op_method! {bi_and, bitand}
-    binary_bitwise_op_method! {bi_or, bitor}
-    binary_bitwise_op_method! {bi_xor, bitxor}
+    binary_bitwise_op_method! {bi_and, bitand, "BigIntAnd"}
+    binary_bitwise_op_method! {bi_or, bitor, "BigIntOr"}
+    binary_bitwise_op_method! {bi_xor, bitxor, "BigIntXor"}
 
     fn bi_shr(&self, dest: Handle, x: Handle, bits: usize) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntShr");
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         assert_positive(bi_x);
@@ -181,6 +196,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_shl(&self, dest: Handle, x: Handle, bits: usize) {
+        self.use_gas_for(BIG_INT_API_COST, "BigIntShl");
         let mut managed_types = self.m_types_borrow_mut();
         let bi_x = managed_types.big_int_map.get(x);
         assert_positive(bi_x);
@@ -2,133 +2,271 @@ use dharitri_wasm::{
     api::{EllipticCurveApi, Handle},
     types::BoxedBytes,
 };
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
 
-use crate::DebugApi;
+use crate::{crypto::EllipticCurve, tx_mock::TxPanic, world_mock::CRYPTO_API_COST, DebugApi};
+
+fn ec_error(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.as_bytes().to_vec(),
+    })
+}
+
+impl DebugApi {
+    fn ec_get(&self, ec_handle: Handle) -> EllipticCurve {
+        let managed_types = self.m_types_borrow();
+        managed_types.elliptic_curve_map.get(ec_handle).clone()
+    }
+
+    fn ec_big_uint_get(&self, handle: Handle) -> Option<BigUint> {
+        let managed_types = self.m_types_borrow();
+        managed_types.big_int_map.get(handle).to_biguint()
+    }
+
+    fn ec_big_uint_set(&self, handle: Handle, value: BigUint) {
+        let mut managed_types = self.m_types_borrow_mut();
+        managed_types.big_int_map.insert(handle, value.into());
+    }
+
+    /// Loads a point, failing execution if it is not on the curve.
+    fn ec_point_on_curve(
+        &self,
+        ec: &EllipticCurve,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
+    ) -> (BigUint, BigUint) {
+        match (
+            self.ec_big_uint_get(x_point_handle),
+            self.ec_big_uint_get(y_point_handle),
+        ) {
+            (Some(x), Some(y)) if ec.is_on_curve(&x, &y) => (x, y),
+            _ => ec_error("point is not on curve"),
+        }
+    }
+
+    fn ec_set_result(
+        &self,
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        (x, y): (BigUint, BigUint),
+    ) {
+        self.ec_big_uint_set(x_result_handle, x);
+        self.ec_big_uint_set(y_result_handle, y);
+    }
+
+    /// Private keys are derived from the tx hash and the block random seed,
+    /// so that the same transaction always produces the same key.
+    fn ec_generate_private_key(&self, ec: &EllipticCurve, salt: Handle) -> Vec<u8> {
+        let key_length = ec.private_key_byte_length() as usize;
+        let mut counter = 0u32;
+        loop {
+            let mut private_key = Vec::with_capacity(key_length);
+            while private_key.len() < key_length {
+                let mut hasher = Sha256::new();
+                hasher.update(self.input_ref().tx_hash.as_bytes());
+                hasher.update(&self.blockchain_ref().current_block_info.block_random_seed[..]);
+                hasher.update(salt.to_be_bytes());
+                hasher.update(counter.to_be_bytes());
+                private_key.extend_from_slice(hasher.finalize().as_slice());
+                counter += 1;
+            }
+            private_key.truncate(key_length);
+
+            let scalar = BigUint::from_bytes_be(&private_key);
+            if scalar > BigUint::from(0u32) && scalar < ec.base_point_order {
+                return private_key;
+            }
+        }
+    }
+}
 
 impl EllipticCurveApi for DebugApi {
-    fn ec_create(&self, _name: &[u8]) -> Handle {
-        panic!("ec_create not implemented")
+    fn ec_create(&self, name: &[u8]) -> Handle {
+        let ec = EllipticCurve::from_name(name).unwrap_or_else(|| ec_error("curve not supported"));
+        let mut managed_types = self.m_types_borrow_mut();
+        managed_types.elliptic_curve_map.insert_new_handle(ec)
     }
 
     fn ec_get_values(
         &self,
-        _ec_handle: Handle,
-        _field_order_handle: Handle,
-        _base_point_order_handle: Handle,
-        _eq_constant_handle: Handle,
-        _x_base_point_handle: Handle,
-        _y_base_point_handle: Handle,
+        ec_handle: Handle,
+        field_order_handle: Handle,
+        base_point_order_handle: Handle,
+        eq_constant_handle: Handle,
+        x_base_point_handle: Handle,
+        y_base_point_handle: Handle,
     ) {
-        panic!("ec_get_values not implemented")
+        let ec = self.ec_get(ec_handle);
+        self.ec_big_uint_set(field_order_handle, ec.field_order);
+        self.ec_big_uint_set(base_point_order_handle, ec.base_point_order);
+        self.ec_big_uint_set(eq_constant_handle, ec.eq_constant);
+        self.ec_big_uint_set(x_base_point_handle, ec.x_base_point);
+        self.ec_big_uint_set(y_base_point_handle, ec.y_base_point);
     }
 
-    fn ec_curve_length(&self, _ec_handle: Handle) -> u32 {
-        panic!("ec_curve_length not implemented")
+    fn ec_curve_length(&self, ec_handle: Handle) -> u32 {
+        self.ec_get(ec_handle).size_of_field
     }
 
-    fn ec_private_key_byte_length(&self, _ec_handle: Handle) -> u32 {
-        panic!("ec_private_key_byte_length not implemented")
+    fn ec_private_key_byte_length(&self, ec_handle: Handle) -> u32 {
+        self.ec_get(ec_handle).private_key_byte_length()
     }
 
     fn ec_add(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_first_point: Handle,
-        _y_first_point: Handle,
-        _x_second_point: Handle,
-        _y_second_point: Handle,
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_first_point: Handle,
+        y_first_point: Handle,
+        x_second_point: Handle,
+        y_second_point: Handle,
     ) {
-        panic!("ec_add not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "AddECC");
+        let ec = self.ec_get(ec_handle);
+        let (x1, y1) = self.ec_point_on_curve(&ec, x_first_point, y_first_point);
+        let (x2, y2) = self.ec_point_on_curve(&ec, x_second_point, y_second_point);
+        self.ec_set_result(x_result_handle, y_result_handle, ec.add(&x1, &y1, &x2, &y2));
     }
 
     fn ec_double(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
     ) {
-        panic!("ec_double not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "DoubleECC");
+        let ec = self.ec_get(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&ec, x_point_handle, y_point_handle);
+        self.ec_set_result(x_result_handle, y_result_handle, ec.double(&x, &y));
     }
 
     fn ec_is_on_curve(
         &self,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
     ) -> bool {
-        panic!("ec_is_on_curve not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "IsOnCurveECC");
+        let ec = self.ec_get(ec_handle);
+        match (
+            self.ec_big_uint_get(x_point_handle),
+            self.ec_big_uint_get(y_point_handle),
+        ) {
+            (Some(x), Some(y)) => ec.is_on_curve(&x, &y),
+            _ => false,
+        }
     }
 
     fn ec_scalar_mult(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _x_point_handle: Handle,
-        _y_point_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        x_point_handle: Handle,
+        y_point_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_mult not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "ScalarMultECC");
+        let ec = self.ec_get(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&ec, x_point_handle, y_point_handle);
+        self.ec_set_result(
+            x_result_handle,
+            y_result_handle,
+            ec.scalar_mult(&x, &y, data),
+        );
     }
 
     fn ec_scalar_base_mult(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "ScalarMultECC");
+        let ec = self.ec_get(ec_handle);
+        self.ec_set_result(x_result_handle, y_result_handle, ec.scalar_base_mult(data));
     }
 
     fn ec_marshal(
         &self,
-        _ec_handle: Handle,
-        _x_pair_handle: Handle,
-        _y_pair_handle: Handle,
+        ec_handle: Handle,
+        x_pair_handle: Handle,
+        y_pair_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_marshal not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "MarshalECC");
+        let ec = self.ec_get(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&ec, x_pair_handle, y_pair_handle);
+        ec.marshal(&x, &y).into()
     }
 
     fn ec_marshal_compressed(
         &self,
-        _ec_handle: Handle,
-        _x_pair_handle: Handle,
-        _y_pair_handle: Handle,
+        ec_handle: Handle,
+        x_pair_handle: Handle,
+        y_pair_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_marshal_compressed not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "MarshalCompressECC");
+        let ec = self.ec_get(ec_handle);
+        let (x, y) = self.ec_point_on_curve(&ec, x_pair_handle, y_pair_handle);
+        ec.marshal_compressed(&x, &y).into()
     }
 
     fn ec_unmarshal(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "UnmarshalECC");
+        let ec = self.ec_get(ec_handle);
+        if data.len() != ec.marshal_byte_length() {
+            ec_error("length of buffer is not correct");
+        }
+        let point = ec
+            .unmarshal(data)
+            .unwrap_or_else(|| ec_error("point is not on curve"));
+        self.ec_set_result(x_result_handle, y_result_handle, point);
     }
 
     fn ec_unmarshal_compressed(
         &self,
-        _x_result_handle: Handle,
-        _y_result_handle: Handle,
-        _ec_handle: Handle,
-        _data: &[u8],
+        x_result_handle: Handle,
+        y_result_handle: Handle,
+        ec_handle: Handle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "UnmarshalCompressECC");
+        let ec = self.ec_get(ec_handle);
+        if data.len() != ec.marshal_compressed_byte_length() {
+            ec_error("length of buffer is not correct");
+        }
+        let point = ec
+            .unmarshal_compressed(data)
+            .unwrap_or_else(|| ec_error("point is not on curve"));
+        self.ec_set_result(x_result_handle, y_result_handle, point);
     }
 
     fn ec_generate_key(
         &self,
-        _x_pub_key_handle: Handle,
-        _y_pub_key_handle: Handle,
-        _ec_handle: Handle,
+        x_pub_key_handle: Handle,
+        y_pub_key_handle: Handle,
+        ec_handle: Handle,
     ) -> BoxedBytes {
-        panic!("ec_generate_key not implemented")
+        self.use_gas_for(CRYPTO_API_COST, "GenerateKeyECC");
+        let ec = self.ec_get(ec_handle);
+        let private_key = self.ec_generate_private_key(&ec, x_pub_key_handle);
+        self.ec_set_result(
+            x_pub_key_handle,
+            y_pub_key_handle,
+            ec.scalar_base_mult(&private_key),
+        );
+        private_key.into()
     }
 }
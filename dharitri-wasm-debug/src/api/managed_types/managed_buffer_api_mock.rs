@@ -3,7 +3,7 @@ use dharitri_wasm::{
     types::BoxedBytes,
 };
 
-use crate::DebugApi;
+use crate::{world_mock::MANAGED_BUFFER_API_COST, DebugApi};
 
 impl DebugApi {
     fn mb_get_slice(
@@ -24,6 +24,7 @@ impl DebugApi {
 
 impl ManagedBufferApi for DebugApi {
     fn mb_new_empty(&self) -> Handle {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferNew");
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -31,6 +32,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_new_from_bytes(&self, bytes: &[u8]) -> Handle {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferNewFromBytes");
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -38,12 +40,14 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_len(&self, handle: Handle) -> usize {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferGetLength");
         let managed_types = self.m_types_borrow();
         let data = managed_types.managed_buffer_map.get(handle);
         data.len()
     }
 
     fn mb_to_boxed_bytes(&self, handle: Handle) -> BoxedBytes {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferGetBytes");
         let managed_types = self.m_types_borrow();
         let data = managed_types.managed_buffer_map.get(handle);
         data.into()
@@ -55,6 +59,7 @@ impl ManagedBufferApi for DebugApi {
         starting_position: usize,
         dest_slice: &mut [u8],
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferGetByteSlice");
         let opt_slice = self.mb_get_slice(source_handle, starting_position, dest_slice.len());
         if let Some(slice) = opt_slice {
             dest_slice.copy_from_slice(slice.as_slice());
@@ -71,6 +76,7 @@ impl ManagedBufferApi for DebugApi {
         slice_len: usize,
         dest_handle: Handle,
     ) -> Result<(), InvalidSliceError> {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferCopyByteSlice");
         let opt_slice = self.mb_get_slice(source_handle, starting_position, slice_len);
         if let Some(slice) = opt_slice {
             let mut managed_types = self.m_types_borrow_mut();
@@ -88,6 +94,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_overwrite(&self, handle: Handle, value: &[u8]) {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferSetBytes");
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .managed_buffer_map
@@ -95,6 +102,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_append(&self, accumulator_handle: Handle, data_handle: Handle) {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferAppend");
         let mut managed_types = self.m_types_borrow_mut();
         let mut data = managed_types.managed_buffer_map.get(data_handle).clone();
         let accumulator = managed_types.managed_buffer_map.get_mut(accumulator_handle);
@@ -102,6 +110,7 @@ impl ManagedBufferApi for DebugApi {
     }
 
     fn mb_append_bytes(&self, accumulator_handle: Handle, bytes: &[u8]) {
+        self.use_gas_for(MANAGED_BUFFER_API_COST, "MBufferAppendBytes");
         let mut managed_types = self.m_types_borrow_mut();
         let accumulator = managed_types.managed_buffer_map.get_mut(accumulator_handle);
         accumulator.extend_from_slice(bytes);
@@ -1,13 +1,25 @@
-use crate::{BigUintPrinter, DebugApi};
-use dharitri_wasm::{api::PrintApi, types::BigUint};
+use crate::{bytes_to_string, BigUintPrinter, DebugApi};
+use dharitri_wasm::{
+    api::PrintApi,
+    types::{BigUint, ManagedBuffer},
+};
 
+/// Printed values are not written to stdout, but collected in the transaction result.
 impl PrintApi for DebugApi {
     fn print_biguint(&self, biguint: &BigUint<Self>) {
-        println!(
+        self.push_print_message(format!(
             "{:?}",
             BigUintPrinter {
                 value: biguint.clone()
             }
-        );
+        ));
+    }
+
+    fn print_managed_buffer(&self, buffer: &ManagedBuffer<Self>) {
+        self.push_print_message(bytes_to_string(buffer.to_boxed_bytes().as_slice()));
+    }
+
+    fn print_fmt(&self, args: core::fmt::Arguments) {
+        self.push_print_message(args.to_string());
     }
 }
@@ -1,6 +1,9 @@
 use crate::{
-    tx_execution::{deploy_contract, execute_builtin_function_or_default},
+    tx_execution::{
+        builtin_function_gas_cost, deploy_contract, execute_builtin_function_or_default,
+    },
     tx_mock::{AsyncCallTxData, BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    world_mock::DHARITRI_API_COST,
     DebugApi,
 };
 use dharitri_wasm::{
@@ -44,23 +47,30 @@ impl DebugApi {
         tx_result.result_values
     }
 
-    fn perform_execute_on_dest_context(
+    fn execute_on_dest_context_tx(
         &self,
+        gas: u64,
         to: Address,
         moax_value: num_bigint::BigUint,
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
-    ) -> Vec<Vec<u8>> {
+    ) -> (TxResult, BlockchainUpdate) {
+        self.use_gas_for(DHARITRI_API_COST, "ExecuteOnDestContext");
+        // the builtin function cost is paid by the caller, on top of the gas given to the callee
+        let builtin_gas_cost =
+            builtin_function_gas_cost(func_name.as_slice(), &self.blockchain_ref().gas_schedule);
+
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
             from: contract_address.clone(),
             to,
             moax_value,
+            moax_value_transferred: false,
             dct_values: Vec::new(),
             func_name,
             args,
-            gas_limit: 1000,
+            gas_limit: gas.saturating_add(builtin_gas_cost).min(self.gas_left()),
             gas_price: 0,
             tx_hash,
         };
@@ -68,6 +78,24 @@ impl DebugApi {
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
             execute_builtin_function_or_default(tx_input, tx_cache);
+        // the print output of the callee is kept, even if the call fails
+        self.print_messages
+            .borrow_mut()
+            .extend_from_slice(tx_result.print_messages.as_slice());
+        self.use_gas(tx_result.gas_used);
+        (tx_result, blockchain_updates)
+    }
+
+    fn perform_execute_on_dest_context(
+        &self,
+        gas: u64,
+        to: Address,
+        moax_value: num_bigint::BigUint,
+        func_name: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        let (tx_result, blockchain_updates) =
+            self.execute_on_dest_context_tx(gas, to, moax_value, func_name, args);
 
         if tx_result.result_status == 0 {
             self.sync_call_post_processing(tx_result, blockchain_updates)
@@ -80,22 +108,40 @@ impl DebugApi {
         }
     }
 
+    /// Synchronous calls can only reach accounts in the same shard.
+    fn check_same_shard(&self, to: &Address) {
+        if !self
+            .blockchain_ref()
+            .in_same_shard(&self.input_ref().to, to)
+        {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: b"sync execution request is not in the same shard".to_vec(),
+            });
+        }
+    }
+
     fn perform_deploy(
         &self,
+        gas: u64,
         contract_code: Vec<u8>,
+        code_metadata: CodeMetadata,
         moax_value: num_bigint::BigUint,
         args: Vec<Vec<u8>>,
     ) -> (Address, Vec<Vec<u8>>) {
+        self.use_gas_for(DHARITRI_API_COST, "CreateContract");
+
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
             from: contract_address.clone(),
             to: Address::zero(),
             moax_value,
+            moax_value_transferred: false,
             dct_values: Vec::new(),
             func_name: Vec::new(),
             args,
-            gas_limit: 1000,
+            gas_limit: gas.min(self.gas_left()),
             gas_price: 0,
             tx_hash,
         };
@@ -103,7 +149,8 @@ impl DebugApi {
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         tx_cache.increase_acount_nonce(contract_address);
         let (tx_result, blockchain_updates, new_address) =
-            deploy_contract(tx_input, contract_code, tx_cache);
+            deploy_contract(tx_input, contract_code, code_metadata, tx_cache);
+        self.use_gas(tx_result.gas_used);
 
         if tx_result.result_status == 0 {
             (
@@ -119,6 +166,19 @@ impl DebugApi {
         }
     }
 
+    /// Splits the remaining gas between the async call and the callback.
+    /// Returns the gas limit of the async call and the gas locked for the callback.
+    fn async_call_gas(&self) -> (u64, u64) {
+        self.use_gas_for(DHARITRI_API_COST, "AsyncCallStep");
+        let gas_left = self.gas_left();
+        let callback_gas_lock = self
+            .blockchain_ref()
+            .gas_schedule
+            .get_cost(DHARITRI_API_COST, "AsyncCallbackGasLock")
+            .min(gas_left);
+        (gas_left - callback_gas_lock, callback_gas_lock)
+    }
+
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
@@ -138,6 +198,7 @@ impl DebugApi {
         let call_value = self.big_uint_value(amount);
         let contract_address = self.input_ref().to.clone();
         let tx_hash = self.get_tx_hash_legacy();
+        let (gas_limit, callback_gas_lock) = self.async_call_gas();
 
         let mut arguments = vec![contract_code, top_encode_to_vec_u8(&code_metadata).unwrap()];
         arguments.extend(
@@ -152,6 +213,8 @@ impl DebugApi {
             endpoint_name: UPGRADE_CONTRACT_FUNC_NAME.to_vec(),
             arguments,
             tx_hash,
+            gas_limit,
+            callback_gas_lock,
         };
         self.perform_async_call(call)
     }
@@ -168,6 +231,7 @@ impl SendApi for DebugApi {
     where
         D: Into<ManagedBuffer<Self>>,
     {
+        self.use_gas_for(DHARITRI_API_COST, "TransferValue");
         let amount_value = self.big_uint_value(amount);
         let available_moax_balance =
             self.with_contract_account(|account| account.moax_balance.clone());
@@ -179,19 +243,32 @@ impl SendApi for DebugApi {
         }
 
         let contract_address = &self.input_ref().to;
+        let recipient = &to.to_address();
+        if let Err(message) = self
+            .blockchain_cache()
+            .check_payable(contract_address, recipient)
+        {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: message.as_bytes().to_vec(),
+            });
+        }
+
         self.blockchain_cache()
             .subtract_moax_balance(contract_address, &amount_value);
-
-        let recipient = &to.to_address();
         self.blockchain_cache()
             .increase_moax_balance(recipient, &amount_value);
+
+        if recipient == &self.input_ref().from {
+            self.result_borrow_mut().result_calls.moax_back_transfer += amount_value;
+        }
     }
 
     fn direct_moax_execute(
         &self,
         to: &ManagedAddress<Self>,
         amount: &BigUint<Self>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
@@ -199,6 +276,7 @@ impl SendApi for DebugApi {
         let recipient = to.to_address();
 
         let _ = self.perform_execute_on_dest_context(
+            gas_limit,
             recipient,
             moax_value,
             endpoint_name.to_boxed_bytes().into_vec(),
@@ -213,7 +291,7 @@ impl SendApi for DebugApi {
         to: &ManagedAddress<Self>,
         token: &TokenIdentifier<Self>,
         amount: &BigUint<Self>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
@@ -225,6 +303,7 @@ impl SendApi for DebugApi {
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
         let _ = self.perform_execute_on_dest_context(
+            gas_limit,
             recipient,
             num_bigint::BigUint::zero(),
             DCT_TRANSFER_FUNC_NAME.to_vec(),
@@ -240,7 +319,7 @@ impl SendApi for DebugApi {
         token: &TokenIdentifier<Self>,
         nonce: u64,
         amount: &BigUint<Self>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
@@ -261,6 +340,7 @@ impl SendApi for DebugApi {
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
         let _ = self.perform_execute_on_dest_context(
+            gas_limit,
             contract_address,
             num_bigint::BigUint::zero(),
             DCT_NFT_TRANSFER_FUNC_NAME.to_vec(),
@@ -274,7 +354,7 @@ impl SendApi for DebugApi {
         &self,
         to: &ManagedAddress<Self>,
         payments: &ManagedVec<Self, DctTokenPayment<Self>>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
@@ -305,6 +385,7 @@ impl SendApi for DebugApi {
         }
 
         let _ = self.perform_execute_on_dest_context(
+            gas_limit,
             contract_address,
             num_bigint::BigUint::zero(),
             DCT_MULTI_TRANSFER_FUNC_NAME.to_vec(),
@@ -325,6 +406,7 @@ impl SendApi for DebugApi {
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
         let tx_hash = self.get_tx_hash_legacy();
+        let (gas_limit, callback_gas_lock) = self.async_call_gas();
         let call = AsyncCallTxData {
             from: contract_address,
             to: recipient,
@@ -332,38 +414,47 @@ impl SendApi for DebugApi {
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
             tx_hash,
+            gas_limit,
+            callback_gas_lock,
         };
         self.perform_async_call(call)
     }
 
     fn deploy_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<Self>,
         code: &ManagedBuffer<Self>,
-        _code_metadata: CodeMetadata,
+        code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
         let moax_value = self.big_uint_value(amount);
         let contract_code = code.to_boxed_bytes().into_vec();
-        let (new_address, result) =
-            self.perform_deploy(contract_code, moax_value, arg_buffer.to_raw_args_vec());
+        let (new_address, result) = self.perform_deploy(
+            gas,
+            contract_code,
+            code_metadata,
+            moax_value,
+            arg_buffer.to_raw_args_vec(),
+        );
 
         (ManagedAddress::from(new_address), ManagedVec::from(result))
     }
 
     fn deploy_from_source_contract(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<Self>,
         source_contract_address: &ManagedAddress<Self>,
-        _code_metadata: CodeMetadata,
+        code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
         let moax_value = self.big_uint_value(amount);
         let source_contract_code = self.get_contract_code(&source_contract_address.to_address());
         let (new_address, result) = self.perform_deploy(
+            gas,
             source_contract_code,
+            code_metadata,
             moax_value,
             arg_buffer.to_raw_args_vec(),
         );
@@ -399,7 +490,7 @@ impl SendApi for DebugApi {
 
     fn execute_on_dest_context_raw(
         &self,
-        _gas: u64,
+        gas: u64,
         to: &ManagedAddress<Self>,
         value: &BigUint<Self>,
         endpoint_name: &ManagedBuffer<Self>,
@@ -407,8 +498,10 @@ impl SendApi for DebugApi {
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
         let moax_value = self.big_uint_value(value);
         let recipient = to.to_address();
+        self.check_same_shard(&recipient);
 
         let result = self.perform_execute_on_dest_context(
+            gas,
             recipient,
             moax_value,
             endpoint_name.to_boxed_bytes().into_vec(),
@@ -420,7 +513,7 @@ impl SendApi for DebugApi {
 
     fn execute_on_dest_context_raw_custom_result_range<F>(
         &self,
-        _gas: u64,
+        gas: u64,
         to: &ManagedAddress<Self>,
         value: &BigUint<Self>,
         endpoint_name: &ManagedBuffer<Self>,
@@ -432,10 +525,12 @@ impl SendApi for DebugApi {
     {
         let moax_value = self.big_uint_value(value);
         let recipient = to.to_address();
+        self.check_same_shard(&recipient);
 
         let num_return_data_before = self.result_borrow_mut().result_values.len();
 
         let result = self.perform_execute_on_dest_context(
+            gas,
             recipient,
             moax_value,
             endpoint_name.to_boxed_bytes().into_vec(),
@@ -475,12 +570,44 @@ impl SendApi for DebugApi {
 
     fn execute_on_dest_context_readonly_raw(
         &self,
-        _gas: u64,
-        _to: &ManagedAddress<Self>,
-        _endpoint_name: &ManagedBuffer<Self>,
-        _arg_buffer: &ManagedArgBuffer<Self>,
+        gas: u64,
+        to: &ManagedAddress<Self>,
+        endpoint_name: &ManagedBuffer<Self>,
+        arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
-        panic!("execute_on_dest_context_readonly_raw not implemented yet!");
+        let recipient = to.to_address();
+        self.check_same_shard(&recipient);
+
+        let readable = self
+            .blockchain_cache()
+            .with_account(&recipient, |account| account.code_metadata.is_readable());
+        if !readable {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: b"contract is not readable".to_vec(),
+            });
+        }
+
+        // like in the VM, storage writes in read only mode do not fail, they are ignored,
+        // so all changes made by the callee are discarded (see forw_raw_sync_readonly.scen.json)
+        let (tx_result, _) = self.execute_on_dest_context_tx(
+            gas,
+            recipient,
+            num_bigint::BigUint::zero(),
+            endpoint_name.to_boxed_bytes().into_vec(),
+            arg_buffer.to_raw_args_vec(),
+        );
+
+        if tx_result.result_status != 0 {
+            std::panic::panic_any(TxPanic {
+                status: tx_result.result_status,
+                message: tx_result.result_message.into_bytes(),
+            });
+        }
+
+        self.result_borrow_mut().merge_after_sync_call(&tx_result);
+
+        ManagedVec::from(tx_result.result_values)
     }
 
     fn storage_store_tx_hash_key(&self, data: &ManagedBuffer<Self>) {
@@ -496,13 +623,14 @@ impl SendApi for DebugApi {
 
     fn call_local_dct_built_in_function(
         &self,
-        _gas: u64,
+        gas: u64,
         function_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
         let contract_address = &self.input_ref().to;
 
         let result = self.perform_execute_on_dest_context(
+            gas,
             contract_address.clone(),
             num_bigint::BigUint::zero(),
             function_name.to_boxed_bytes().into_vec(),
@@ -1,4 +1,8 @@
-use crate::{tx_mock::TxPanic, DebugApi};
+use crate::{
+    tx_mock::TxPanic,
+    world_mock::{BASE_OPERATION_COST, DHARITRI_API_COST},
+    DebugApi,
+};
 use alloc::vec::Vec;
 use dharitri_wasm::api::{BigIntApi, Handle, ManagedBufferApi, StorageReadApi, StorageWriteApi};
 use num_bigint::{BigInt, BigUint, Sign};
@@ -10,6 +14,7 @@ impl StorageReadApi for DebugApi {
     }
 
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
+        self.use_gas_for(DHARITRI_API_COST, "StorageLoad");
         self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
             None => Vec::with_capacity(0),
             Some(value) => value.clone(),
@@ -72,6 +77,13 @@ impl StorageWriteApi for DebugApi {
             });
         }
 
+        self.use_gas_for(DHARITRI_API_COST, "StorageStore");
+        let store_per_byte = self
+            .blockchain_ref()
+            .gas_schedule
+            .get_cost(BASE_OPERATION_COST, "StorePerByte");
+        self.use_gas(store_per_byte.saturating_mul(value.len() as u64));
+
         self.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
@@ -0,0 +1,28 @@
+//! Conversions between addresses and their bech32 representation, as used on the blockchain.
+
+use bech32::{FromBase32, ToBase32, Variant};
+use dharitri_wasm::types::Address;
+
+/// The human-readable part of all Dharitri bech32 addresses.
+pub const ADDRESS_HRP: &str = "moa";
+
+/// Decodes a bech32 address. Panics if the input is not a valid 32-byte bech32 address.
+pub fn decode(bech32_address: &str) -> Address {
+    let (_, data, _) = bech32::decode(bech32_address)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{}`: {}", bech32_address, err));
+    let bytes = Vec::<u8>::from_base32(&data)
+        .unwrap_or_else(|err| panic!("invalid bech32 address `{}`: {}", bech32_address, err));
+    assert!(
+        bytes.len() == 32,
+        "bech32 address `{}` should decode to 32 bytes, got {}",
+        bech32_address,
+        bytes.len()
+    );
+    Address::from_slice(&bytes)
+}
+
+/// Encodes an address as bech32, with the `moa` prefix.
+pub fn encode(address: &Address) -> String {
+    bech32::encode(ADDRESS_HRP, address.as_bytes().to_base32(), Variant::Bech32)
+        .expect("bech32 encode error")
+}
@@ -4,12 +4,21 @@ use super::*;
 
 use alloc::{boxed::Box, vec::Vec};
 use dharitri_wasm::contract_base::CallableContract;
-use std::{collections::HashMap, fmt};
+use std::{collections::HashMap, fmt, rc::Rc};
 
 pub type ContractCallFactory<A> = Box<dyn Fn(DebugApi) -> Box<dyn CallableContract<A>>>;
 
+/// Factories are shared, so that forks of the blockchain mock can reuse the registered contracts.
 pub struct ContractMap<A> {
-    factories: HashMap<Vec<u8>, ContractCallFactory<A>>,
+    factories: HashMap<Vec<u8>, Rc<dyn Fn(DebugApi) -> Box<dyn CallableContract<A>>>>,
+}
+
+impl<A> Clone for ContractMap<A> {
+    fn clone(&self) -> Self {
+        ContractMap {
+            factories: self.factories.clone(),
+        }
+    }
 }
 
 impl<A> fmt::Debug for ContractMap<A> {
@@ -42,7 +51,9 @@ impl<A> ContractMap<A> {
         contract_bytes: Vec<u8>,
         new_contract_closure: Box<dyn Fn(DebugApi) -> Box<dyn CallableContract<A>>>,
     ) {
-        let previous_entry = self.factories.insert(contract_bytes, new_contract_closure);
+        let previous_entry = self
+            .factories
+            .insert(contract_bytes, Rc::from(new_contract_closure));
         assert!(previous_entry.is_none(), "contract inserted twice");
     }
 
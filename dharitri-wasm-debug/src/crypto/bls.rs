@@ -0,0 +1,303 @@
+//! BLS signatures over the BLS12-381 curve, compatible with the node (herumi/mcl, non-ETH mode).
+//!
+//! Public keys are G2 points, signatures are G1 points.
+//! Points are serialized as their x coordinate in little endian,
+//! with the parity of the y coordinate in the most significant bit.
+
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha2::{Digest, Sha512};
+
+pub const BLS_SECRET_KEY_LENGTH: usize = 32;
+pub const BLS_PUBLIC_KEY_LENGTH: usize = 96;
+pub const BLS_SIGNATURE_LENGTH: usize = 48;
+
+const FP_LENGTH: usize = 48;
+const FP_BIT_SIZE: u64 = 381;
+const SIGN_FLAG: u8 = 0x80;
+const ZCASH_COMPRESSION_FLAG: u8 = 0x80;
+const ZCASH_FLAGS_MASK: u8 = 0xe0;
+
+const FIELD_MODULUS_HEX: &[u8] = b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// The node does not use the standard (zcash) generator of G2 for public keys.
+/// This is the node's generator, in its own serialized form.
+const PUBLIC_KEY_GENERATOR_HEX: &[u8] = b"cc1ef2d9d8c53b60568548e55c1a3ae34e07ce638182fcbd6476c381db2806dfbb612c123cab0a1400cf1af81a013d0fb3a4f19603d35ca16f6709162ca8fc9e5ffbbdbfa6dc2ad8f655660e3f4c928ce26401a98e65c2168a900f08a5f71d97";
+
+/// Cofactor of G1, (z - 1)^2 / 3, as little endian 64-bit limbs.
+const G1_COFACTOR: [u64; 4] = [0x8c00aaab0000aaab, 0x396c8c005555e156, 0, 0];
+
+/// Coefficient b of the G1 curve equation, y^2 = x^3 + b.
+const G1_CURVE_B: u32 = 4;
+
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match deserialize_g2(key) {
+        Some(public_key) => public_key,
+        None => return false,
+    };
+    let signature = match deserialize_g1(signature) {
+        Some(signature) => signature,
+        None => return false,
+    };
+    let hashed_message = match hash_and_map_to_g1(message) {
+        Some(hashed_message) => hashed_message,
+        None => return false,
+    };
+
+    pairing(&signature, &public_key_generator())
+        == pairing(&G1Affine::from(hashed_message), &public_key)
+}
+
+/// Computes the serialized public key of a serialized secret key.
+/// Returns `None` if the secret key is invalid.
+pub fn bls_public_key(secret_key: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = deserialize_secret_key(secret_key)?;
+    let public_key = G2Affine::from(public_key_generator() * secret_key);
+    Some(serialize_g2(&public_key))
+}
+
+/// Signs a message the same way the node does.
+/// Returns `None` if the secret key is invalid.
+pub fn sign_bls(secret_key: &[u8], message: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = deserialize_secret_key(secret_key)?;
+    let hashed_message = hash_and_map_to_g1(message)?;
+    let signature = G1Affine::from(hashed_message * secret_key);
+    Some(serialize_g1(&signature))
+}
+
+/// Reduces arbitrary seed bytes to a valid serialized secret key.
+pub fn bls_secret_key_from_seed(seed: &[u8; 64]) -> Vec<u8> {
+    Scalar::from_bytes_wide(seed).to_bytes().to_vec()
+}
+
+fn deserialize_secret_key(bytes: &[u8]) -> Option<Scalar> {
+    if bytes.len() != BLS_SECRET_KEY_LENGTH {
+        return None;
+    }
+    let mut scalar_bytes = [0u8; BLS_SECRET_KEY_LENGTH];
+    scalar_bytes.copy_from_slice(bytes);
+    let scalar = Option::<Scalar>::from(Scalar::from_bytes(&scalar_bytes))?;
+    if scalar == Scalar::zero() {
+        return None;
+    }
+    Some(scalar)
+}
+
+fn public_key_generator() -> G2Affine {
+    deserialize_g2(&hex::decode(PUBLIC_KEY_GENERATOR_HEX).unwrap()).unwrap()
+}
+
+/// Converts a little endian x coordinate to the big endian zcash compressed form.
+/// Returns the parity flag of y separately, since zcash encodes the sign of y differently.
+fn to_zcash_compressed(le_bytes: &[u8]) -> Option<(Vec<u8>, bool)> {
+    let y_is_odd = le_bytes[le_bytes.len() - 1] & SIGN_FLAG != 0;
+    let mut be_bytes: Vec<u8> = le_bytes.iter().rev().copied().collect();
+    be_bytes[0] &= !SIGN_FLAG;
+    if be_bytes[0] & ZCASH_FLAGS_MASK != 0 {
+        return None;
+    }
+    be_bytes[0] |= ZCASH_COMPRESSION_FLAG;
+    Some((be_bytes, y_is_odd))
+}
+
+fn deserialize_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != BLS_SIGNATURE_LENGTH {
+        return None;
+    }
+    let (compressed, y_is_odd) = to_zcash_compressed(bytes)?;
+    let mut compressed_bytes = [0u8; BLS_SIGNATURE_LENGTH];
+    compressed_bytes.copy_from_slice(&compressed);
+    let point = Option::<G1Affine>::from(G1Affine::from_compressed(&compressed_bytes))?;
+    if g1_y_is_odd(&point) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn deserialize_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != BLS_PUBLIC_KEY_LENGTH {
+        return None;
+    }
+    // the node writes x.c0 first, zcash writes x.c1 first
+    let (c1_compressed, y_is_odd) = to_zcash_compressed(&bytes[FP_LENGTH..])?;
+    let mut compressed_bytes = [0u8; BLS_PUBLIC_KEY_LENGTH];
+    compressed_bytes[..FP_LENGTH].copy_from_slice(&c1_compressed);
+    for (dest, source) in compressed_bytes[FP_LENGTH..]
+        .iter_mut()
+        .zip(bytes[..FP_LENGTH].iter().rev())
+    {
+        *dest = *source;
+    }
+    let point = Option::<G2Affine>::from(G2Affine::from_compressed(&compressed_bytes))?;
+    if g2_y_is_odd(&point) == y_is_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn serialize_g1(point: &G1Affine) -> Vec<u8> {
+    if bool::from(point.is_identity()) {
+        return vec![0u8; BLS_SIGNATURE_LENGTH];
+    }
+    let uncompressed = point.to_uncompressed();
+    let mut result: Vec<u8> = uncompressed[..FP_LENGTH].iter().rev().copied().collect();
+    if g1_y_is_odd(point) {
+        result[FP_LENGTH - 1] |= SIGN_FLAG;
+    }
+    result
+}
+
+fn serialize_g2(point: &G2Affine) -> Vec<u8> {
+    if bool::from(point.is_identity()) {
+        return vec![0u8; BLS_PUBLIC_KEY_LENGTH];
+    }
+    let uncompressed = point.to_uncompressed();
+    let mut result: Vec<u8> = uncompressed[FP_LENGTH..2 * FP_LENGTH]
+        .iter()
+        .rev()
+        .copied()
+        .collect();
+    result.extend(uncompressed[..FP_LENGTH].iter().rev());
+    if g2_y_is_odd(point) {
+        result[BLS_PUBLIC_KEY_LENGTH - 1] |= SIGN_FLAG;
+    }
+    result
+}
+
+fn g1_y_is_odd(point: &G1Affine) -> bool {
+    let uncompressed = point.to_uncompressed();
+    uncompressed[2 * FP_LENGTH - 1] & 1 == 1
+}
+
+/// The parity of an Fp2 element is given by its first component (c0),
+/// which comes last in the zcash encoding.
+fn g2_y_is_odd(point: &G2Affine) -> bool {
+    let uncompressed = point.to_uncompressed();
+    uncompressed[4 * FP_LENGTH - 1] & 1 == 1
+}
+
+/// Hashes the message to a field element (SHA-512, truncated and masked like mcl's `setHashOf`),
+/// maps it to the curve using the Fouque-Tibouchi encoding, then clears the cofactor.
+fn hash_and_map_to_g1(message: &[u8]) -> Option<G1Projective> {
+    let field = PrimeField::bls12_381();
+    let digest = Sha512::digest(message);
+    let t = field.element_from_le_bytes_masked(&digest[..FP_LENGTH]);
+    let (x, y) = field.map_to_g1_curve(&t)?;
+
+    let mut uncompressed = [0u8; 2 * FP_LENGTH];
+    uncompressed[..FP_LENGTH].copy_from_slice(&field.to_be_bytes(&x));
+    uncompressed[FP_LENGTH..].copy_from_slice(&field.to_be_bytes(&y));
+    let point = Option::<G1Affine>::from(G1Affine::from_uncompressed_unchecked(&uncompressed))?;
+
+    Some(G1Projective::from(point) * Scalar::from_raw(G1_COFACTOR))
+}
+
+/// Minimal arithmetic over the base field, only used for mapping hashes to the curve.
+struct PrimeField {
+    modulus: BigUint,
+}
+
+impl PrimeField {
+    fn bls12_381() -> Self {
+        PrimeField {
+            modulus: BigUint::parse_bytes(FIELD_MODULUS_HEX, 16).unwrap(),
+        }
+    }
+
+    fn element_from_u32(&self, value: u32) -> BigUint {
+        BigUint::from(value) % &self.modulus
+    }
+
+    fn element_from_le_bytes_masked(&self, bytes: &[u8]) -> BigUint {
+        let value = BigUint::from_bytes_le(bytes) & mask(FP_BIT_SIZE);
+        if value >= self.modulus {
+            value & mask(FP_BIT_SIZE - 1)
+        } else {
+            value
+        }
+    }
+
+    fn to_be_bytes(&self, value: &BigUint) -> [u8; FP_LENGTH] {
+        let bytes = value.to_bytes_be();
+        let mut result = [0u8; FP_LENGTH];
+        result[FP_LENGTH - bytes.len()..].copy_from_slice(&bytes);
+        result
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.modulus
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.modulus - b) % &self.modulus
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.modulus
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.modulus - 2u32), &self.modulus)
+    }
+
+    fn is_quadratic_non_residue(&self, a: &BigUint) -> bool {
+        let exponent = (&self.modulus - 1u32) >> 1;
+        a.modpow(&exponent, &self.modulus) == &self.modulus - 1u32
+    }
+
+    /// The modulus is 3 mod 4, so the square root is a^((p + 1) / 4), if it exists.
+    fn sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let exponent = (&self.modulus + 1u32) >> 2;
+        let root = a.modpow(&exponent, &self.modulus);
+        if self.mul(&root, &root) == *a {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// P.-A. Fouque and M. Tibouchi, "Indifferentiable hashing to Barreto-Naehrig curves".
+    /// Follows mcl's `MapTo::calcBN` exactly, including the choice of square roots.
+    fn map_to_g1_curve(&self, t: &BigUint) -> Option<(BigUint, BigUint)> {
+        if t.is_zero() {
+            return None;
+        }
+        let one = BigUint::one();
+        let curve_b = self.element_from_u32(G1_CURVE_B);
+        let c1 = self.sqrt(&self.neg(&self.element_from_u32(3)))?;
+        let c2 = self.mul(&self.sub(&c1, &one), &self.inv(&self.element_from_u32(2)));
+        let negative = self.is_quadratic_non_residue(t);
+
+        let denominator = self.add(&self.add(&self.mul(t, t), &curve_b), &one);
+        if denominator.is_zero() {
+            return None;
+        }
+        let w = self.mul(&self.mul(&self.inv(&denominator), &c1), t);
+
+        let mut x = BigUint::zero();
+        for i in 0..3 {
+            x = match i {
+                0 => self.add(&self.neg(&self.mul(t, &w)), &c2),
+                1 => self.sub(&self.neg(&x), &one),
+                _ => self.add(&self.inv(&self.mul(&w, &w)), &one),
+            };
+            let y_squared = self.add(&self.mul(&self.mul(&x, &x), &x), &curve_b);
+            if let Some(y) = self.sqrt(&y_squared) {
+                let y = if negative { self.neg(&y) } else { y };
+                return Some((x, y));
+            }
+        }
+        None
+    }
+}
+
+fn mask(bits: u64) -> BigUint {
+    (BigUint::one() << bits) - 1u32
+}
@@ -0,0 +1,34 @@
+use ed25519_dalek::{ExpandedSecretKey, PublicKey, SecretKey, Signature, Verifier};
+use std::convert::TryFrom;
+
+pub const ED25519_SECRET_KEY_LENGTH: usize = 32;
+pub const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match PublicKey::from_bytes(key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::try_from(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public_key.verify(message, &signature).is_ok()
+}
+
+/// Computes the public key of a 32-byte secret key (seed).
+/// Returns `None` if the secret key has the wrong length.
+pub fn ed25519_public_key(secret_key: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::from_bytes(secret_key).ok()?;
+    Some(PublicKey::from(&secret_key).as_bytes().to_vec())
+}
+
+/// Signs a message with a 32-byte secret key (seed).
+/// Returns `None` if the secret key has the wrong length.
+pub fn sign_ed25519(secret_key: &[u8], message: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::from_bytes(secret_key).ok()?;
+    let public_key = PublicKey::from(&secret_key);
+    let signature = ExpandedSecretKey::from(&secret_key).sign(message, &public_key);
+    Some(signature.to_bytes().to_vec())
+}
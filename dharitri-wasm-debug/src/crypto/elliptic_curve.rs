@@ -0,0 +1,374 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+const MARSHAL_UNCOMPRESSED_PREFIX: u8 = 4;
+const MARSHAL_COMPRESSED_EVEN_PREFIX: u8 = 2;
+const MARSHAL_COMPRESSED_ODD_PREFIX: u8 = 3;
+
+/// A short Weierstrass curve `y² = x³ - 3x + b`, like the NIST curves supported by the node.
+///
+/// Same as in Go's `crypto/elliptic`, the point at infinity is represented as (0, 0).
+#[derive(Clone, Debug)]
+pub struct EllipticCurve {
+    pub field_order: BigUint,
+    pub base_point_order: BigUint,
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+type JacobianPoint = (BigUint, BigUint, BigUint);
+
+impl EllipticCurve {
+    /// Accepts the same names as the node: `p224`, `p256`, `p384` and `p521`.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::from_hex(
+                "ffffffffffffffffffffffffffffffff000000000000000000000001",
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+                "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+                224,
+            )),
+            b"p256" => Some(Self::from_hex(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                256,
+            )),
+            b"p384" => Some(Self::from_hex(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                384,
+            )),
+            b"p521" => Some(Self::from_hex(
+                "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+                "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+                "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+                521,
+            )),
+            _ => None,
+        }
+    }
+
+    fn from_hex(p: &str, n: &str, b: &str, gx: &str, gy: &str, size_of_field: u32) -> Self {
+        let parse = |hex_str: &str| BigUint::parse_bytes(hex_str.as_bytes(), 16).unwrap();
+        EllipticCurve {
+            field_order: parse(p),
+            base_point_order: parse(n),
+            eq_constant: parse(b),
+            x_base_point: parse(gx),
+            y_base_point: parse(gy),
+            size_of_field,
+        }
+    }
+
+    pub fn private_key_byte_length(&self) -> u32 {
+        ((self.base_point_order.bits() + 7) / 8) as u32
+    }
+
+    fn coordinate_byte_length(&self) -> usize {
+        ((self.size_of_field + 7) / 8) as usize
+    }
+
+    pub fn marshal_byte_length(&self) -> usize {
+        1 + 2 * self.coordinate_byte_length()
+    }
+
+    pub fn marshal_compressed_byte_length(&self) -> usize {
+        1 + self.coordinate_byte_length()
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.field_order || y >= &self.field_order {
+            return false;
+        }
+        self.mul(y, y) == self.curve_rhs(x)
+    }
+
+    pub fn add(
+        &self,
+        x_first: &BigUint,
+        y_first: &BigUint,
+        x_second: &BigUint,
+        y_second: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let sum = self.add_jacobian(
+            &self.affine_to_jacobian(x_first, y_first),
+            &self.affine_to_jacobian(x_second, y_second),
+        );
+        self.jacobian_to_affine(sum)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        self.jacobian_to_affine(self.double_jacobian(&self.affine_to_jacobian(x, y)))
+    }
+
+    /// The scalar is interpreted as a big endian unsigned number.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, scalar: &[u8]) -> (BigUint, BigUint) {
+        let point = self.affine_to_jacobian(x, y);
+        let mut result = (BigUint::zero(), BigUint::zero(), BigUint::zero());
+        for byte in scalar {
+            for bit in (0..8).rev() {
+                result = self.double_jacobian(&result);
+                if (byte >> bit) & 1 == 1 {
+                    result = self.add_jacobian(&result, &point);
+                }
+            }
+        }
+        self.jacobian_to_affine(result)
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> (BigUint, BigUint) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, scalar)
+    }
+
+    /// Uncompressed form: `0x04 | x | y`.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![MARSHAL_UNCOMPRESSED_PREFIX];
+        result.extend_from_slice(&self.coordinate_bytes(x));
+        result.extend_from_slice(&self.coordinate_bytes(y));
+        result
+    }
+
+    /// Compressed form: `0x02 | x` for even `y`, `0x03 | x` for odd `y`.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![if is_odd(y) {
+            MARSHAL_COMPRESSED_ODD_PREFIX
+        } else {
+            MARSHAL_COMPRESSED_EVEN_PREFIX
+        }];
+        result.extend_from_slice(&self.coordinate_bytes(x));
+        result
+    }
+
+    /// Returns `None` if the data is malformed or the point is not on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_len = self.coordinate_byte_length();
+        if data.len() != self.marshal_byte_length() || data[0] != MARSHAL_UNCOMPRESSED_PREFIX {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_len]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Returns `None` if the data is malformed or the point is not on the curve.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        if data.len() != self.marshal_compressed_byte_length() {
+            return None;
+        }
+        if data[0] != MARSHAL_COMPRESSED_EVEN_PREFIX && data[0] != MARSHAL_COMPRESSED_ODD_PREFIX {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.field_order {
+            return None;
+        }
+        let mut y = self.sqrt(&self.curve_rhs(&x))?;
+        if is_odd(&y) != (data[0] == MARSHAL_COMPRESSED_ODD_PREFIX) {
+            y = self.neg(&y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    fn coordinate_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let byte_len = self.coordinate_byte_length();
+        let bytes = value.to_bytes_be();
+        let mut result = vec![0u8; byte_len.saturating_sub(bytes.len())];
+        result.extend_from_slice(&bytes);
+        result
+    }
+
+    /// x³ - 3x + b
+    fn curve_rhs(&self, x: &BigUint) -> BigUint {
+        let x_cubed = self.mul(&self.mul(x, x), x);
+        let three_x = self.mul(&BigUint::from(3u32), x);
+        self.add_mod(&self.sub(&x_cubed, &three_x), &self.eq_constant)
+    }
+
+    /// Jacobian coordinates (X, Y, Z) stand for the affine point (X/Z², Y/Z³).
+    /// Working with them avoids a field inversion on every addition.
+    fn affine_to_jacobian(&self, x: &BigUint, y: &BigUint) -> JacobianPoint {
+        if x.is_zero() && y.is_zero() {
+            return (BigUint::zero(), BigUint::zero(), BigUint::zero());
+        }
+        (x.clone(), y.clone(), BigUint::one())
+    }
+
+    fn jacobian_to_affine(&self, (x, y, z): JacobianPoint) -> (BigUint, BigUint) {
+        if z.is_zero() {
+            return (BigUint::zero(), BigUint::zero());
+        }
+        let z_inv = self.inv(&z);
+        let z_inv_squared = self.mul(&z_inv, &z_inv);
+        (
+            self.mul(&x, &z_inv_squared),
+            self.mul(&y, &self.mul(&z_inv_squared, &z_inv)),
+        )
+    }
+
+    /// "add-2007-bl" formulas.
+    fn add_jacobian(&self, first: &JacobianPoint, second: &JacobianPoint) -> JacobianPoint {
+        let (x1, y1, z1) = first;
+        let (x2, y2, z2) = second;
+        if z1.is_zero() {
+            return second.clone();
+        }
+        if z2.is_zero() {
+            return first.clone();
+        }
+
+        let z1_squared = self.mul(z1, z1);
+        let z2_squared = self.mul(z2, z2);
+        let u1 = self.mul(x1, &z2_squared);
+        let u2 = self.mul(x2, &z1_squared);
+        let s1 = self.mul(y1, &self.mul(z2, &z2_squared));
+        let s2 = self.mul(y2, &self.mul(z1, &z1_squared));
+        let h = self.sub(&u2, &u1);
+        let r = self.add_mod(&self.sub(&s2, &s1), &self.sub(&s2, &s1));
+        if h.is_zero() {
+            if r.is_zero() {
+                return self.double_jacobian(first);
+            }
+            return (BigUint::zero(), BigUint::zero(), BigUint::zero());
+        }
+
+        let two_h = self.add_mod(&h, &h);
+        let i = self.mul(&two_h, &two_h);
+        let j = self.mul(&h, &i);
+        let v = self.mul(&u1, &i);
+        let x3 = self.sub(&self.sub(&self.mul(&r, &r), &j), &self.add_mod(&v, &v));
+        let s1_j = self.mul(&s1, &j);
+        let y3 = self.sub(
+            &self.mul(&r, &self.sub(&v, &x3)),
+            &self.add_mod(&s1_j, &s1_j),
+        );
+        let z1_plus_z2 = self.add_mod(z1, z2);
+        let z3 = self.mul(
+            &self.sub(
+                &self.sub(&self.mul(&z1_plus_z2, &z1_plus_z2), &z1_squared),
+                &z2_squared,
+            ),
+            &h,
+        );
+        (x3, y3, z3)
+    }
+
+    /// "dbl-2001-b" formulas, which rely on the curve coefficient `a` being -3.
+    fn double_jacobian(&self, point: &JacobianPoint) -> JacobianPoint {
+        let (x, y, z) = point;
+        if z.is_zero() || y.is_zero() {
+            return (BigUint::zero(), BigUint::zero(), BigUint::zero());
+        }
+
+        let delta = self.mul(z, z);
+        let gamma = self.mul(y, y);
+        let beta = self.mul(x, &gamma);
+        let alpha = self.mul(
+            &BigUint::from(3u32),
+            &self.mul(&self.sub(x, &delta), &self.add_mod(x, &delta)),
+        );
+        let eight_beta = self.mul(&BigUint::from(8u32), &beta);
+        let x3 = self.sub(&self.mul(&alpha, &alpha), &eight_beta);
+        let y_plus_z = self.add_mod(y, z);
+        let z3 = self.sub(&self.sub(&self.mul(&y_plus_z, &y_plus_z), &gamma), &delta);
+        let four_beta = self.mul(&BigUint::from(4u32), &beta);
+        let eight_gamma_squared = self.mul(&BigUint::from(8u32), &self.mul(&gamma, &gamma));
+        let y3 = self.sub(
+            &self.mul(&alpha, &self.sub(&four_beta, &x3)),
+            &eight_gamma_squared,
+        );
+        (x3, y3, z3)
+    }
+
+    fn add_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.field_order
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let a = a % &self.field_order;
+        let b = b % &self.field_order;
+        (&self.field_order + a - b) % &self.field_order
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.field_order
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        let exponent = &self.field_order - BigUint::from(2u32);
+        a.modpow(&exponent, &self.field_order)
+    }
+
+    /// Tonelli-Shanks, since the P-224 field order is not 3 mod 4.
+    fn sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.field_order;
+        let one = BigUint::one();
+        let a = a % p;
+        if a.is_zero() {
+            return Some(a);
+        }
+        let p_minus_one = p - &one;
+        if a.modpow(&(&p_minus_one >> 1), p) != one {
+            return None;
+        }
+
+        let mut q = p_minus_one.clone();
+        let mut s = 0u32;
+        while !is_odd(&q) {
+            q >>= 1;
+            s += 1;
+        }
+
+        let mut non_residue = BigUint::from(2u32);
+        while non_residue.modpow(&(&p_minus_one >> 1), p) == one {
+            non_residue += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = non_residue.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + &one) >> 1), p);
+        while t != one {
+            let mut i = 0u32;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = self.mul(&t_pow, &t_pow);
+                i += 1;
+            }
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = self.mul(&b, &b);
+            }
+            m = i;
+            c = self.mul(&b, &b);
+            t = self.mul(&t, &c);
+            r = self.mul(&r, &b);
+        }
+        Some(r)
+    }
+}
+
+fn is_odd(value: &BigUint) -> bool {
+    value.bit(0)
+}
@@ -0,0 +1,9 @@
+mod bls;
+mod ed25519;
+mod elliptic_curve;
+mod secp256k1;
+
+pub use bls::*;
+pub use ed25519::*;
+pub use elliptic_curve::*;
+pub use secp256k1::*;
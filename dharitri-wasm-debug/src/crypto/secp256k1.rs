@@ -0,0 +1,108 @@
+use dharitri_wasm::types::MessageHashType;
+use libsecp256k1::{Message, PublicKey, SecretKey, Signature};
+use num_bigint::BigUint;
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+pub const SECP256K1_SECRET_KEY_LENGTH: usize = 32;
+
+const DER_SEQUENCE_TAG: u8 = 0x30;
+const DER_INTEGER_TAG: u8 = 0x02;
+
+const CURVE_ORDER_HEX: &[u8] = b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+/// Verifies a DER-encoded signature against a compressed or uncompressed public key,
+/// after hashing the message as indicated by the hash type.
+pub fn verify_secp256k1(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash_type: MessageHashType,
+) -> bool {
+    let public_key = match PublicKey::parse_slice(key, None) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::parse_der(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let message = hashed_message(message, hash_type);
+    libsecp256k1::verify(&message, &signature, &public_key)
+}
+
+/// Encodes a signature given as (r, s) in DER format.
+/// Like the node, it converts `s` to its low form first.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let curve_order = BigUint::parse_bytes(CURVE_ORDER_HEX, 16).unwrap();
+    let mut s = BigUint::from_bytes_be(s);
+    if s > &curve_order >> 1 && s < curve_order {
+        s = &curve_order - s;
+    }
+
+    let r_encoded = der_integer(&BigUint::from_bytes_be(r));
+    let s_encoded = der_integer(&s);
+
+    let mut result = vec![DER_SEQUENCE_TAG, (r_encoded.len() + s_encoded.len()) as u8];
+    result.extend_from_slice(&r_encoded);
+    result.extend_from_slice(&s_encoded);
+    result
+}
+
+/// Computes the compressed public key of a 32-byte secret key.
+/// Returns `None` if the secret key is invalid.
+pub fn secp256k1_public_key(secret_key: &[u8]) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::parse_slice(secret_key).ok()?;
+    Some(
+        PublicKey::from_secret_key(&secret_key)
+            .serialize_compressed()
+            .to_vec(),
+    )
+}
+
+/// Produces a DER-encoded signature of the message, hashed as indicated by the hash type.
+/// Returns `None` if the secret key is invalid.
+pub fn sign_secp256k1(
+    secret_key: &[u8],
+    message: &[u8],
+    hash_type: MessageHashType,
+) -> Option<Vec<u8>> {
+    let secret_key = SecretKey::parse_slice(secret_key).ok()?;
+    let message = hashed_message(message, hash_type);
+    let (signature, _) = libsecp256k1::sign(&message, &secret_key);
+    Some(signature.serialize_der().as_ref().to_vec())
+}
+
+fn hashed_message(message: &[u8], hash_type: MessageHashType) -> Message {
+    let hash = match hash_type {
+        MessageHashType::ECDSAPlainMsg => message.to_vec(),
+        MessageHashType::ECDSASha256 => Sha256::digest(message).to_vec(),
+        MessageHashType::ECDSADoubleSha256 => Sha256::digest(&Sha256::digest(message)).to_vec(),
+        MessageHashType::ECDSAKeccak256 => Keccak256::digest(message).to_vec(),
+        MessageHashType::ECDSARipemd160 => Ripemd160::digest(message).to_vec(),
+    };
+    message_from_hash(&hash)
+}
+
+/// Same as the node: hashes longer than 32 bytes are truncated,
+/// shorter ones are interpreted as smaller numbers.
+fn message_from_hash(hash: &[u8]) -> Message {
+    let mut message_bytes = [0u8; 32];
+    if hash.len() >= 32 {
+        message_bytes.copy_from_slice(&hash[..32]);
+    } else {
+        message_bytes[32 - hash.len()..].copy_from_slice(hash);
+    }
+    Message::parse(&message_bytes)
+}
+
+fn der_integer(value: &BigUint) -> Vec<u8> {
+    let mut bytes = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    let mut result = vec![DER_INTEGER_TAG, bytes.len() as u8];
+    result.extend_from_slice(&bytes);
+    result
+}
@@ -1,8 +1,12 @@
 #![allow(unused_variables)] // for now
 
-use crate::{denali_step, world_mock::BlockchainMock};
+use crate::{
+    denali_step,
+    tx_execution::execute_cross_shard_calls,
+    world_mock::{BlockchainMock, GasSchedule},
+};
 
-use denali::model::Step;
+use denali::model::{Scenario, Step, TxExpect};
 use std::{path::Path, rc::Rc};
 
 /// Runs denali test using the Rust infrastructure and the debug mode.
@@ -17,6 +21,10 @@ pub fn denali_rs<P: AsRef<Path>>(relative_path: P, world: BlockchainMock) {
 
 fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>) {
     let scenario = denali::parse_scenario(steps_path);
+    if let Some(gas_schedule_name) = &scenario.gas_schedule {
+        apply_gas_schedule(steps_path, &scenario, gas_schedule_name, state);
+    }
+    let check_gas = scenario.check_gas.unwrap_or(true) && state.gas_schedule.is_metered();
 
     for step in scenario.steps.iter() {
         match step {
@@ -29,6 +37,7 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
                 comment,
                 accounts,
                 new_addresses,
+                token_properties,
                 block_hashes,
                 previous_block_info,
                 current_block_info,
@@ -36,6 +45,7 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
                 Rc::get_mut(state).unwrap(),
                 accounts,
                 new_addresses,
+                token_properties,
                 previous_block_info,
                 current_block_info,
             ),
@@ -44,7 +54,7 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
                 comment,
                 tx,
                 expect,
-            } => denali_step::sc_call::execute(state, tx_id, tx, expect),
+            } => denali_step::sc_call::execute(state, tx_id, tx, expect, check_gas),
             Step::ScQuery {
                 tx_id,
                 comment,
@@ -56,7 +66,7 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
                 comment,
                 tx,
                 expect,
-            } => denali_step::sc_deploy::execute(state, tx_id, tx, expect),
+            } => denali_step::sc_deploy::execute(state, tx_id, tx, expect, check_gas),
             Step::Transfer { tx_id, comment, tx } => denali_step::transfer::execute(state, tx),
             Step::ValidatorReward { tx_id, comment, tx } => {
                 Rc::get_mut(state)
@@ -66,9 +76,105 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
             Step::CheckState { comment, accounts } => {
                 denali_step::check_state::execute(accounts, Rc::get_mut(state).unwrap());
             },
-            Step::DumpState { .. } => {
-                state.print_accounts();
+            Step::DumpState { comment, path } => {
+                if let Some(path) = path {
+                    let parent_path = steps_path.parent().unwrap();
+                    state.dump_state_to_file(parent_path.join(path));
+                } else {
+                    state.print_accounts();
+                }
+            },
+            Step::SaveSnapshot { comment, name } => {
+                Rc::get_mut(state).unwrap().save_snapshot(name);
+            },
+            Step::LoadSnapshot { comment, name } => {
+                Rc::get_mut(state).unwrap().load_snapshot(name);
+            },
+            Step::ExecuteCrossShardCalls { comment } => {
+                execute_cross_shard_calls(state);
             },
         }
     }
 }
+
+/// Environment variable pointing to a directory with the node gas schedule files,
+/// e.g. `gasScheduleV3.toml`, used for the `"v3"` and `"v4"` schedules if not registered.
+const GAS_SCHEDULE_DIR_ENV_VAR: &str = "DENALI_GAS_SCHEDULE_DIR";
+
+const NODE_GAS_SCHEDULE_NAMES: &[&str] = &["v3", "v4"];
+
+/// `"dummy"` makes every operation cost 1 gas,
+/// a path to a `.toml` or `.json` file loads the schedule from that file (relative to the scenario).
+///
+/// The node schedules, `"v3"` and `"v4"`, are not bundled. They are taken from
+/// `BlockchainMock::register_gas_schedule`, or else from the directory in `DENALI_GAS_SCHEDULE_DIR`.
+/// If neither is available, the scenario can only run if it does not check gas,
+/// in which case the gas schedule of the world is left unchanged.
+/// Any other name is an error.
+fn apply_gas_schedule(
+    steps_path: &Path,
+    scenario: &Scenario,
+    gas_schedule_name: &str,
+    state: &mut Rc<BlockchainMock>,
+) {
+    let gas_schedule = if gas_schedule_name == "dummy" {
+        GasSchedule::dummy()
+    } else if gas_schedule_name.ends_with(".toml") || gas_schedule_name.ends_with(".json") {
+        let parent_path = steps_path.parent().unwrap();
+        GasSchedule::load_file(parent_path.join(gas_schedule_name))
+    } else if let Some(gas_schedule) = state.named_gas_schedules.get(gas_schedule_name) {
+        gas_schedule.clone()
+    } else if NODE_GAS_SCHEDULE_NAMES.contains(&gas_schedule_name) {
+        if let Some(gas_schedule) = load_node_gas_schedule(gas_schedule_name) {
+            gas_schedule
+        } else {
+            assert!(
+                !scenario_checks_gas(steps_path, scenario),
+                "gas schedule {:?} not available, but {:?} checks gas; set {} to load it",
+                gas_schedule_name,
+                steps_path,
+                GAS_SCHEDULE_DIR_ENV_VAR
+            );
+            return;
+        }
+    } else {
+        panic!(
+            "unknown gas schedule {:?} in {:?}",
+            gas_schedule_name, steps_path
+        );
+    };
+    Rc::get_mut(state).unwrap().set_gas_schedule(gas_schedule);
+}
+
+/// Loads e.g. `gasScheduleV3.toml` for `"v3"`, if the gas schedule directory is configured.
+fn load_node_gas_schedule(gas_schedule_name: &str) -> Option<GasSchedule> {
+    let dir = std::env::var_os(GAS_SCHEDULE_DIR_ENV_VAR)?;
+    let file_name = format!("gasSchedule{}.toml", gas_schedule_name.to_uppercase());
+    Some(GasSchedule::load_file(Path::new(&dir).join(file_name)))
+}
+
+/// True if the scenario, or any of its external steps, expects a specific amount of gas remaining or refunded.
+fn scenario_checks_gas(steps_path: &Path, scenario: &Scenario) -> bool {
+    if scenario.check_gas == Some(false) {
+        return false;
+    }
+    scenario.steps.iter().any(|step| match step {
+        Step::ExternalSteps { path } => {
+            let external_steps_path = steps_path.parent().unwrap().join(path);
+            let external_scenario = denali::parse_scenario(external_steps_path.as_path());
+            scenario_checks_gas(external_steps_path.as_path(), &external_scenario)
+        },
+        Step::ScCall { expect, .. } | Step::ScDeploy { expect, .. } => {
+            expect.as_ref().map_or(false, tx_expect_checks_gas)
+        },
+        _ => false,
+    })
+}
+
+fn tx_expect_checks_gas(tx_expect: &TxExpect) -> bool {
+    let checks_gas_remaining = tx_expect
+        .gas
+        .as_ref()
+        .map_or(false, |expected_gas| !expected_gas.is_star());
+    checks_gas_remaining || !tx_expect.refund.is_star()
+}
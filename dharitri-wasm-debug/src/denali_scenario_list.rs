@@ -0,0 +1,30 @@
+use std::{fs, path::Path};
+
+const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+
+/// Used by the tests generated with `denali_tests!`.
+/// The macro lists the scenario files at compile time,
+/// so scenarios added or removed after that are only picked up after a rebuild.
+#[doc(hidden)]
+pub fn check_denali_scenario_list<P: AsRef<Path>>(dir_path: P, generated: &[&str]) {
+    let dir_path = dir_path.as_ref();
+    let mut found: Vec<String> = fs::read_dir(dir_path)
+        .unwrap_or_else(|err| panic!("could not read {}: {}", dir_path.display(), err))
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            file_name
+                .strip_suffix(SCENARIO_FILE_SUFFIX)
+                .map(|name| name.to_string())
+        })
+        .collect();
+    found.sort();
+
+    assert!(
+        found == generated,
+        "Denali scenarios in {} changed since the tests were generated, touch the test file to regenerate them.\n\tGenerated: {:?}\n\tFound: {:?}",
+        dir_path.display(),
+        generated,
+        found
+    );
+}
@@ -1,12 +1,12 @@
 use denali::model::{
     AddressKey, BytesValue, CheckDct, CheckDctData, CheckDctInstance, CheckDctInstances,
-    CheckDctMap, CheckStorage, CheckValue, Checkable,
+    CheckDctMap, CheckStorage, CheckStorageValue, CheckValue, Checkable,
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
 
 use crate::{
-    bytes_to_string, verbose_hex,
+    bytes_to_string, denali_expr, verbose_address, verbose_hex,
     world_mock::{AccountDct, BlockchainMock, DctData, DctInstance, DctInstances},
 };
 
@@ -46,6 +46,25 @@ pub fn execute(accounts: &denali::model::CheckAccounts, state: &mut BlockchainMo
                 std::str::from_utf8(actual_code.as_slice()).unwrap()
             );
 
+            let actual_code_metadata = account.code_metadata.into_bytes().to_vec();
+            assert!(
+                expected_account.code_metadata.check(&actual_code_metadata),
+                "bad account code metadata. Address: {}. Want: {}. Have: {}",
+                expected_address,
+                expected_account.code_metadata,
+                verbose_hex(&actual_code_metadata)
+            );
+
+            assert!(
+                expected_account
+                    .developer_rewards
+                    .check(&account.developer_rewards),
+                "bad account developer rewards. Address: {}. Want: {}. Have: {}",
+                expected_address,
+                expected_account.developer_rewards,
+                account.developer_rewards
+            );
+
             if let CheckStorage::Equal(eq) = &expected_account.storage {
                 let default_value = &Vec::new();
                 for (expected_key, expected_value) in eq.storages.iter() {
@@ -55,30 +74,43 @@ pub fn execute(accounts: &denali::model::CheckAccounts, state: &mut BlockchainMo
                         .unwrap_or(default_value);
                     assert!(
                         expected_value.check(actual_value),
-                        "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
+                        "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}{}",
                         expected_address,
                         expected_key,
                         expected_value,
-                        verbose_hex(actual_value)
+                        denali_expr(actual_value),
+                        storage_value_diff(expected_value, actual_value)
                     );
                 }
 
                 let default_check_value = CheckValue::Equal(BytesValue::empty());
                 for (actual_key, actual_value) in account.storage.iter() {
-                    let expected_value = eq
-                        .storages
-                        .get(&actual_key.clone().into())
-                        .unwrap_or(&default_check_value);
-                    if expected_value.to_string() == default_check_value.to_string()
-                        && !eq.other_storages_allowed
-                    {
+                    if eq.storages.contains_key(&actual_key.clone().into()) {
+                        continue;
+                    }
+
+                    let mut matches_key_prefix = false;
+                    for (key_prefix, expected_value) in eq.key_prefix_checks(actual_key) {
+                        matches_key_prefix = true;
                         assert!(
                             expected_value.check(actual_value),
-                            "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
+                            "bad storage value. Address: {}. Key: {} (matching {}|*). Want: {}. Have: {}",
                             expected_address,
-                            verbose_hex(actual_key),
+                            denali_expr(actual_key),
+                            key_prefix,
                             expected_value,
-                            verbose_hex(actual_value)
+                            denali_expr(actual_value)
+                        );
+                    }
+
+                    if !matches_key_prefix && !eq.other_storages_allowed {
+                        assert!(
+                            default_check_value.check(actual_value.as_slice()),
+                            "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
+                            expected_address,
+                            denali_expr(actual_key),
+                            default_check_value,
+                            denali_expr(actual_value)
                         );
                     }
                 }
@@ -87,12 +119,20 @@ pub fn execute(accounts: &denali::model::CheckAccounts, state: &mut BlockchainMo
         } else {
             assert!(
                 accounts.other_accounts_allowed,
-                "Expected account not found"
+                "Expected account not found. Address: {}",
+                expected_address
             );
         }
     }
 }
 
+fn storage_value_diff(expected: &CheckStorageValue, actual: &[u8]) -> String {
+    match expected {
+        CheckStorageValue::Value(check_value) => super::check_value_diff(check_value, actual),
+        _ => String::new(),
+    }
+}
+
 pub fn check_account_dct(address: &AddressKey, expected: &CheckDctMap, actual: &AccountDct) {
     match expected {
         CheckDctMap::Star => {},
@@ -274,7 +314,7 @@ pub fn check_token_instance(
             token,
             expected_value.nonce.value,
             expected_value.creator,
-            verbose_hex(actual_creator),
+            verbose_address(actual_creator),
         ))
     }
 
@@ -298,15 +338,19 @@ pub fn check_token_instance(
         ))
     }
 
-    let actual_uri = actual_value.metadata.uri.clone().unwrap_or_default();
-    if !expected_value.uri.check(&actual_uri) {
+    let actual_uris = actual_value.metadata.uris.as_slice();
+    if !expected_value.uri.check(actual_uris) {
         errors.push(format!(
-            "bad dct uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+            "bad dct uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: [{}]",
             address,
             token,
             expected_value.nonce.value,
             expected_value.uri,
-            verbose_hex(&actual_uri),
+            actual_uris
+                .iter()
+                .map(|uri| denali_expr(uri))
+                .collect::<Vec<_>>()
+                .join(", "),
         ))
     }
 }
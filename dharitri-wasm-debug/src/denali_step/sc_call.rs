@@ -8,18 +8,20 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output, check_tx_refund, check_with_print_messages};
 
 pub fn execute(
     state: &mut Rc<BlockchainMock>,
     tx_id: &str,
     tx: &TxCall,
     expect: &Option<TxExpect>,
+    check_gas: bool,
 ) {
     let tx_input = TxInput {
         from: tx.from.value.into(),
         to: tx.to.value.into(),
         moax_value: tx.moax_value.value.clone(),
+        moax_value_transferred: false,
         dct_values: tx_dct_transfers_from_denali(tx.dct_value.as_slice()),
         func_name: tx.function.as_bytes().to_vec(),
         args: tx
@@ -33,7 +35,15 @@ pub fn execute(
     };
     let tx_result = sc_call_with_async_and_callback(tx_input, state, true);
     if let Some(tx_expect) = expect {
-        check_tx_output(tx_id, tx_expect, &tx_result);
+        check_with_print_messages(&tx_result, || {
+            check_tx_output(tx_id, tx_expect, &tx_result);
+            if check_gas {
+                check_tx_gas(tx_id, tx_expect, tx.gas_limit.value, &tx_result);
+                let refund =
+                    state.tx_gas_refund(tx.gas_limit.value, tx.gas_price.value, &tx_result);
+                check_tx_refund(tx_id, tx_expect, &refund);
+            }
+        });
     }
 }
 
@@ -9,18 +9,20 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output, check_tx_refund, check_with_print_messages};
 
 pub fn execute(
     state: &mut Rc<BlockchainMock>,
     tx_id: &str,
     tx: &TxDeploy,
     expect: &Option<TxExpect>,
+    check_gas: bool,
 ) {
     let tx_input = TxInput {
         from: tx.from.value.into(),
         to: Address::zero(),
         moax_value: tx.moax_value.value.clone(),
+        moax_value_transferred: false,
         dct_values: Vec::new(),
         func_name: b"init".to_vec(),
         args: tx
@@ -34,6 +36,14 @@ pub fn execute(
     };
     let tx_result = sc_create(tx_input, &tx.contract_code.value, state);
     if let Some(tx_expect) = expect {
-        check_tx_output(tx_id, tx_expect, &tx_result);
+        check_with_print_messages(&tx_result, || {
+            check_tx_output(tx_id, tx_expect, &tx_result);
+            if check_gas {
+                check_tx_gas(tx_id, tx_expect, tx.gas_limit.value, &tx_result);
+                let refund =
+                    state.tx_gas_refund(tx.gas_limit.value, tx.gas_price.value, &tx_result);
+                check_tx_refund(tx_id, tx_expect, &refund);
+            }
+        });
     }
 }
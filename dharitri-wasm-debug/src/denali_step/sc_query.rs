@@ -9,13 +9,14 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_output, check_with_print_messages};
 
 pub fn execute(state: Rc<BlockchainMock>, tx_id: &str, tx: &TxQuery, expect: &Option<TxExpect>) {
     let tx_input = TxInput {
         from: tx.to.value.into(),
         to: tx.to.value.into(),
         moax_value: BigUint::from(0u32),
+        moax_value_transferred: false,
         dct_values: Vec::new(),
         func_name: tx.function.as_bytes().to_vec(),
         args: tx
@@ -34,6 +35,8 @@ pub fn execute(state: Rc<BlockchainMock>, tx_id: &str, tx: &TxQuery, expect: &Op
         "Can't query a view function that performs an async call"
     );
     if let Some(tx_expect) = expect {
-        check_tx_output(tx_id, tx_expect, &tx_result);
+        check_with_print_messages(&tx_result, || {
+            check_tx_output(tx_id, tx_expect, &tx_result);
+        });
     }
 }
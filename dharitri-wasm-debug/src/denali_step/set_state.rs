@@ -1,18 +1,20 @@
 use std::collections::BTreeMap;
 
-use dharitri_wasm::types::Address;
-use denali::model::{Account, AddressKey, BlockInfo, NewAddress};
+use dharitri_wasm::types::{Address, CodeMetadata};
+use denali::model::{Account, AddressKey, BlockInfo, BytesKey, NewAddress, TokenProperties};
 use num_bigint::BigUint;
 
 use crate::world_mock::{
-    is_smart_contract_address, AccountData, AccountDct, BlockInfo as CrateBlockInfo,
-    BlockchainMock, DctData, DctInstance, DctInstanceMetadata, DctInstances, DctRoles,
+    default_contract_code_metadata, is_smart_contract_address, AccountData, AccountDct,
+    BlockInfo as CrateBlockInfo, BlockchainMock, DctData, DctInstance, DctInstanceMetadata,
+    DctInstances, DctRoles, DctTokenProperties,
 };
 
 pub fn execute(
     state: &mut BlockchainMock,
     accounts: &BTreeMap<AddressKey, Account>,
     new_addresses: &[NewAddress],
+    token_properties: &BTreeMap<BytesKey, TokenProperties>,
     previous_block_info: &Option<BlockInfo>,
     current_block_info: &Option<BlockInfo>,
 ) {
@@ -58,10 +60,26 @@ pub fn execute(
                 .code
                 .as_ref()
                 .map(|bytes_value| bytes_value.value.clone()),
+            code_metadata: account
+                .code_metadata
+                .as_ref()
+                .map(|bytes_value| convert_denali_code_metadata(bytes_value.value.as_slice()))
+                .unwrap_or_else(|| {
+                    if account.code.is_some() {
+                        default_contract_code_metadata()
+                    } else {
+                        CodeMetadata::DEFAULT
+                    }
+                }),
             contract_owner: account
                 .owner
                 .as_ref()
                 .map(|address_value| address_value.value.into()),
+            developer_rewards: account
+                .developer_rewards
+                .as_ref()
+                .map(|rewards| rewards.value.clone())
+                .unwrap_or_default(),
         });
     }
     for new_address in new_addresses.iter() {
@@ -75,6 +93,12 @@ pub fn execute(
             new_address.new_address.value.into(),
         )
     }
+    for (token_identifier, properties) in token_properties.iter() {
+        state.set_dct_token_properties(
+            token_identifier.value.as_slice(),
+            convert_denali_token_properties_to_world_mock(properties),
+        );
+    }
     if let Some(block_info_obj) = &*previous_block_info {
         update_block_info(&mut state.previous_block_info, block_info_obj);
     }
@@ -102,7 +126,7 @@ fn convert_denali_dct_to_world_mock(
                 .token_identifier
                 .as_ref()
                 .map(|token_identifier| token_identifier.value.clone())
-                .unwrap_or_default(),
+                .unwrap_or_else(|| token_identifier.to_vec()),
             instances: DctInstances::new_from_hash(
                 full_dct
                     .instances
@@ -161,13 +185,31 @@ fn convert_denali_dct_instance_to_world_mock(
                 .map(|royalties| royalties.value)
                 .unwrap_or_default(),
             hash: denali_dct.hash.as_ref().map(|hash| hash.value.clone()),
-            uri: denali_dct.uri.as_ref().map(|uri| uri.value.clone()),
+            uris: denali_dct.uri.iter().map(|uri| uri.value.clone()).collect(),
             attributes: denali_dct
                 .attributes
                 .as_ref()
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: false,
+    }
+}
+
+fn convert_denali_token_properties_to_world_mock(
+    denali_properties: &TokenProperties,
+) -> DctTokenProperties {
+    let is_set = |property: &Option<denali::model::U64Value>| {
+        property
+            .as_ref()
+            .map(|u64_value| u64_value.value > 0)
+            .unwrap_or_default()
+    };
+    DctTokenProperties {
+        paused: is_set(&denali_properties.paused),
+        can_freeze: is_set(&denali_properties.can_freeze),
+        can_wipe: is_set(&denali_properties.can_wipe),
+        limited_transfer: is_set(&denali_properties.limited_transfer),
     }
 }
 
@@ -201,3 +243,13 @@ fn update_block_info(
         block_info.block_random_seed = Box::from(seed);
     }
 }
+
+fn convert_denali_code_metadata(bytes: &[u8]) -> CodeMetadata {
+    assert!(
+        bytes.len() <= 2,
+        "code metadata cannot be longer than 2 bytes"
+    );
+    let mut code_metadata_bytes = [0u8; 2];
+    code_metadata_bytes[2 - bytes.len()..].copy_from_slice(bytes);
+    CodeMetadata::from(code_metadata_bytes)
+}
@@ -13,6 +13,7 @@ pub fn execute(state: &mut Rc<BlockchainMock>, tx_transfer: &TxTransfer) {
         from: tx_transfer.from.value.into(),
         to: tx_transfer.to.value.into(),
         moax_value: tx_transfer.moax_value.value.clone(),
+        moax_value_transferred: false,
         dct_values: tx_dct_transfers_from_denali(tx_transfer.dct_value.as_slice()),
         func_name: Vec::new(),
         args: Vec::new(),
@@ -20,5 +21,6 @@ pub fn execute(state: &mut Rc<BlockchainMock>, tx_transfer: &TxTransfer) {
         gas_price: tx_transfer.gas_price.value,
         tx_hash: H256::zero(),
     };
-    sc_call(tx_input, state, true);
+    // transfer steps have no expect section, so the result is not checked
+    let _ = sc_call(tx_input, state, true);
 }
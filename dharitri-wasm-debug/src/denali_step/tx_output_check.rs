@@ -1,6 +1,20 @@
-use denali::model::{CheckLogs, Checkable, TxExpect};
+use denali::model::{BytesValue, CheckLogs, CheckValue, Checkable, TxExpect};
 
-use crate::{address_hex, bytes_to_string, tx_mock::TxResult, verbose_hex};
+use num_bigint::BigUint;
+
+use crate::{
+    address_bech32, bytes_diff, bytes_to_string, denali_expr, tx_mock::TxResult, verbose_address,
+    verbose_hex,
+};
+
+/// Runs the checks of a step, echoing the contract print output if any of them fails.
+pub fn check_with_print_messages<F: FnOnce()>(tx_result: &TxResult, checks: F) {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(checks));
+    if let Err(panic_any) = result {
+        tx_result.print_messages_to_stderr();
+        std::panic::resume_unwind(panic_any);
+    }
+}
 
 pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult) {
     let have_str = tx_result.result_message.as_str();
@@ -18,17 +32,26 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         tx_result.result_values.len(),
         "bad out value. Tx id: {}. Want: {:?}. Have: {:?}",
         tx_id,
-        tx_expect.out,
-        tx_result.result_values
+        tx_expect
+            .out
+            .iter()
+            .map(|expected_out| expected_out.to_string())
+            .collect::<Vec<_>>(),
+        tx_result
+            .result_values
+            .iter()
+            .map(|value| denali_expr(value))
+            .collect::<Vec<_>>()
     );
     for (i, expected_out) in tx_expect.out.iter().enumerate() {
         let actual_value = &tx_result.result_values[i];
         assert!(
             expected_out.check(actual_value.as_slice()),
-            "bad out value. Tx id: {}. Want: {}. Have: {}",
+            "bad out value. Tx id: {}. Want: {}. Have: {}{}",
             tx_id,
             expected_out,
-            verbose_hex(actual_value.as_slice())
+            denali_expr(actual_value.as_slice()),
+            check_value_diff(expected_out, actual_value.as_slice())
         );
     }
 
@@ -57,11 +80,11 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
 					actual_log.denali_check(expected_log),
 					"Logs do not match. Tx id: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
 					tx_id,
-					verbose_hex(&expected_log.address.value),
+					verbose_address(&expected_log.address.value),
 					&expected_log.endpoint,
 					expected_log.topics.iter().map(|topic| topic.to_string()).collect::<String>(),
 					&expected_log.data,
-					address_hex(&actual_log.address),
+					address_bech32(&actual_log.address),
 					bytes_to_string(&actual_log.endpoint),
 					actual_log.topics.iter().map(|topic| verbose_hex(topic)).collect::<String>(),
 					bytes_to_string(&actual_log.data),
@@ -70,3 +93,38 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         },
     }
 }
+
+/// Checks the gas remaining after the transaction, if the scenario specifies it.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, gas_limit: u64, tx_result: &TxResult) {
+    if let Some(expected_gas) = &tx_expect.gas {
+        let gas_remaining = gas_limit.saturating_sub(tx_result.gas_used);
+        assert!(
+            expected_gas.check(gas_remaining),
+            "gas remaining mismatch. Tx id: {}. Want: {}. Have: {}",
+            tx_id,
+            expected_gas,
+            gas_remaining
+        );
+    }
+}
+
+/// Checks the MOAX value refunded to the sender for the unused gas.
+pub fn check_tx_refund(tx_id: &str, tx_expect: &TxExpect, refund: &BigUint) {
+    assert!(
+        tx_expect.refund.check(refund),
+        "refund mismatch. Tx id: {}. Want: {}. Have: {}",
+        tx_id,
+        tx_expect.refund,
+        refund
+    );
+}
+
+/// The byte diff is only relevant when an exact value was expected.
+pub(crate) fn check_value_diff(expected: &CheckValue<BytesValue>, actual: &[u8]) -> String {
+    match expected {
+        CheckValue::Equal(expected_value) => {
+            format!("\n{}", bytes_diff(expected_value.value.as_slice(), actual))
+        },
+        CheckValue::Star => String::new(),
+    }
+}
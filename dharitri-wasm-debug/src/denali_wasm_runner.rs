@@ -0,0 +1,11 @@
+use crate::{denali_rs, world_mock::BlockchainMock};
+
+use std::path::Path;
+
+/// Runs denali test using the Rust infrastructure, but executing the compiled wasm contracts.
+/// The contract bytecode referenced in the scenario is run by an embedded interpreter,
+/// so the contract map of the world is not used.
+pub fn denali_wasm<P: AsRef<Path>>(relative_path: P, mut world: BlockchainMock) {
+    world.set_wasm_execution(true);
+    denali_rs(relative_path, world);
+}
@@ -1,4 +1,5 @@
 use alloc::string::String;
+use denali::value_interpreter::{reconstruct, ExprReconstructorHint};
 use dharitri_wasm::{
     api::ManagedTypeApi,
     types::{Address, BigUint, BoxedBytes, ManagedType},
@@ -14,6 +15,20 @@ pub fn address_hex(address: &Address) -> String {
     alloc::format!("0x{}", hex::encode(address.as_bytes()))
 }
 
+/// Renders the address the way it appears on the blockchain, e.g. `moa1...`.
+pub fn address_bech32(address: &Address) -> String {
+    crate::bech32::encode(address)
+}
+
+/// Renders 32-byte values as bech32 addresses, and anything else as hex.
+pub fn verbose_address(value: &[u8]) -> String {
+    if value.len() == 32 {
+        address_bech32(&Address::from_slice(value))
+    } else {
+        verbose_hex(value)
+    }
+}
+
 pub fn key_hex(key: &[u8]) -> String {
     alloc::format!("0x{}", hex::encode(key))
 }
@@ -22,6 +37,29 @@ pub fn verbose_hex(value: &[u8]) -> String {
     alloc::format!("0x{}", hex::encode(value))
 }
 
+/// Renders a value as the most plausible denali expression, e.g. `str:...`, `address:...` or a number.
+pub fn denali_expr(value: &[u8]) -> String {
+    reconstruct(value, ExprReconstructorHint::NoHint)
+}
+
+/// Shows where two values start to diverge. The bytes after the common prefix are shown in brackets.
+pub fn bytes_diff(want: &[u8], have: &[u8]) -> String {
+    let common_length = want
+        .iter()
+        .zip(have.iter())
+        .take_while(|(want_byte, have_byte)| want_byte == have_byte)
+        .count();
+    let common_hex = hex::encode(&want[..common_length]);
+    alloc::format!(
+        "Byte diff at offset {}:\n\tWant: 0x{}[{}]\n\tHave: 0x{}[{}]",
+        common_length,
+        common_hex,
+        hex::encode(&want[common_length..]),
+        common_hex,
+        hex::encode(&have[common_length..]),
+    )
+}
+
 /// returns it as hex formatted number if it's not valid utf8
 pub fn bytes_to_string(bytes: &[u8]) -> String {
     String::from_utf8(bytes.to_vec()).unwrap_or_else(|_| verbose_hex(bytes))
@@ -2,16 +2,21 @@
 
 pub mod abi_json;
 pub mod api;
+pub mod bech32;
 mod contract_map;
+pub mod crypto;
 mod display_util;
 mod managed_test_util;
 mod denali_go_runner;
 mod denali_rs_runner;
+mod denali_scenario_list;
 mod denali_step;
+mod denali_wasm_runner;
 pub mod meta;
 pub mod testing_framework;
 pub mod tx_execution;
 pub mod tx_mock;
+pub mod wasm_executor;
 pub mod world_mock;
 
 pub use contract_map::*;
@@ -21,6 +26,9 @@ pub use denali_step::*;
 
 pub use denali_go_runner::denali_go;
 pub use denali_rs_runner::denali_rs;
+pub use denali_scenario_list::check_denali_scenario_list;
+pub use denali_wasm_runner::denali_wasm;
+pub use dharitri_wasm_debug_derive::denali_tests;
 pub use tx_mock::DebugApi;
 pub use world_mock::BlockchainMock;
 
@@ -2,20 +2,27 @@ use std::{collections::HashMap, path::PathBuf, rc::Rc, str::FromStr};
 
 use dharitri_wasm::{
     contract_base::{CallableContract, ContractBase},
-    types::{Address, DctLocalRole, H256},
+    types::{Address, CodeMetadata, DctLocalRole, MessageHashType, H256},
 };
 
 use crate::{
-    rust_biguint,
+    crypto, rust_biguint,
     testing_framework::bytes_to_hex,
-    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputDCT},
-    world_mock::{AccountData, AccountDct, DctInstanceMetadata},
+    tx_execution::{execute_cross_shard_calls, execute_result_calls, interpret_panic_as_tx_result},
+    tx_mock::{
+        TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxLog, TxPanic, TxResult,
+        TxResultCalls,
+    },
+    world_mock::{
+        default_contract_code_metadata, AccountData, AccountDct, DctInstanceMetadata,
+        DctTokenProperties, GasSchedule,
+    },
     BlockchainMock, DebugApi,
 };
 
 use super::{
     tx_denali::{ScCallDenali, TxExpectDenali},
-    AddressFactory, DenaliGenerator, ScQueryDenali,
+    AddressFactory, DenaliGenerator, KeyFactory, KeyPair, ScQueryDenali,
 };
 
 pub struct ContractObjWrapper<
@@ -45,10 +52,15 @@ where
 
 pub struct BlockchainStateWrapper {
     address_factory: AddressFactory,
+    key_factory: KeyFactory,
     rc_b_mock: Rc<BlockchainMock>,
     address_to_code_path: HashMap<Address, Vec<u8>>,
     denali_generator: DenaliGenerator,
     workspace_path: PathBuf,
+    tx_gas_limit: u64,
+    tx_gas_price: u64,
+    last_tx_logs: Vec<TxLog>,
+    last_tx_print_messages: Vec<String>,
 }
 
 pub enum StateChange {
@@ -64,10 +76,15 @@ impl BlockchainStateWrapper {
 
         BlockchainStateWrapper {
             address_factory: AddressFactory::new(),
+            key_factory: KeyFactory::new(),
             rc_b_mock: Rc::new(BlockchainMock::new()),
             address_to_code_path: HashMap::new(),
             denali_generator: DenaliGenerator::new(),
             workspace_path: current_dir,
+            tx_gas_limit: u64::MAX,
+            tx_gas_price: 0,
+            last_tx_logs: Vec::new(),
+            last_tx_print_messages: Vec::new(),
         }
     }
 
@@ -95,6 +112,26 @@ impl BlockchainStateWrapper {
         );
     }
 
+    pub fn check_developer_rewards(
+        &self,
+        address: &Address,
+        expected_developer_rewards: &num_bigint::BigUint,
+    ) {
+        let actual_developer_rewards = match &self.rc_b_mock.accounts.get(address) {
+            Some(acc) => acc.developer_rewards.clone(),
+            None => rust_biguint!(0),
+        };
+
+        assert_eq!(
+            expected_developer_rewards,
+            &actual_developer_rewards,
+            "Developer rewards mismatch for address {}. Expected: {}, have {}",
+            address_to_hex(address),
+            expected_developer_rewards,
+            actual_developer_rewards
+        );
+    }
+
     pub fn check_dct_balance(
         &self,
         address: &Address,
@@ -159,16 +196,121 @@ impl BlockchainStateWrapper {
         );
     }
 
-    /*
-    pub fn check_nft_balance_with_properties(
+    /// Reads a value directly from the storage of an account. Missing keys yield an empty value.
+    pub fn get_storage_raw(&self, address: &Address, key: &[u8]) -> Vec<u8> {
+        match self.rc_b_mock.accounts.get(address) {
+            Some(acc) => acc.storage.get(key).cloned().unwrap_or_default(),
+            None => panic!(
+                "get_storage_raw: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    /// Reads and top-decodes a value directly from the storage of an account.
+    /// Only works for types with un-managed decoding.
+    /// For managed types, use `get_sc_storage` and the storage mappers of the contract.
+    pub fn get_storage<T: dharitri_wasm::dharitri_codec::TopDecode>(
+        &self,
+        address: &Address,
+        key: &[u8],
+    ) -> T {
+        let raw_value = self.get_storage_raw(address, key);
+        match T::top_decode(raw_value.as_slice()) {
+            Result::Ok(value) => value,
+            Result::Err(err) => panic!(
+                "Failed to decode storage value under key {}: {:?}",
+                bytes_to_hex(key),
+                err
+            ),
+        }
+    }
+
+    /// Same as `check_nft_balance`, but also checks the metadata of the NFT.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_nft_balance_with_properties<T: dharitri_wasm::dharitri_codec::TopEncode>(
         &self,
         address: &Address,
         token_id: &[u8],
         nonce: u64,
         expected_balance: &num_bigint::BigUint,
+        expected_attributes: &T,
+        expected_royalties: u64,
+        expected_creator: Option<&Address>,
+        expected_name: Option<&[u8]>,
+        expected_hash: Option<&[u8]>,
+        expected_uris: &[Vec<u8>],
     ) {
+        self.check_nft_balance(
+            address,
+            token_id,
+            nonce,
+            expected_balance,
+            expected_attributes,
+        );
+
+        let metadata = self
+            .rc_b_mock
+            .accounts
+            .get(address)
+            .and_then(|acc| {
+                acc.dct
+                    .get_by_identifier_or_default(token_id)
+                    .instances
+                    .get_by_nonce(nonce)
+                    .map(|instance| instance.metadata.clone())
+            })
+            .unwrap_or_default();
+
+        assert_eq!(
+            expected_royalties,
+            metadata.royalties,
+            "DCT NFT royalties mismatch for address {}. Expected: {}, have {}",
+            address_to_hex(address),
+            expected_royalties,
+            metadata.royalties
+        );
+        assert_eq!(
+            expected_creator,
+            metadata.creator.as_ref(),
+            "DCT NFT creator mismatch for address {}. Expected: {:?}, have {:?}",
+            address_to_hex(address),
+            expected_creator.map(address_to_hex),
+            metadata.creator.as_ref().map(address_to_hex)
+        );
+        let expected_name = expected_name.unwrap_or_default();
+        assert_eq!(
+            expected_name,
+            metadata.name.as_slice(),
+            "DCT NFT name mismatch for address {}. Expected: {}, have {}",
+            address_to_hex(address),
+            bytes_to_hex(expected_name),
+            bytes_to_hex(&metadata.name)
+        );
+        assert_eq!(
+            expected_hash,
+            metadata.hash.as_deref(),
+            "DCT NFT hash mismatch for address {}. Expected: {:?}, have {:?}",
+            address_to_hex(address),
+            expected_hash.map(bytes_to_hex),
+            metadata.hash.as_deref().map(bytes_to_hex)
+        );
+        assert_eq!(
+            expected_uris,
+            metadata.uris.as_slice(),
+            "DCT NFT URIs mismatch for address {}. Expected: {:?}, have {:?}",
+            address_to_hex(address),
+            expected_uris
+                .iter()
+                .map(|uri| bytes_to_hex(uri))
+                .collect::<Vec<_>>(),
+            metadata
+                .uris
+                .iter()
+                .map(|uri| bytes_to_hex(uri))
+                .collect::<Vec<_>>()
+        );
     }
-    */
 }
 
 impl BlockchainStateWrapper {
@@ -237,6 +379,11 @@ impl BlockchainStateWrapper {
         sc_identifier: Option<Vec<u8>>,
         sc_denali_path_expr: Option<Vec<u8>>,
     ) {
+        let code_metadata = if sc_identifier.is_some() {
+            default_contract_code_metadata()
+        } else {
+            CodeMetadata::DEFAULT
+        };
         let acc_data = AccountData {
             address: address.clone(),
             nonce: 0,
@@ -245,7 +392,9 @@ impl BlockchainStateWrapper {
             storage: HashMap::new(),
             username: Vec::new(),
             contract_path: sc_identifier,
+            code_metadata,
             contract_owner: owner.cloned(),
+            developer_rewards: rust_biguint!(0),
         };
         self.denali_generator
             .set_account(&acc_data, sc_denali_path_expr);
@@ -256,7 +405,7 @@ impl BlockchainStateWrapper {
 
     pub fn set_moax_balance(&mut self, address: &Address, balance: &num_bigint::BigUint) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.moax_balance = balance.clone();
 
@@ -277,7 +426,7 @@ impl BlockchainStateWrapper {
         balance: &num_bigint::BigUint,
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.dct.set_dct_balance(
                     token_id.to_vec(),
@@ -302,7 +451,16 @@ impl BlockchainStateWrapper {
         attributes: &T,
     ) {
         self.set_nft_balance_all_properties(
-            address, token_id, nonce, balance, attributes, 0, None, None, None, None,
+            address,
+            token_id,
+            nonce,
+            balance,
+            attributes,
+            0,
+            None,
+            None,
+            None,
+            &[],
         );
     }
 
@@ -318,10 +476,10 @@ impl BlockchainStateWrapper {
         creator: Option<&Address>,
         name: Option<&[u8]>,
         hash: Option<&[u8]>,
-        uri: Option<&[u8]>,
+        uris: &[Vec<u8>],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 acc.dct.set_dct_balance(
                     token_id.to_vec(),
@@ -333,7 +491,7 @@ impl BlockchainStateWrapper {
                         royalties,
                         name: name.unwrap_or_default().to_vec(),
                         hash: hash.map(|h| h.to_vec()),
-                        uri: uri.map(|u| u.to_vec()),
+                        uris: uris.to_vec(),
                     },
                 );
 
@@ -353,7 +511,7 @@ impl BlockchainStateWrapper {
         roles: &[DctLocalRole],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match b_mock_ref.accounts.get_mut(address) {
+        match b_mock_ref.account_mut(address) {
             Some(acc) => {
                 let mut roles_raw = Vec::new();
                 for role in roles {
@@ -370,6 +528,88 @@ impl BlockchainStateWrapper {
         }
     }
 
+    /// Sets the token-level properties (paused, freeze/wipe capabilities, transfer role restriction)
+    /// that the mock checks on every transfer of the given token.
+    pub fn set_dct_token_properties(&mut self, token_id: &[u8], properties: DctTokenProperties) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_dct_token_properties(token_id, properties.clone());
+
+        self.denali_generator
+            .set_token_properties(token_id, &properties);
+    }
+
+    /// Freezes or unfreezes a token for an account, for all nonces.
+    pub fn set_dct_frozen(&mut self, address: &Address, token_id: &[u8], frozen: bool) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        match b_mock_ref.account_mut(address) {
+            Some(acc) => {
+                acc.dct.set_frozen(token_id, frozen);
+
+                self.add_denali_set_account(address);
+            },
+            None => panic!(
+                "set_dct_frozen: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    /// Contracts created with `create_sc_account` are upgradeable, readable and payable by default.
+    pub fn set_code_metadata(&mut self, address: &Address, code_metadata: CodeMetadata) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        match b_mock_ref.account_mut(address) {
+            Some(acc) => {
+                acc.code_metadata = code_metadata;
+
+                self.add_denali_set_account(address);
+            },
+            None => panic!(
+                "set_code_metadata: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    /// Writes a value directly to the storage of an account. Writing an empty value clears the key.
+    pub fn set_storage_raw(&mut self, address: &Address, key: &[u8], value: &[u8]) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        match b_mock_ref.account_mut(address) {
+            Some(acc) => {
+                if value.is_empty() {
+                    acc.storage.remove(key);
+                } else {
+                    acc.storage.insert(key.to_vec(), value.to_vec());
+                }
+
+                self.add_denali_set_account(address);
+            },
+            None => panic!(
+                "set_storage_raw: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    /// Top-encodes a value and writes it directly to the storage of an account.
+    /// For managed types, use `set_sc_storage` and the storage mappers of the contract.
+    pub fn set_storage<T: dharitri_wasm::dharitri_codec::TopEncode>(
+        &mut self,
+        address: &Address,
+        key: &[u8],
+        value: &T,
+    ) {
+        let mut serialized_value = Vec::new();
+        if let Result::Err(err) = value.top_encode(&mut serialized_value) {
+            panic!(
+                "Failed to encode storage value under key {}: {:?}",
+                bytes_to_hex(key),
+                err
+            )
+        }
+
+        self.set_storage_raw(address, key, &serialized_value);
+    }
+
     pub fn set_block_epoch(&mut self, block_epoch: u64) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         b_mock_ref.current_block_info.block_epoch = block_epoch;
@@ -470,6 +710,151 @@ impl BlockchainStateWrapper {
         );
     }
 
+    /// Saves the current world state under a name, to be restored later via `load_snapshot`.
+    pub fn save_snapshot(&mut self, name: &str) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.save_snapshot(name);
+
+        self.denali_generator.save_snapshot(name);
+    }
+
+    /// Rolls the world state back to a named snapshot. The snapshot can be loaded again later.
+    pub fn load_snapshot(&mut self, name: &str) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.load_snapshot(name);
+
+        self.denali_generator.load_snapshot(name);
+    }
+
+    /// Writes the current world state as a `setState` scenario, next to the denali output.
+    /// Other scenarios can start from this state by referencing the file in an `externalSteps` step.
+    pub fn dump_state(&self, file_name: &str) {
+        self.rc_b_mock
+            .dump_state_to_file(self.workspace_path.join(file_name));
+    }
+
+    /// Creates an independent copy of the wrapper and its world state.
+    /// The denali output of the fork starts from the current state of all accounts.
+    pub fn fork(&self) -> Self {
+        let b_mock = self.rc_b_mock.fork();
+
+        let mut denali_generator = DenaliGenerator::new();
+        let mut addresses: Vec<&Address> = b_mock.accounts.keys().collect();
+        addresses.sort_by_key(|address| address.as_bytes());
+        for address in addresses {
+            let opt_contract_path = self.address_to_code_path.get(address);
+            denali_generator.set_account(&b_mock.accounts[address], opt_contract_path.cloned());
+        }
+        denali_generator.set_block_info(&b_mock.current_block_info, &b_mock.previous_block_info);
+
+        BlockchainStateWrapper {
+            address_factory: self.address_factory.clone(),
+            key_factory: self.key_factory.clone(),
+            rc_b_mock: Rc::new(b_mock),
+            address_to_code_path: self.address_to_code_path.clone(),
+            denali_generator,
+            workspace_path: self.workspace_path.clone(),
+            tx_gas_limit: self.tx_gas_limit,
+            tx_gas_price: self.tx_gas_price,
+            last_tx_logs: Vec::new(),
+            last_tx_print_messages: Vec::new(),
+        }
+    }
+
+    /// Gas is not metered by default. Set a gas schedule to have the executed transactions consume gas.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_gas_schedule(gas_schedule);
+    }
+
+    /// Splits the accounts into shards, based on their address, like on the real network.
+    /// Synchronous calls between contracts in different shards fail afterwards.
+    pub fn set_num_shards(&mut self, num_shards: u32) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_num_shards(num_shards);
+    }
+
+    /// Executes the async calls, callbacks and transfer-execute calls waiting for another shard,
+    /// in the order they were produced, and returns their results.
+    /// Callbacks of the async calls executed now wait for the next call.
+    pub fn execute_cross_shard_calls(&mut self) -> Vec<TxResult> {
+        execute_cross_shard_calls(&mut self.rc_b_mock)
+    }
+
+    /// The gas limit of all subsequent transactions. Unlimited by default.
+    pub fn set_tx_gas_limit(&mut self, gas_limit: u64) {
+        self.tx_gas_limit = gas_limit;
+    }
+
+    /// The gas price of all subsequent transactions. Zero by default, so transactions cost nothing.
+    /// With a non-zero gas price, the caller pays `gas_limit * gas_price` upfront and gets the unused gas refunded,
+    /// so a gas limit and a metered gas schedule need to be set as well.
+    /// A caller that cannot pay gets a failed `TxResult`, without the transaction being executed.
+    /// Queries are free.
+    pub fn set_tx_gas_price(&mut self, gas_price: u64) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_tx_fee_settlement(true);
+        self.tx_gas_price = gas_price;
+    }
+
+    /// The share of the fee for the used gas that goes to the called contract. 30% by default.
+    pub fn set_developer_fees_percentage(&mut self, developer_fees_percentage: u64) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.set_developer_fees_percentage(developer_fees_percentage);
+    }
+
+    /// The logs emitted by the last executed transaction or query.
+    pub fn get_last_tx_logs(&self) -> Vec<TxLog> {
+        self.last_tx_logs.clone()
+    }
+
+    /// Everything the contract printed during the last executed transaction or query.
+    /// Also echoed to stderr when a `TxResult` assertion fails.
+    pub fn get_last_tx_print_messages(&self) -> Vec<String> {
+        self.last_tx_print_messages.clone()
+    }
+
+    /// Checks that the last executed transaction emitted exactly the events produced by `events_fn`, in order.
+    /// The expected events are generated by calling the `#[event]` methods of the contract,
+    /// so their topics and data are encoded the same way the contract encodes them.
+    pub fn check_last_tx_events<CB, ContractObjBuilder, EventsFn: FnOnce(CB)>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        events_fn: EventsFn,
+    ) where
+        CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
+        ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
+    {
+        let actual_logs = std::mem::take(&mut self.last_tx_logs);
+        let events_result = self.execute_query(sc_wrapper, events_fn);
+        events_result.assert_ok();
+        self.last_tx_logs = actual_logs;
+
+        let expected_logs = events_result.result_logs;
+        assert_eq!(
+            expected_logs.len(),
+            self.last_tx_logs.len(),
+            "Log amounts do not match. Want: {}. Have: {}",
+            expected_logs.len(),
+            self.last_tx_logs.len()
+        );
+
+        for (expected_log, actual_log) in expected_logs.iter().zip(self.last_tx_logs.iter()) {
+            assert!(
+                expected_log.address == actual_log.address
+                    && expected_log.topics == actual_log.topics
+                    && expected_log.data == actual_log.data,
+                "Logs do not match.\nWant: Address: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Topics: {:?}, Data: {}",
+                address_to_hex(&expected_log.address),
+                expected_log.topics.iter().map(hex::encode).collect::<Vec<_>>(),
+                hex::encode(&expected_log.data),
+                address_to_hex(&actual_log.address),
+                actual_log.topics.iter().map(hex::encode).collect::<Vec<_>>(),
+                hex::encode(&actual_log.data),
+            );
+        }
+    }
+
     pub fn add_denali_sc_call(
         &mut self,
         sc_call: ScCallDenali,
@@ -503,6 +888,42 @@ impl BlockchainStateWrapper {
     }
 }
 
+impl BlockchainStateWrapper {
+    /// Keys are generated deterministically, so the signatures are the same on every test run.
+    pub fn create_ed25519_keypair(&mut self) -> KeyPair {
+        self.key_factory.new_ed25519_keypair()
+    }
+
+    pub fn create_secp256k1_keypair(&mut self) -> KeyPair {
+        self.key_factory.new_secp256k1_keypair()
+    }
+
+    pub fn create_bls_keypair(&mut self) -> KeyPair {
+        self.key_factory.new_bls_keypair()
+    }
+
+    pub fn sign_ed25519(&self, secret_key: &[u8], message: &[u8]) -> Vec<u8> {
+        crypto::sign_ed25519(secret_key, message)
+            .unwrap_or_else(|| panic!("Invalid Ed25519 secret key"))
+    }
+
+    /// Produces a DER-encoded signature, as expected by `verify_secp256k1` and `verify_custom_secp256k1`.
+    /// The message is hashed as indicated by the hash type before signing.
+    pub fn sign_secp256k1(
+        &self,
+        secret_key: &[u8],
+        message: &[u8],
+        hash_type: MessageHashType,
+    ) -> Vec<u8> {
+        crypto::sign_secp256k1(secret_key, message, hash_type)
+            .unwrap_or_else(|| panic!("Invalid secp256k1 secret key"))
+    }
+
+    pub fn sign_bls(&self, secret_key: &[u8], message: &[u8]) -> Vec<u8> {
+        crypto::sign_bls(secret_key, message).unwrap_or_else(|| panic!("Invalid BLS secret key"))
+    }
+}
+
 impl BlockchainStateWrapper {
     pub fn execute_tx<CB, ContractObjBuilder, TxFn: FnOnce(CB) -> StateChange>(
         &mut self,
@@ -510,11 +931,19 @@ impl BlockchainStateWrapper {
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         moax_payment: &num_bigint::BigUint,
         tx_fn: TxFn,
-    ) where
+    ) -> TxResult
+    where
         CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
         ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
     {
-        self.execute_tx_any(caller, sc_wrapper, moax_payment, Vec::new(), tx_fn);
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            moax_payment,
+            Vec::new(),
+            self.tx_gas_price,
+            tx_fn,
+        )
     }
 
     pub fn execute_dct_transfer<CB, ContractObjBuilder, TxFn: FnOnce(CB) -> StateChange>(
@@ -525,7 +954,8 @@ impl BlockchainStateWrapper {
         dct_nonce: u64,
         dct_amount: &num_bigint::BigUint,
         tx_fn: TxFn,
-    ) where
+    ) -> TxResult
+    where
         CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
         ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
     {
@@ -534,7 +964,14 @@ impl BlockchainStateWrapper {
             nonce: dct_nonce,
             value: dct_amount.clone(),
         }];
-        self.execute_tx_any(caller, sc_wrapper, &rust_biguint!(0), dct_transfer, tx_fn);
+        self.execute_tx_any(
+            caller,
+            sc_wrapper,
+            &rust_biguint!(0),
+            dct_transfer,
+            self.tx_gas_price,
+            tx_fn,
+        )
     }
 
     pub fn execute_dct_multi_transfer<CB, ContractObjBuilder, TxFn: FnOnce(CB) -> StateChange>(
@@ -543,7 +980,8 @@ impl BlockchainStateWrapper {
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         dct_transfers: &[TxInputDCT],
         tx_fn: TxFn,
-    ) where
+    ) -> TxResult
+    where
         CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
         ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
     {
@@ -552,86 +990,235 @@ impl BlockchainStateWrapper {
             sc_wrapper,
             &rust_biguint!(0),
             dct_transfers.to_vec(),
+            self.tx_gas_price,
             tx_fn,
-        );
+        )
     }
 
     pub fn execute_query<CB, ContractObjBuilder, TxFn: FnOnce(CB)>(
         &mut self,
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         query_fn: TxFn,
-    ) where
+    ) -> TxResult
+    where
         CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
         ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
     {
-        self.execute_tx(
+        // no gas price, queries do not pay fees
+        self.execute_tx_any(
             sc_wrapper.address_ref(),
             sc_wrapper,
             &rust_biguint!(0),
+            Vec::new(),
+            0,
             |sc| {
                 query_fn(sc);
                 StateChange::Revert
             },
+        )
+    }
+
+    /// Reads contract storage through the storage mappers of the contract, without executing a transaction.
+    /// Managed values can only be used inside the closure, so convert them before returning.
+    pub fn get_sc_storage<CB, ContractObjBuilder, R, StorageFn: FnOnce(CB) -> R>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        storage_fn: StorageFn,
+    ) -> R
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
+        ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
+    {
+        let (result, _) = self.execute_in_sc_context(sc_wrapper, storage_fn);
+        result
+    }
+
+    /// Writes contract storage through the storage mappers of the contract, without executing a transaction.
+    /// Useful for setting up large states, e.g. populating a `MapMapper` before the first call.
+    pub fn set_sc_storage<CB, ContractObjBuilder, StorageFn: FnOnce(CB)>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        storage_fn: StorageFn,
+    ) where
+        CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
+        ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
+    {
+        let (_, tx_context_rc) = self.execute_in_sc_context(sc_wrapper, storage_fn);
+
+        let tx_context = Rc::try_unwrap(tx_context_rc).unwrap();
+        let updates = tx_context.into_blockchain_updates();
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        updates.apply(b_mock_ref);
+
+        self.add_denali_set_account(sc_wrapper.address_ref());
+    }
+
+    // runs a closure on the contract object, with the contract as both caller and callee,
+    // panics are propagated after the context is cleaned up
+    fn execute_in_sc_context<CB, ContractObjBuilder, R, ScFn: FnOnce(CB) -> R>(
+        &mut self,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        sc_fn: ScFn,
+    ) -> (R, Rc<TxContext>)
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
+        ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
+    {
+        let sc_address = sc_wrapper.address_ref();
+        let tx_cache = TxCache::new(self.rc_b_mock.clone());
+        let tx_input = build_tx_input(
+            sc_address,
+            sc_address,
+            &rust_biguint!(0),
+            Vec::new(),
+            u64::MAX,
+            0,
         );
+        let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
+        TxContextStack::static_push(tx_context_rc.clone());
+
+        let obj_builder = sc_wrapper.obj_builder;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            let sc = obj_builder(DebugApi::new(tx_context_rc));
+            sc_fn(sc)
+        }));
+
+        let tx_context_rc = TxContextStack::static_pop();
+        match result {
+            Ok(result) => (result, tx_context_rc),
+            Err(panic_any) => std::panic::resume_unwind(panic_any),
+        }
     }
 
     // deduplicates code for execution
+    // contract errors are caught and converted into the returned TxResult,
+    // in which case all changes are reverted;
+    // any other panic, e.g. a failed assertion in the test closure, is propagated
     fn execute_tx_any<CB, ContractObjBuilder, TxFn: FnOnce(CB) -> StateChange>(
         &mut self,
         caller: &Address,
         sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
         moax_payment: &num_bigint::BigUint,
         dct_payments: Vec<TxInputDCT>,
+        gas_price: u64,
         tx_fn: TxFn,
-    ) where
+    ) -> TxResult
+    where
         CB: ContractBase<Api = DebugApi> + CallableContract<DebugApi> + 'static,
         ContractObjBuilder: 'static + Copy + Fn(DebugApi) -> CB,
     {
         let sc_address = sc_wrapper.address_ref();
-        let tx_cache = TxCache::new(self.rc_b_mock.clone());
-        let rust_zero = rust_biguint!(0);
-
-        if moax_payment > &rust_zero {
-            tx_cache.subtract_moax_balance(caller, moax_payment);
-            tx_cache.increase_moax_balance(sc_address, moax_payment);
-        }
-
-        for dct in &dct_payments {
-            if dct.value > rust_zero {
-                let metadata = tx_cache.subtract_dct_balance(
-                    caller,
-                    &dct.token_identifier,
-                    dct.nonce,
-                    &dct.value,
-                );
-                tx_cache.increase_dct_balance(
-                    sc_address,
-                    &dct.token_identifier,
-                    dct.nonce,
-                    &dct.value,
-                    metadata,
-                );
+        if gas_price > 0 {
+            if !self.can_pay_tx_gas(caller, gas_price) {
+                let tx_result = TxResult::from_panic_obj(&TxPanic {
+                    status: 10,
+                    message: b"insufficient funds for gas".to_vec(),
+                });
+                self.last_tx_logs = Vec::new();
+                self.last_tx_print_messages = Vec::new();
+                return tx_result;
             }
+            self.rc_b_mock
+                .subtract_tx_gas(caller, self.tx_gas_limit, gas_price);
         }
 
-        let tx_input = build_tx_input(caller, sc_address, moax_payment, dct_payments);
+        let tx_cache = TxCache::new(self.rc_b_mock.clone());
+        let tx_input = build_tx_input(
+            caller,
+            sc_address,
+            moax_payment,
+            dct_payments,
+            self.tx_gas_limit,
+            gas_price,
+        );
         let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
         TxContextStack::static_push(tx_context_rc.clone());
 
-        let debug_api = DebugApi::new(tx_context_rc);
-        let sc = (sc_wrapper.obj_builder)(debug_api);
+        let obj_builder = sc_wrapper.obj_builder;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            transfer_tx_payments(&tx_context_rc);
 
-        let state_change = tx_fn(sc);
+            let debug_api = DebugApi::new(tx_context_rc);
+            let sc = obj_builder(debug_api);
 
-        let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
-        let updates = api_after_exec.into_blockchain_updates();
+            tx_fn(sc)
+        }));
+
+        let tx_context = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
+        let (mut tx_result, state_change) = match result {
+            Ok(state_change) => (tx_context.extract_result(), state_change),
+            Err(panic_any) => {
+                if !is_contract_error(panic_any.as_ref()) {
+                    std::panic::resume_unwind(panic_any);
+                }
+                let tx_result = interpret_panic_as_tx_result(panic_any);
+                let state_change = if tx_result.result_status == 0 {
+                    StateChange::Commit
+                } else {
+                    StateChange::Revert
+                };
+                (tx_result, state_change)
+            },
+        };
+        tx_result.gas_used = tx_context.gas_used_for_result(&tx_result);
+        tx_result.print_messages = tx_context.extract_print_messages();
 
-        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         match state_change {
             StateChange::Commit => {
+                let updates = tx_context.into_blockchain_updates();
+                let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
                 updates.apply(b_mock_ref);
+
+                // the calls produced by the transaction, calls to other shards only get queued
+                let result_calls =
+                    std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
+                tx_result =
+                    execute_result_calls(tx_result, sc_address, result_calls, &mut self.rc_b_mock);
             },
-            StateChange::Revert => {},
+            // releases the reference to the blockchain mock held by the tx cache
+            StateChange::Revert => drop(tx_context),
+        }
+        self.last_tx_logs = tx_result.result_logs.clone();
+        self.last_tx_print_messages = tx_result.print_messages.clone();
+        self.rc_b_mock
+            .settle_tx_fee(caller, sc_address, self.tx_gas_limit, gas_price, &tx_result);
+
+        tx_result
+    }
+
+    fn can_pay_tx_gas(&self, caller: &Address, gas_price: u64) -> bool {
+        let gas_cost = rust_biguint!(self.tx_gas_limit) * gas_price;
+        self.rc_b_mock
+            .accounts
+            .get(caller)
+            .map_or(false, |account| account.moax_balance >= gas_cost)
+    }
+}
+
+/// Errors signalled by the contract or by the VM, as opposed to Rust panics, such as failed assertions.
+fn is_contract_error(panic_any: &(dyn std::any::Any + Send)) -> bool {
+    panic_any.is::<TxPanic>() || panic_any.is::<TxResult>()
+}
+
+fn transfer_tx_payments(tx_context: &TxContext) {
+    let tx_input = tx_context.input_ref();
+    let tx_cache = tx_context.blockchain_cache();
+    let rust_zero = rust_biguint!(0);
+
+    if tx_input.moax_value > rust_zero {
+        tx_cache.subtract_moax_balance(&tx_input.from, &tx_input.moax_value);
+        tx_cache.increase_moax_balance(&tx_input.to, &tx_input.moax_value);
+    }
+
+    for dct in &tx_input.dct_values {
+        if dct.value > rust_zero {
+            tx_cache.transfer_dct_balance(
+                &tx_input.from,
+                &tx_input.to,
+                &dct.token_identifier,
+                dct.nonce,
+                &dct.value,
+            );
         }
     }
 }
@@ -641,16 +1228,19 @@ fn build_tx_input(
     dest: &Address,
     moax_value: &num_bigint::BigUint,
     dct_values: Vec<TxInputDCT>,
+    gas_limit: u64,
+    gas_price: u64,
 ) -> TxInput {
     TxInput {
         from: caller.clone(),
         to: dest.clone(),
         moax_value: moax_value.clone(),
+        moax_value_transferred: false,
         dct_values,
         func_name: Vec::new(),
         args: Vec::new(),
-        gas_limit: u64::MAX,
-        gas_price: 0,
+        gas_limit,
+        gas_price,
         tx_hash: H256::zero(),
     }
 }
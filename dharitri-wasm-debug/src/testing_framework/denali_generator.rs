@@ -1,10 +1,15 @@
-use std::{collections::BTreeMap, fs::File, io::Write};
+use std::{collections::BTreeMap, fs::File, io::Write, path::Path};
 
-use denali::serde_raw::{ScenarioRaw, StepRaw};
-use serde::Serialize;
+use denali::{
+    scenario_fmt::serialize_scenario_raw,
+    serde_raw::{ScenarioRaw, StepRaw},
+};
 
 use super::{raw_converter::*, ScCallDenali, ScQueryDenali, TxExpectDenali};
-use crate::world_mock::{AccountData, BlockInfo};
+use crate::world_mock::{AccountData, BlockInfo, DctTokenProperties};
+
+/// Snapshots are only supported by denali-rs, scenarios using them cannot run in the Go VM.
+const RUST_ONLY_COMMENT: &str = "uses saveSnapshot/loadSnapshot, only runs in denali-rs";
 
 pub(crate) struct DenaliGenerator {
     scenario: ScenarioRaw,
@@ -16,6 +21,7 @@ impl DenaliGenerator {
         Self {
             scenario: ScenarioRaw {
                 check_gas: None,
+                trace_gas: None,
                 comment: None,
                 gas_schedule: None,
                 name: None,
@@ -26,18 +32,16 @@ impl DenaliGenerator {
     }
 
     pub fn write_denali_output(self, file_path: &str) {
-        let buf = Vec::new();
-        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
-        let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
-        self.scenario.serialize(&mut ser).unwrap();
-        let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
-        serialized.push('\n');
-
-        let mut file = File::create(file_path).unwrap();
-        file.write_all(serialized.as_bytes()).unwrap();
+        write_scenario_raw(&self.scenario, file_path);
     }
 }
 
+pub(crate) fn write_scenario_raw<P: AsRef<Path>>(scenario: &ScenarioRaw, file_path: P) {
+    let serialized = serialize_scenario_raw(scenario);
+    let mut file = File::create(file_path).unwrap();
+    file.write_all(serialized.as_bytes()).unwrap();
+}
+
 impl DenaliGenerator {
     fn add_step(&mut self, step: StepRaw) {
         self.scenario.steps.push(step);
@@ -46,7 +50,7 @@ impl DenaliGenerator {
     pub fn set_account(&mut self, acc: &AccountData, sc_denali_path_expr: Option<Vec<u8>>) {
         let mut accounts_raw = BTreeMap::new();
 
-        let addr_as_str = bytes_to_hex(acc.address.as_bytes());
+        let addr_as_str = address_to_expr(&acc.address);
         let mut acc_clone = acc.clone();
         acc_clone.contract_path = sc_denali_path_expr;
 
@@ -57,6 +61,23 @@ impl DenaliGenerator {
             accounts: accounts_raw,
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
+            token_properties: BTreeMap::new(),
+            comment: None,
+            current_block_info: None,
+            previous_block_info: None,
+        };
+        self.add_step(step);
+    }
+
+    pub fn set_token_properties(&mut self, token_id: &[u8], properties: &DctTokenProperties) {
+        let mut token_properties_raw = BTreeMap::new();
+        token_properties_raw.insert(bytes_to_expr(token_id), token_properties_as_raw(properties));
+
+        let step = StepRaw::SetState {
+            accounts: BTreeMap::new(),
+            block_hashes: Vec::new(),
+            new_addresses: Vec::new(),
+            token_properties: token_properties_raw,
             comment: None,
             current_block_info: None,
             previous_block_info: None,
@@ -72,6 +93,7 @@ impl DenaliGenerator {
             accounts: BTreeMap::new(),
             block_hashes: Vec::new(),
             new_addresses: Vec::new(),
+            token_properties: BTreeMap::new(),
             comment: None,
             current_block_info: Some(current_raw),
             previous_block_info: Some(prev_raw),
@@ -114,6 +136,28 @@ impl DenaliGenerator {
         self.add_step(step);
     }
 
+    fn mark_rust_only(&mut self) {
+        self.scenario.comment = Some(RUST_ONLY_COMMENT.to_string());
+    }
+
+    pub fn save_snapshot(&mut self, name: &str) {
+        self.mark_rust_only();
+        let step = StepRaw::SaveSnapshot {
+            comment: None,
+            name: name.to_string(),
+        };
+        self.add_step(step);
+    }
+
+    pub fn load_snapshot(&mut self, name: &str) {
+        self.mark_rust_only();
+        let step = StepRaw::LoadSnapshot {
+            comment: None,
+            name: name.to_string(),
+        };
+        self.add_step(step);
+    }
+
     pub fn check_account(&mut self, acc: &AccountData) {
         let check_raw = account_as_check_state_raw(acc);
 
@@ -0,0 +1,76 @@
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::crypto::{
+    bls_public_key, bls_secret_key_from_seed, ed25519_public_key, secp256k1_public_key,
+};
+
+const SEED_LEN: usize = 32;
+
+pub struct KeyPair {
+    pub secret_key: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+/// Generates deterministic keypairs, so that signatures are the same on every test run.
+#[derive(Clone)]
+pub(crate) struct KeyFactory {
+    last_generated_seed: [u8; SEED_LEN],
+}
+
+impl Default for KeyFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyFactory {
+    pub fn new() -> Self {
+        Self {
+            last_generated_seed: [0u8; SEED_LEN],
+        }
+    }
+
+    pub fn new_ed25519_keypair(&mut self) -> KeyPair {
+        let secret_key = self.new_seed().to_vec();
+        let public_key = ed25519_public_key(&secret_key).unwrap();
+
+        KeyPair {
+            secret_key,
+            public_key,
+        }
+    }
+
+    pub fn new_secp256k1_keypair(&mut self) -> KeyPair {
+        loop {
+            let secret_key = self.new_seed().to_vec();
+            if let Some(public_key) = secp256k1_public_key(&secret_key) {
+                return KeyPair {
+                    secret_key,
+                    public_key,
+                };
+            }
+        }
+    }
+
+    pub fn new_bls_keypair(&mut self) -> KeyPair {
+        let mut wide_seed = [0u8; 64];
+        wide_seed.copy_from_slice(&Sha512::digest(&self.new_seed()));
+        let secret_key = bls_secret_key_from_seed(&wide_seed);
+        let public_key = bls_public_key(&secret_key).unwrap();
+
+        KeyPair {
+            secret_key,
+            public_key,
+        }
+    }
+
+    fn new_seed(&mut self) -> [u8; SEED_LEN] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.last_generated_seed);
+        let result: [u8; SEED_LEN] = hasher.finalize().into();
+
+        self.last_generated_seed = result;
+
+        result
+    }
+}
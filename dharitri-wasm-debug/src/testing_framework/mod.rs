@@ -1,6 +1,7 @@
 mod address_factory;
 mod contract_obj_wrapper;
 mod helper_macros;
+mod key_factory;
 mod denali_generator;
 mod raw_converter;
 mod tx_denali;
@@ -8,6 +9,7 @@ mod tx_denali;
 use address_factory::*;
 pub use contract_obj_wrapper::*;
 pub use helper_macros::*;
-use denali_generator::*;
-use raw_converter::*;
+pub use key_factory::*;
+pub(crate) use denali_generator::*;
+pub(crate) use raw_converter::*;
 pub use tx_denali::*;
@@ -1,13 +1,18 @@
 use std::collections::BTreeMap;
 
-use crate::world_mock::{AccountData, BlockInfo, DctData};
-use dharitri_wasm::types::Address;
-use denali::serde_raw::{
-    AccountRaw, BlockInfoRaw, CheckAccountRaw, CheckAccountsRaw, CheckBytesValueRaw,
-    CheckDctDataRaw, CheckDctInstanceRaw, CheckDctInstancesRaw, CheckDctMapContentsRaw,
-    CheckDctMapRaw, CheckDctRaw, CheckLogsRaw, CheckStorageDetailsRaw, CheckStorageRaw,
-    DctFullRaw, DctRaw, InstanceRaw, TxCallRaw, TxDCTRaw, TxExpectRaw, TxQueryRaw, ValueSubTree,
+use crate::world_mock::{AccountData, BlockInfo, DctData, DctTokenProperties};
+use denali::{
+    serde_raw::{
+        AccountRaw, BlockInfoRaw, CheckAccountRaw, CheckAccountsRaw, CheckBytesValueRaw,
+        CheckDctDataRaw, CheckDctInstanceRaw, CheckDctInstancesRaw, CheckDctMapContentsRaw,
+        CheckDctMapRaw, CheckDctRaw, CheckLogsRaw, CheckStorageDetailsRaw, CheckStorageRaw,
+        CheckValueListRaw, DctFullRaw, DctRaw, InstanceRaw, TokenPropertiesRaw, TxCallRaw,
+        TxDCTRaw, TxExpectRaw, TxQueryRaw, ValueSubTree,
+    },
+    value_interpreter::{reconstruct, ExprReconstructorHint},
 };
+use dharitri_wasm::types::Address;
+use num_traits::Zero;
 
 use super::{ScCallDenali, ScQueryDenali, TxExpectDenali};
 
@@ -22,7 +27,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
 
     let mut all_dct_raw = BTreeMap::new();
     for (token_id, dct_data) in acc.dct.iter() {
-        let token_id_raw = String::from_utf8(token_id.clone()).unwrap();
+        let token_id_raw = bytes_to_expr(token_id);
         let dct_raw = dct_data_as_raw(dct_data);
 
         let _ = all_dct_raw.insert(token_id_raw, dct_raw);
@@ -30,7 +35,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
 
     let mut storage_raw = BTreeMap::new();
     for (key, value) in acc.storage.iter() {
-        let key_raw = bytes_to_denali_string_or_hex(key);
+        let key_raw = bytes_to_expr(key);
         let value_raw = bytes_as_raw(value);
 
         let _ = storage_raw.insert(key_raw, value_raw);
@@ -39,12 +44,28 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
     AccountRaw {
         balance: balance_raw,
         code: code_raw,
+        code_metadata: acc
+            .contract_path
+            .as_ref()
+            .map(|_| ValueSubTree::Str(bytes_to_hex(&acc.code_metadata.into_bytes()))),
         comment: None,
+        developer_rewards: if acc.developer_rewards.is_zero() {
+            None
+        } else {
+            Some(rust_biguint_as_raw(&acc.developer_rewards))
+        },
         dct: all_dct_raw,
         nonce: Some(u64_as_raw(acc.nonce)),
         owner: acc.contract_owner.as_ref().map(address_as_raw),
         storage: storage_raw,
-        username: None, // TODO: Add if needed
+        username: if acc.username.is_empty() {
+            None
+        } else {
+            Some(ValueSubTree::Str(reconstruct(
+                &acc.username,
+                ExprReconstructorHint::Str,
+            )))
+        },
     }
 }
 
@@ -70,14 +91,23 @@ pub(crate) fn dct_data_as_raw(dct: &DctData) -> DctRaw {
             hash: inst.metadata.hash.as_ref().map(|h| bytes_as_raw(h)),
             nonce: Some(u64_as_raw(inst.nonce)),
             royalties: Some(u64_as_raw(inst.metadata.royalties)),
-            uri: inst.metadata.uri.as_ref().map(|u| bytes_as_raw(u)),
+            uri: inst
+                .metadata
+                .uris
+                .iter()
+                .map(|uri| bytes_as_raw(uri))
+                .collect(),
         };
 
         instances_raw.push(inst_raw);
     }
 
     DctRaw::Full(DctFullRaw {
-        frozen: None,
+        frozen: if dct.frozen {
+            Some(u64_as_raw(1))
+        } else {
+            None
+        },
         instances: instances_raw,
         last_nonce: last_nonce_raw,
         roles: roles_raw,
@@ -85,6 +115,16 @@ pub(crate) fn dct_data_as_raw(dct: &DctData) -> DctRaw {
     })
 }
 
+pub(crate) fn token_properties_as_raw(properties: &DctTokenProperties) -> TokenPropertiesRaw {
+    let bool_as_raw = |value: bool| if value { Some(u64_as_raw(1)) } else { None };
+    TokenPropertiesRaw {
+        paused: bool_as_raw(properties.paused),
+        can_freeze: bool_as_raw(properties.can_freeze),
+        can_wipe: bool_as_raw(properties.can_wipe),
+        limited_transfer: bool_as_raw(properties.limited_transfer),
+    }
+}
+
 pub(crate) fn block_info_as_raw(block_info: &BlockInfo) -> BlockInfoRaw {
     BlockInfoRaw {
         block_epoch: Some(u64_as_raw(block_info.block_epoch)),
@@ -190,7 +230,13 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
                     .clone()
                     .unwrap_or_else(|| ValueSubTree::Str("0".to_owned())),
                 royalties: opt_raw_value_to_check_raw(&inst_raw.royalties),
-                uri: opt_raw_value_to_check_raw(&inst_raw.uri),
+                uri: CheckValueListRaw::CheckList(
+                    inst_raw
+                        .uri
+                        .iter()
+                        .map(|uri| CheckBytesValueRaw::Equal(uri.clone()))
+                        .collect(),
+                ),
             };
 
             dct_instances_check_raw.push(inst_check_raw);
@@ -215,7 +261,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
 
     let mut raw_storage = BTreeMap::new();
     for (key, value) in acc.storage.iter() {
-        let key_as_str = bytes_to_denali_string_or_hex(key);
+        let key_as_str = bytes_to_expr(key);
         let check_val_raw = CheckBytesValueRaw::Equal(bytes_as_raw(value));
 
         raw_storage.insert(key_as_str, check_val_raw);
@@ -235,16 +281,15 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
         owner: CheckBytesValueRaw::Star, // TODO: Add owner check?
         storage: CheckStorageRaw::Equal(check_storage_raw),
         code: CheckBytesValueRaw::Star,
+        code_metadata: CheckBytesValueRaw::Unspecified,
+        developer_rewards: CheckBytesValueRaw::Unspecified,
         async_call_data: CheckBytesValueRaw::Unspecified,
         comment: None,
         username: CheckBytesValueRaw::Unspecified,
     };
 
     let mut all_accounts_check_raw = BTreeMap::new();
-    all_accounts_check_raw.insert(
-        bytes_to_hex(acc.address.as_bytes()),
-        Box::new(check_acc_raw),
-    );
+    all_accounts_check_raw.insert(address_to_expr(&acc.address), Box::new(check_acc_raw));
 
     CheckAccountsRaw {
         other_accounts_allowed: true, // so we only check the current account
@@ -259,12 +304,9 @@ pub(crate) fn opt_raw_value_to_check_raw(raw_value: &Option<ValueSubTree>) -> Ch
     }
 }
 
-pub(crate) fn bytes_to_denali_string_or_hex(bytes: &[u8]) -> String {
-    let conversion_result = String::from_utf8(bytes.to_vec());
-    match conversion_result {
-        core::result::Result::Ok(bytes_as_str) => format!("str:{}", bytes_as_str),
-        core::result::Result::Err(_) => bytes_to_hex(bytes),
-    }
+/// Renders the bytes as the most readable denali expression that interprets back to them.
+pub(crate) fn bytes_to_expr(bytes: &[u8]) -> String {
+    reconstruct(bytes, ExprReconstructorHint::NoHint)
 }
 
 pub(crate) fn rust_biguint_as_raw(big_uint: &num_bigint::BigUint) -> ValueSubTree {
@@ -272,7 +314,12 @@ pub(crate) fn rust_biguint_as_raw(big_uint: &num_bigint::BigUint) -> ValueSubTre
 }
 
 pub(crate) fn address_as_raw(address: &Address) -> ValueSubTree {
-    bytes_as_raw(address.as_bytes())
+    ValueSubTree::Str(address_to_expr(address))
+}
+
+/// Addresses are written as `address:`/`sc:` expressions when possible, otherwise in bech32.
+pub(crate) fn address_to_expr(address: &Address) -> String {
+    reconstruct(address.as_bytes(), ExprReconstructorHint::Address)
 }
 
 pub(crate) fn u64_as_raw(value: u64) -> ValueSubTree {
@@ -280,7 +327,7 @@ pub(crate) fn u64_as_raw(value: u64) -> ValueSubTree {
 }
 
 pub(crate) fn bytes_as_raw(bytes: &[u8]) -> ValueSubTree {
-    ValueSubTree::Str(bytes_to_hex(bytes))
+    ValueSubTree::Str(bytes_to_expr(bytes))
 }
 
 pub(crate) fn bytes_to_hex(bytes: &[u8]) -> String {
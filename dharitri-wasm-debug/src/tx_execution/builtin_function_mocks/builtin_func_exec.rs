@@ -1,22 +1,35 @@
 use crate::{
-    tx_execution::default_execution,
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_execution::{default_execution, execute_dct_system_sc},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    world_mock::{is_dct_system_sc_address, GasSchedule, BUILT_IN_COST},
 };
 
 use super::{
-    change_owner_mock::execute_change_owner, dct_local_burn::execute_local_burn,
-    dct_local_mint::execute_local_mint, dct_multi_transfer_mock::execute_dct_multi_transfer,
-    dct_nft_add_quantity_mock::execute_nft_add_quantity, dct_nft_burn_mock::execute_nft_burn,
+    change_owner_mock::execute_change_owner,
+    claim_developer_rewards_mock::execute_claim_developer_rewards,
+    dct_freeze_mock::{execute_dct_freeze, execute_dct_unfreeze},
+    dct_local_burn::execute_local_burn,
+    dct_local_mint::execute_local_mint,
+    dct_multi_transfer_mock::execute_dct_multi_transfer,
+    dct_nft_add_quantity_mock::execute_nft_add_quantity,
+    dct_nft_add_uri_mock::execute_nft_add_uri,
+    dct_nft_burn_mock::execute_nft_burn,
     dct_nft_create_mock::execute_dct_nft_create,
-    dct_nft_transfer_mock::execute_dct_nft_transfer, dct_transfer_mock::execute_dct_transfer,
-    set_username_mock::execute_set_username, upgrade_contract::execute_upgrade_contract,
+    dct_nft_transfer_mock::execute_dct_nft_transfer,
+    dct_nft_update_attributes_mock::execute_nft_update_attributes,
+    dct_transfer_mock::execute_dct_transfer,
+    dct_wipe_mock::execute_dct_wipe,
+    set_username_mock::execute_set_username,
+    upgrade_contract::execute_upgrade_contract,
 };
 
 use dharitri_wasm::api::{
-    CHANGE_OWNER_BUILTIN_FUNC_NAME, DCT_LOCAL_BURN_FUNC_NAME, DCT_LOCAL_MINT_FUNC_NAME,
-    DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_BURN_FUNC_NAME,
-    DCT_NFT_CREATE_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME,
-    SET_USERNAME_FUNC_NAME, UPGRADE_CONTRACT_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME, DCT_FREEZE_FUNC_NAME,
+    DCT_LOCAL_BURN_FUNC_NAME, DCT_LOCAL_MINT_FUNC_NAME, DCT_MULTI_TRANSFER_FUNC_NAME,
+    DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_ADD_URI_FUNC_NAME, DCT_NFT_BURN_FUNC_NAME,
+    DCT_NFT_CREATE_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+    DCT_TRANSFER_FUNC_NAME, DCT_UNFREEZE_FUNC_NAME, DCT_WIPE_FUNC_NAME, SET_USERNAME_FUNC_NAME,
+    UPGRADE_CONTRACT_FUNC_NAME,
 };
 
 const DCT_ROLE_LOCAL_MINT: &[u8] = b"DCTRoleLocalMint";
@@ -24,12 +37,24 @@ const DCT_ROLE_LOCAL_BURN: &[u8] = b"DCTRoleLocalBurn";
 const DCT_ROLE_NFT_CREATE: &[u8] = b"DCTRoleNFTCreate";
 const DCT_ROLE_NFT_ADD_QUANTITY: &[u8] = b"DCTRoleNFTAddQuantity";
 const DCT_ROLE_NFT_BURN: &[u8] = b"DCTRoleNFTBurn";
+const DCT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"DCTRoleNFTUpdateAttributes";
+const DCT_ROLE_NFT_ADD_URI: &[u8] = b"DCTRoleNFTAddURI";
 
 pub fn execute_builtin_function_or_default(
     tx_input: TxInput,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate) {
-    match tx_input.func_name.as_slice() {
+    if is_dct_system_sc_address(&tx_input.to) {
+        return execute_dct_system_sc(tx_input, tx_cache);
+    }
+
+    let gas_limit = tx_input.gas_limit;
+    let builtin_gas_cost = builtin_function_gas_cost(
+        tx_input.func_name.as_slice(),
+        &tx_cache.blockchain_ref().gas_schedule,
+    );
+
+    let builtin_result = match tx_input.func_name.as_slice() {
         DCT_LOCAL_MINT_FUNC_NAME => check_and_execute_builtin_function(
             DCT_ROLE_LOCAL_MINT,
             tx_input,
@@ -62,15 +87,81 @@ pub fn execute_builtin_function_or_default(
             tx_cache,
             &execute_nft_burn,
         ),
+        DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME => check_and_execute_builtin_function(
+            DCT_ROLE_NFT_UPDATE_ATTRIBUTES,
+            tx_input,
+            tx_cache,
+            &execute_nft_update_attributes,
+        ),
+        DCT_NFT_ADD_URI_FUNC_NAME => check_and_execute_builtin_function(
+            DCT_ROLE_NFT_ADD_URI,
+            tx_input,
+            tx_cache,
+            &execute_nft_add_uri,
+        ),
+        DCT_FREEZE_FUNC_NAME => execute_dct_freeze(tx_input, tx_cache),
+        DCT_UNFREEZE_FUNC_NAME => execute_dct_unfreeze(tx_input, tx_cache),
+        DCT_WIPE_FUNC_NAME => execute_dct_wipe(tx_input, tx_cache),
 
         DCT_TRANSFER_FUNC_NAME => execute_dct_transfer(tx_input, tx_cache),
         CHANGE_OWNER_BUILTIN_FUNC_NAME => execute_change_owner(tx_input, tx_cache),
+        CLAIM_DEVELOPER_REWARDS_FUNC_NAME => execute_claim_developer_rewards(tx_input, tx_cache),
         SET_USERNAME_FUNC_NAME => execute_set_username(tx_input, tx_cache),
         UPGRADE_CONTRACT_FUNC_NAME => execute_upgrade_contract(tx_input, tx_cache),
-        _ => default_execution(tx_input, tx_cache),
+        _ => return default_execution(tx_input, tx_cache),
+    };
+
+    charge_builtin_function_gas(builtin_result, builtin_gas_cost, gas_limit)
+}
+
+/// The cost of a builtin function call, 0 if the function is not a builtin function.
+pub fn builtin_function_gas_cost(func_name: &[u8], gas_schedule: &GasSchedule) -> u64 {
+    match func_name {
+        DCT_LOCAL_MINT_FUNC_NAME
+        | DCT_LOCAL_BURN_FUNC_NAME
+        | DCT_MULTI_TRANSFER_FUNC_NAME
+        | DCT_NFT_TRANSFER_FUNC_NAME
+        | DCT_NFT_CREATE_FUNC_NAME
+        | DCT_NFT_ADD_QUANTITY_FUNC_NAME
+        | DCT_NFT_BURN_FUNC_NAME
+        | DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME
+        | DCT_NFT_ADD_URI_FUNC_NAME
+        | DCT_FREEZE_FUNC_NAME
+        | DCT_UNFREEZE_FUNC_NAME
+        | DCT_WIPE_FUNC_NAME
+        | DCT_TRANSFER_FUNC_NAME
+        | CHANGE_OWNER_BUILTIN_FUNC_NAME
+        | CLAIM_DEVELOPER_REWARDS_FUNC_NAME
+        | SET_USERNAME_FUNC_NAME
+        | UPGRADE_CONTRACT_FUNC_NAME => {
+            gas_schedule.get_cost(BUILT_IN_COST, String::from_utf8_lossy(func_name).as_ref())
+        },
+        _ => 0,
     }
 }
 
+/// Adds the cost of the builtin function itself on top of any contract execution it triggered.
+fn charge_builtin_function_gas(
+    (mut tx_result, blockchain_updates): (TxResult, BlockchainUpdate),
+    builtin_gas_cost: u64,
+    gas_limit: u64,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_result.result_status != 0 {
+        tx_result.gas_used = gas_limit;
+        return (tx_result, blockchain_updates);
+    }
+
+    let gas_used = tx_result.gas_used.saturating_add(builtin_gas_cost);
+    if gas_used > gas_limit {
+        let mut out_of_gas_result = TxResult::from_panic_obj(&TxPanic::out_of_gas());
+        out_of_gas_result.gas_used = gas_limit;
+        return (out_of_gas_result, BlockchainUpdate::empty());
+    }
+
+    tx_result.gas_used = gas_used;
+    (tx_result, blockchain_updates)
+}
+
 fn check_and_execute_builtin_function(
     role_name: &[u8],
     tx_input: TxInput,
@@ -0,0 +1,32 @@
+use num_traits::Zero;
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+pub fn execute_claim_developer_rewards(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if !tx_input.args.is_empty() {
+        return (
+            TxResult::from_vm_error("ClaimDeveloperRewards expects no arguments".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let is_owner = tx_cache.with_account(&tx_input.to, |account| {
+        account.contract_owner.as_ref() == Some(&tx_input.from)
+    });
+    if !is_owner {
+        return (
+            TxResult::from_vm_error("operation in account not permitted".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let developer_rewards = tx_cache.with_account_mut(&tx_input.to, |account| {
+        std::mem::replace(&mut account.developer_rewards, Zero::zero())
+    });
+    tx_cache.increase_moax_balance(&tx_input.from, &developer_rewards);
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
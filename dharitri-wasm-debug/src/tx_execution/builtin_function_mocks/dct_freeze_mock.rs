@@ -0,0 +1,101 @@
+use dharitri_wasm::{
+    api::{DCT_FREEZE_FUNC_NAME, DCT_UNFREEZE_FUNC_NAME},
+    dharitri_codec::TopDecode,
+};
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult, TxResultCalls},
+    world_mock::{is_dct_system_sc_address, DctTokenProperties},
+};
+
+pub fn execute_dct_freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    execute_dct_set_frozen(DCT_FREEZE_FUNC_NAME, true, tx_input, tx_cache)
+}
+
+pub fn execute_dct_unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    execute_dct_set_frozen(DCT_UNFREEZE_FUNC_NAME, false, tx_input, tx_cache)
+}
+
+/// Expects the token identifier and, when targeting a single NFT, its nonce.
+fn execute_dct_set_frozen(
+    func_name: &[u8],
+    frozen: bool,
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Some(err_result) = check_system_sc_builtin_call(&tx_input) {
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    if !token_property_allows(&tx_cache, &token_identifier, |properties| {
+        properties.can_freeze
+    }) {
+        let err_result = TxResult::from_vm_error("cannot freeze".to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let nonce_arg = tx_input.args.get(1).cloned();
+    let updated = tx_cache.with_account_mut(&tx_input.to, |account| {
+        if let Some(nonce_bytes) = &nonce_arg {
+            let nonce = u64::top_decode(nonce_bytes.as_slice()).unwrap();
+            account
+                .dct
+                .set_instance_frozen(token_identifier.as_slice(), nonce, frozen)
+        } else {
+            account.dct.set_frozen(token_identifier.as_slice(), frozen);
+            true
+        }
+    });
+    if !updated {
+        let err_result = TxResult::from_vm_error("new NFT data on sender".to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let mut topics = vec![token_identifier];
+    topics.extend(nonce_arg);
+    let dct_freeze_log = TxLog {
+        address: tx_input.to,
+        endpoint: func_name.to_vec(),
+        topics,
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_status: 0,
+        result_message: String::new(),
+        result_values: Vec::new(),
+        result_logs: vec![dct_freeze_log],
+        result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        print_messages: Vec::new(),
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+/// Freeze, unfreeze and wipe can only be triggered by the DCT system smart contract.
+pub(super) fn check_system_sc_builtin_call(tx_input: &TxInput) -> Option<TxResult> {
+    if !is_dct_system_sc_address(&tx_input.from) {
+        return Some(TxResult::from_vm_error(
+            "caller address is not the DCT system SC".to_string(),
+        ));
+    }
+    if tx_input.args.is_empty() || tx_input.args.len() > 2 {
+        return Some(TxResult::from_vm_error(
+            "invalid number of arguments".to_string(),
+        ));
+    }
+    None
+}
+
+/// Tokens without properties in the mock world are not restricted.
+pub(super) fn token_property_allows(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    property: fn(&DctTokenProperties) -> bool,
+) -> bool {
+    tx_cache
+        .get_dct_token_properties(token_identifier)
+        .map_or(true, |properties| property(&properties))
+}
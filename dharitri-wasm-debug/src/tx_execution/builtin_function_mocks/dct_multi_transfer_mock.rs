@@ -75,6 +75,7 @@ pub fn execute_dct_multi_transfer(
         from: tx_input.from,
         to: destination,
         moax_value: BigUint::zero(),
+        moax_value_transferred: false,
         dct_values,
         func_name,
         args,
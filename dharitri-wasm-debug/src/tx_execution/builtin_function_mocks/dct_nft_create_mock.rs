@@ -9,6 +9,9 @@ use crate::{
     world_mock::{DctInstance, DctInstanceMetadata},
 };
 
+/// 100%, expressed in hundredths of a percent, same as on the real network.
+const MAX_ROYALTIES: u64 = 10_000;
+
 pub fn execute_dct_nft_create(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -28,7 +31,15 @@ pub fn execute_dct_nft_create(
     let royalties = u64::top_decode(tx_input.args[3].as_slice()).unwrap();
     let hash = tx_input.args[4].clone();
     let attributes = tx_input.args[5].clone();
-    let uri = tx_input.args[6].clone();
+    let uris = tx_input.args[6..].to_vec();
+
+    if royalties > MAX_ROYALTIES {
+        let err_result = TxResult::from_vm_error(
+            "invalid arguments to process built-in function, invalid max royality value"
+                .to_string(),
+        );
+        return (err_result, BlockchainUpdate::empty());
+    }
 
     let new_nonce = tx_cache.with_account_mut(&tx_input.to, |account| {
         let dct_data = account
@@ -44,9 +55,10 @@ pub fn execute_dct_nft_create(
                 creator: Some(tx_input.from.clone()),
                 royalties,
                 hash: Some(hash),
-                uri: Some(uri),
+                uris,
                 attributes,
             },
+            frozen: false,
         });
 
         dct_data.last_nonce
@@ -70,6 +82,8 @@ pub fn execute_dct_nft_create(
         result_values: vec![top_encode_to_vec_u8(&new_nonce).unwrap()],
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        print_messages: Vec::new(),
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
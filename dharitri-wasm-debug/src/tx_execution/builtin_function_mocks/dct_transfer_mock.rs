@@ -40,6 +40,7 @@ pub fn execute_dct_transfer(tx_input: TxInput, tx_cache: TxCache) -> (TxResult,
         from: tx_input.from,
         to: tx_input.to,
         moax_value: BigUint::zero(),
+        moax_value_transferred: false,
         dct_values,
         func_name,
         args,
@@ -0,0 +1,56 @@
+use dharitri_wasm::{api::DCT_WIPE_FUNC_NAME, dharitri_codec::TopDecode};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult, TxResultCalls};
+
+use super::dct_freeze_mock::{check_system_sc_builtin_call, token_property_allows};
+
+/// Expects the token identifier and, when targeting a single NFT, its nonce.
+/// Only frozen tokens can be wiped.
+pub fn execute_dct_wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if let Some(err_result) = check_system_sc_builtin_call(&tx_input) {
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    if !token_property_allows(&tx_cache, &token_identifier, |properties| {
+        properties.can_wipe
+    }) {
+        let err_result = TxResult::from_vm_error("cannot wipe".to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let nonce_arg = tx_input.args.get(1).cloned();
+    let nonce = nonce_arg
+        .as_ref()
+        .map(|nonce_bytes| u64::top_decode(nonce_bytes.as_slice()).unwrap())
+        .unwrap_or_default();
+    let wiped = tx_cache.with_account_mut(&tx_input.to, |account| {
+        account.dct.wipe(token_identifier.as_slice(), nonce)
+    });
+    if !wiped {
+        let err_result =
+            TxResult::from_vm_error("cannot wipe an account that is not frozen".to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let mut topics = vec![token_identifier];
+    topics.extend(nonce_arg);
+    let dct_wipe_log = TxLog {
+        address: tx_input.to,
+        endpoint: DCT_WIPE_FUNC_NAME.to_vec(),
+        topics,
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_status: 0,
+        result_message: String::new(),
+        result_values: Vec::new(),
+        result_logs: vec![dct_wipe_log],
+        result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        print_messages: Vec::new(),
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -1,3 +1,5 @@
+use dharitri_wasm::{dharitri_codec::TopDecode, types::CodeMetadata};
+
 use crate::{
     tx_execution::default_execution,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
@@ -14,10 +16,26 @@ pub fn execute_upgrade_contract(
         );
     }
 
-    let new_code = tx_input.args[0].clone();
+    let upgradeable = tx_cache.with_account(&tx_input.to, |account| {
+        account.code_metadata.is_upgradeable()
+    });
+    if !upgradeable {
+        return (
+            TxResult::from_vm_error("upgrade not allowed".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
 
-    // tx_input.args[1] is the code metadata, we ignore for now
-    // TODO: model code metadata in Denali
+    let new_code = tx_input.args[0].clone();
+    let code_metadata = match CodeMetadata::top_decode(tx_input.args[1].as_slice()) {
+        Ok(code_metadata) => code_metadata,
+        Err(_) => {
+            return (
+                TxResult::from_vm_error("invalid code metadata".to_string()),
+                BlockchainUpdate::empty(),
+            );
+        },
+    };
 
     let args = if tx_input.args.len() > 2 {
         tx_input.args[2..].to_vec()
@@ -27,12 +45,14 @@ pub fn execute_upgrade_contract(
 
     tx_cache.with_account_mut(&tx_input.to, |account| {
         account.contract_path = Some(new_code);
+        account.code_metadata = code_metadata;
     });
 
     let exec_input = TxInput {
         from: tx_input.from,
         to: tx_input.to,
         moax_value: tx_input.moax_value,
+        moax_value_transferred: false,
         dct_values: Vec::new(),
         func_name: b"init".to_vec(),
         args,
@@ -0,0 +1,114 @@
+use dharitri_wasm::{api::DCT_TRANSFER_FUNC_NAME, types::DctTokenType};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::tx_mock::{
+    AsyncCallTxData, BlockchainUpdate, TxCache, TxInput, TxResult, TxResultCalls,
+};
+
+use super::{
+    dct_system_sc_freeze::{
+        freeze, freeze_single_nft, unfreeze, unfreeze_single_nft, wipe, wipe_single_nft,
+    },
+    dct_system_sc_issue::issue,
+    dct_system_sc_roles::{set_special_role, unset_special_role},
+    dct_system_sc_token_info::system_sc_address,
+    dct_system_sc_token_management::{mint, pause, transfer_ownership, unpause},
+};
+
+const ISSUE_FUNGIBLE_ENDPOINT_NAME: &[u8] = b"issue";
+const ISSUE_NON_FUNGIBLE_ENDPOINT_NAME: &[u8] = b"issueNonFungible";
+const ISSUE_SEMI_FUNGIBLE_ENDPOINT_NAME: &[u8] = b"issueSemiFungible";
+const REGISTER_META_DCT_ENDPOINT_NAME: &[u8] = b"registerMetaDCT";
+const SET_SPECIAL_ROLE_ENDPOINT_NAME: &[u8] = b"setSpecialRole";
+const UNSET_SPECIAL_ROLE_ENDPOINT_NAME: &[u8] = b"unSetSpecialRole";
+const TRANSFER_OWNERSHIP_ENDPOINT_NAME: &[u8] = b"transferOwnership";
+const MINT_ENDPOINT_NAME: &[u8] = b"mint";
+const PAUSE_ENDPOINT_NAME: &[u8] = b"pause";
+const UNPAUSE_ENDPOINT_NAME: &[u8] = b"unPause";
+const FREEZE_ENDPOINT_NAME: &[u8] = b"freeze";
+const UNFREEZE_ENDPOINT_NAME: &[u8] = b"unFreeze";
+const FREEZE_SINGLE_NFT_ENDPOINT_NAME: &[u8] = b"freezeSingleNFT";
+const UNFREEZE_SINGLE_NFT_ENDPOINT_NAME: &[u8] = b"unFreezeSingleNFT";
+const WIPE_ENDPOINT_NAME: &[u8] = b"wipe";
+const WIPE_SINGLE_NFT_ENDPOINT_NAME: &[u8] = b"wipeSingleNFT";
+const CALLBACK_ENDPOINT_NAME: &[u8] = b"callBack";
+
+/// Errors of the system smart contract, reported as VM errors.
+pub type SystemScResult = Result<TxResult, String>;
+
+/// Mocks the DCT system smart contract, which has a fixed address and no code.
+///
+/// Tokens sent to the caller (e.g. the initial supply of a fungible token) are sent via an async `DCTTransfer`,
+/// so when the system smart contract is called asynchronously, the callback receives them.
+/// Otherwise, the new token identifier is returned.
+/// Failed calls change nothing.
+pub fn execute_dct_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = match tx_input.func_name.as_slice() {
+        ISSUE_FUNGIBLE_ENDPOINT_NAME => issue(DctTokenType::Fungible, &tx_input, &tx_cache),
+        ISSUE_NON_FUNGIBLE_ENDPOINT_NAME => issue(DctTokenType::NonFungible, &tx_input, &tx_cache),
+        ISSUE_SEMI_FUNGIBLE_ENDPOINT_NAME => {
+            issue(DctTokenType::SemiFungible, &tx_input, &tx_cache)
+        },
+        REGISTER_META_DCT_ENDPOINT_NAME => issue(DctTokenType::Meta, &tx_input, &tx_cache),
+        SET_SPECIAL_ROLE_ENDPOINT_NAME => set_special_role(&tx_input, &tx_cache),
+        UNSET_SPECIAL_ROLE_ENDPOINT_NAME => unset_special_role(&tx_input, &tx_cache),
+        TRANSFER_OWNERSHIP_ENDPOINT_NAME => transfer_ownership(&tx_input, &tx_cache),
+        MINT_ENDPOINT_NAME => mint(&tx_input, &tx_cache),
+        PAUSE_ENDPOINT_NAME => pause(&tx_input, &tx_cache),
+        UNPAUSE_ENDPOINT_NAME => unpause(&tx_input, &tx_cache),
+        FREEZE_ENDPOINT_NAME => freeze(&tx_input, &tx_cache),
+        UNFREEZE_ENDPOINT_NAME => unfreeze(&tx_input, &tx_cache),
+        FREEZE_SINGLE_NFT_ENDPOINT_NAME => freeze_single_nft(&tx_input, &tx_cache),
+        UNFREEZE_SINGLE_NFT_ENDPOINT_NAME => unfreeze_single_nft(&tx_input, &tx_cache),
+        WIPE_ENDPOINT_NAME => wipe(&tx_input, &tx_cache),
+        WIPE_SINGLE_NFT_ENDPOINT_NAME => wipe_single_nft(&tx_input, &tx_cache),
+        // the tokens sent back by the system smart contract were received, nothing else to do
+        CALLBACK_ENDPOINT_NAME => Ok(TxResult::empty()),
+        _ => Err("invalid method to call".to_string()),
+    };
+
+    match result {
+        Ok(tx_result) => (tx_result, tx_cache.into_blockchain_updates()),
+        Err(message) => (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    }
+}
+
+pub fn require_no_payment(tx_input: &TxInput) -> Result<(), String> {
+    if !tx_input.moax_value.is_zero() || !tx_input.dct_values.is_empty() {
+        return Err("callValue must be 0".to_string());
+    }
+    Ok(())
+}
+
+/// The system smart contract first receives the tokens, then sends them to the caller.
+pub fn send_tokens_to_caller(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    amount: &BigUint,
+) -> TxResult {
+    tx_cache.increase_dct_balance(
+        &system_sc_address(),
+        token_identifier,
+        0,
+        amount,
+        Default::default(),
+    );
+
+    let mut tx_result = TxResult::empty();
+    tx_result.result_calls = TxResultCalls {
+        async_call: Some(AsyncCallTxData {
+            from: system_sc_address(),
+            to: tx_input.from.clone(),
+            call_value: BigUint::zero(),
+            endpoint_name: DCT_TRANSFER_FUNC_NAME.to_vec(),
+            arguments: vec![token_identifier.to_vec(), amount.to_bytes_be()],
+            tx_hash: tx_input.tx_hash.clone(),
+            gas_limit: tx_input.gas_limit,
+            callback_gas_lock: 0,
+        }),
+        ..TxResultCalls::empty()
+    };
+    tx_result
+}
@@ -0,0 +1,139 @@
+use dharitri_wasm::{
+    dharitri_codec::TopDecode,
+    types::{Address, DctTokenType},
+};
+
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+use super::{
+    dct_system_sc_exec::{require_no_payment, SystemScResult},
+    dct_system_sc_token_info::TokenInfo,
+    dct_system_sc_token_management::load_owned_token,
+};
+
+const CAN_FREEZE_PROPERTY: &[u8] = b"canFreeze";
+const CAN_WIPE_PROPERTY: &[u8] = b"canWipe";
+
+/// Arguments: `token identifier`, `address`.
+pub fn freeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_frozen(tx_input, tx_cache, true)
+}
+
+/// Arguments: `token identifier`, `address`.
+pub fn unfreeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_frozen(tx_input, tx_cache, false)
+}
+
+/// Arguments: `token identifier`, `nonce`, `address`.
+pub fn freeze_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_single_nft_frozen(tx_input, tx_cache, true)
+}
+
+/// Arguments: `token identifier`, `nonce`, `address`.
+pub fn unfreeze_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_single_nft_frozen(tx_input, tx_cache, false)
+}
+
+/// Arguments: `token identifier`, `address`. The address must be frozen.
+pub fn wipe(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    let (token_identifier, token_info, address) =
+        parse_freeze_args(tx_input, tx_cache, false, CAN_WIPE_PROPERTY)?;
+    if token_info.token_type != DctTokenType::Fungible {
+        return Err("wipe can be done only on fungible tokens".to_string());
+    }
+
+    wipe_balance(tx_cache, &address, &token_identifier, 0)
+}
+
+/// Arguments: `token identifier`, `nonce`, `address`. The NFT must be frozen.
+pub fn wipe_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    let (token_identifier, _, address) =
+        parse_freeze_args(tx_input, tx_cache, true, CAN_WIPE_PROPERTY)?;
+    let nonce = u64::top_decode(tx_input.args[1].as_slice()).map_err(|_| "invalid nonce")?;
+
+    wipe_balance(tx_cache, &address, &token_identifier, nonce)
+}
+
+fn set_frozen(tx_input: &TxInput, tx_cache: &TxCache, frozen: bool) -> SystemScResult {
+    let (token_identifier, token_info, address) =
+        parse_freeze_args(tx_input, tx_cache, false, CAN_FREEZE_PROPERTY)?;
+    if token_info.token_type != DctTokenType::Fungible {
+        return Err("only fungible tokens can be frozen per address".to_string());
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.dct.set_frozen(&token_identifier, frozen);
+    });
+
+    Ok(TxResult::empty())
+}
+
+fn set_single_nft_frozen(tx_input: &TxInput, tx_cache: &TxCache, frozen: bool) -> SystemScResult {
+    let (token_identifier, _, address) =
+        parse_freeze_args(tx_input, tx_cache, true, CAN_FREEZE_PROPERTY)?;
+    let nonce = u64::top_decode(tx_input.args[1].as_slice()).map_err(|_| "invalid nonce")?;
+
+    let instance_found = tx_cache.with_account_mut(&address, |account| {
+        account
+            .dct
+            .set_instance_frozen(&token_identifier, nonce, frozen)
+    });
+    if !instance_found {
+        return Err("account does not hold the NFT".to_string());
+    }
+
+    Ok(TxResult::empty())
+}
+
+fn wipe_balance(
+    tx_cache: &TxCache,
+    address: &Address,
+    token_identifier: &[u8],
+    nonce: u64,
+) -> SystemScResult {
+    let wiped =
+        tx_cache.with_account_mut(address, |account| account.dct.wipe(token_identifier, nonce));
+    if !wiped {
+        return Err("cannot wipe an account that is not frozen".to_string());
+    }
+
+    Ok(TxResult::empty())
+}
+
+/// The address is always the last argument, the single NFT variants also receive a nonce before it.
+fn parse_freeze_args(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    single_nft: bool,
+    required_property: &[u8],
+) -> Result<(Vec<u8>, TokenInfo, Address), String> {
+    require_no_payment(tx_input)?;
+    let expected_num_args = if single_nft { 3 } else { 2 };
+    if tx_input.args.len() != expected_num_args {
+        return Err("invalid number of arguments".to_string());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let token_info = load_owned_token(tx_input, tx_cache, &token_identifier)?;
+    if !token_info.has_property(required_property) {
+        return Err(if required_property == CAN_WIPE_PROPERTY {
+            "cannot wipe".to_string()
+        } else {
+            "cannot freeze".to_string()
+        });
+    }
+    if single_nft && token_info.token_type == DctTokenType::Fungible {
+        return Err("only non fungible tokens can be frozen per nonce".to_string());
+    }
+
+    let address_arg = tx_input.args.last().unwrap();
+    if address_arg.len() != Address::len_bytes() {
+        return Err("invalid address".to_string());
+    }
+    let address = Address::from_slice(address_arg.as_slice());
+    if !tx_cache.blockchain_ref().account_exists(&address) {
+        return Err("account does not exist".to_string());
+    }
+
+    Ok((token_identifier, token_info, address))
+}
@@ -0,0 +1,143 @@
+use dharitri_wasm::{
+    dharitri_codec::{top_encode_to_vec_u8, TopDecode},
+    types::DctTokenType,
+};
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+use super::{
+    dct_system_sc_exec::{send_tokens_to_caller, SystemScResult},
+    dct_system_sc_token_info::{system_sc_address, TokenInfo, TOKEN_PROPERTIES},
+};
+
+const ISSUED_TOKEN_COUNT_KEY: &[u8] = b"issuedTokenCount";
+const MAX_NUM_DECIMALS: usize = 18;
+
+/// Fungible tokens: `name`, `ticker`, `initial supply`, `number of decimals`, then the properties.
+/// Meta tokens: `name`, `ticker`, `number of decimals`, then the properties.
+/// Non-fungible and semi-fungible tokens: `name`, `ticker`, then the properties.
+/// Properties come in pairs: the property name followed by `true` or `false`.
+pub fn issue(token_type: DctTokenType, tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    let issue_cost = &tx_cache.blockchain_ref().dct_issue_cost;
+    if &tx_input.moax_value != issue_cost {
+        return Err("callValue not equals with baseIssuingCost".to_string());
+    }
+
+    let mut args = tx_input.args.iter();
+    let name = args.next().ok_or("not enough arguments")?.clone();
+    let ticker = args.next().ok_or("not enough arguments")?.clone();
+    let initial_supply = if token_type == DctTokenType::Fungible {
+        BigUint::from_bytes_be(args.next().ok_or("not enough arguments")?)
+    } else {
+        BigUint::default()
+    };
+    let num_decimals = if token_type == DctTokenType::Fungible || token_type == DctTokenType::Meta {
+        let num_decimals_arg = args.next().ok_or("not enough arguments")?;
+        usize::top_decode(num_decimals_arg.as_slice())
+            .map_err(|_| "invalid number of decimals".to_string())?
+    } else {
+        0
+    };
+    let properties = parse_properties(args.as_slice())?;
+
+    if !is_token_name_valid(&name) {
+        return Err("invalid name".to_string());
+    }
+    if !is_ticker_valid(&ticker) {
+        return Err("ticker name is not valid".to_string());
+    }
+    if num_decimals > MAX_NUM_DECIMALS {
+        return Err("invalid number of decimals".to_string());
+    }
+
+    let caller_balance =
+        tx_cache.with_account(&tx_input.from, |account| account.moax_balance.clone());
+    if &caller_balance < issue_cost {
+        return Err("failed transfer (insufficient funds)".to_string());
+    }
+    tx_cache.subtract_moax_balance(&tx_input.from, issue_cost);
+    tx_cache.increase_moax_balance(&system_sc_address(), issue_cost);
+
+    let token_identifier = new_token_identifier(tx_cache, &ticker);
+    let token_info = TokenInfo {
+        owner: tx_input.from.clone(),
+        name,
+        token_type,
+        num_decimals,
+        properties,
+    };
+    token_info.store(tx_cache, &token_identifier);
+
+    if initial_supply > BigUint::default() {
+        Ok(send_tokens_to_caller(
+            tx_input,
+            tx_cache,
+            &token_identifier,
+            &initial_supply,
+        ))
+    } else {
+        let mut tx_result = TxResult::empty();
+        tx_result.result_values = vec![token_identifier];
+        Ok(tx_result)
+    }
+}
+
+fn parse_properties(args: &[Vec<u8>]) -> Result<Vec<Vec<u8>>, String> {
+    if args.len() % 2 != 0 {
+        return Err("invalid number of arguments".to_string());
+    }
+
+    let mut properties = Vec::new();
+    for pair in args.chunks(2) {
+        if !TOKEN_PROPERTIES.contains(&pair[0].as_slice()) {
+            return Err(format!(
+                "unknown token property: {}",
+                String::from_utf8_lossy(&pair[0])
+            ));
+        }
+        match pair[1].as_slice() {
+            b"true" => properties.push(pair[0].clone()),
+            b"false" => {},
+            _ => return Err("token property value must be true or false".to_string()),
+        }
+    }
+    Ok(properties)
+}
+
+fn is_token_name_valid(name: &[u8]) -> bool {
+    (3..=20).contains(&name.len()) && name.iter().all(u8::is_ascii_alphanumeric)
+}
+
+fn is_ticker_valid(ticker: &[u8]) -> bool {
+    (3..=10).contains(&ticker.len())
+        && ticker
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Deterministic, so tests can reference the token identifiers:
+/// the random part is derived from the ticker and the number of tokens issued so far.
+fn new_token_identifier(tx_cache: &TxCache, ticker: &[u8]) -> Vec<u8> {
+    let issued_token_count = tx_cache.with_account_mut(&system_sc_address(), |account| {
+        let count_bytes = account
+            .storage
+            .get(ISSUED_TOKEN_COUNT_KEY)
+            .cloned()
+            .unwrap_or_default();
+        let count = u64::top_decode(count_bytes.as_slice()).unwrap();
+        account.storage.insert(
+            ISSUED_TOKEN_COUNT_KEY.to_vec(),
+            top_encode_to_vec_u8(&(count + 1)).unwrap(),
+        );
+        count
+    });
+
+    let mut hasher = Keccak256::new();
+    hasher.update(ticker);
+    hasher.update(issued_token_count.to_be_bytes());
+    let hash = hasher.finalize();
+
+    [ticker, b"-", hex::encode(&hash[..3]).as_bytes()].concat()
+}
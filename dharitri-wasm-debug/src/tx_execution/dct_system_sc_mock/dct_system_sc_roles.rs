@@ -0,0 +1,114 @@
+use dharitri_wasm::types::{Address, DctLocalRole, DctTokenType};
+
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+use super::{
+    dct_system_sc_exec::{require_no_payment, SystemScResult},
+    dct_system_sc_token_info::TokenInfo,
+};
+
+const CAN_ADD_SPECIAL_ROLES_PROPERTY: &[u8] = b"canAddSpecialRoles";
+
+/// Arguments: `token identifier`, `address`, then the role names.
+/// The roles are added to the ones the address already has.
+pub fn set_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    let (token_identifier, address, roles) = parse_role_args(tx_input, tx_cache)?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        let mut account_roles = account.dct.get_roles(&token_identifier);
+        for role in roles {
+            if !account_roles.contains(&role) {
+                account_roles.push(role);
+            }
+        }
+        account.dct.set_roles(token_identifier, account_roles);
+    });
+
+    Ok(TxResult::empty())
+}
+
+/// Arguments: `token identifier`, `address`, then the role names.
+pub fn unset_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    let (token_identifier, address, roles) = parse_role_args(tx_input, tx_cache)?;
+
+    tx_cache.with_account_mut(&address, |account| {
+        let account_roles = account
+            .dct
+            .get_roles(&token_identifier)
+            .into_iter()
+            .filter(|role| !roles.contains(role))
+            .collect();
+        account.dct.set_roles(token_identifier, account_roles);
+    });
+
+    Ok(TxResult::empty())
+}
+
+fn parse_role_args(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<(Vec<u8>, Address, Vec<Vec<u8>>), String> {
+    require_no_payment(tx_input)?;
+    if tx_input.args.len() < 3 {
+        return Err("not enough arguments".to_string());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let token_info =
+        TokenInfo::load(tx_cache, &token_identifier).ok_or("no ticker with given name")?;
+    if token_info.owner != tx_input.from {
+        return Err("can be called by owner only".to_string());
+    }
+    if !token_info.has_property(CAN_ADD_SPECIAL_ROLES_PROPERTY) {
+        return Err("cannot add special roles".to_string());
+    }
+
+    if tx_input.args[1].len() != Address::len_bytes() {
+        return Err("invalid address".to_string());
+    }
+    let address = Address::from_slice(tx_input.args[1].as_slice());
+    if !tx_cache.blockchain_ref().account_exists(&address) {
+        return Err("account does not exist".to_string());
+    }
+
+    let roles = tx_input.args[2..].to_vec();
+    for role in roles.iter() {
+        if !is_role_allowed(&token_info.token_type, role) {
+            return Err(format!(
+                "invalid role for token type: {}",
+                String::from_utf8_lossy(role)
+            ));
+        }
+    }
+
+    Ok((token_identifier, address, roles))
+}
+
+fn is_role_allowed(token_type: &DctTokenType, role_name: &[u8]) -> bool {
+    let allowed_roles: &[DctLocalRole] = match token_type {
+        DctTokenType::Fungible => &[
+            DctLocalRole::Mint,
+            DctLocalRole::Burn,
+            DctLocalRole::Transfer,
+        ],
+        DctTokenType::NonFungible => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftUpdateAttributes,
+            DctLocalRole::NftAddUri,
+            DctLocalRole::Transfer,
+        ],
+        DctTokenType::SemiFungible | DctTokenType::Meta => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftAddQuantity,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftUpdateAttributes,
+            DctLocalRole::NftAddUri,
+            DctLocalRole::Transfer,
+        ],
+        DctTokenType::Invalid => &[],
+    };
+    allowed_roles
+        .iter()
+        .any(|role| role.as_role_name() == role_name)
+}
@@ -0,0 +1,120 @@
+use dharitri_wasm::{
+    dct::DCT_SYSTEM_SC_ADDRESS_ARRAY,
+    dharitri_codec::{top_encode_to_vec_u8, TopDecode},
+    types::{Address, DctTokenType},
+};
+
+use crate::{tx_mock::TxCache, world_mock::dct_system_sc_token_field_key};
+
+const OWNER_FIELD: &[u8] = b"owner";
+const NAME_FIELD: &[u8] = b"name";
+const TYPE_FIELD: &[u8] = b"type";
+const NUM_DECIMALS_FIELD: &[u8] = b"numDecimals";
+
+const TRUE_BYTES: &[u8] = b"true";
+const FALSE_BYTES: &[u8] = b"false";
+
+/// The properties that can be set when issuing a token.
+pub const TOKEN_PROPERTIES: [&[u8]; 8] = [
+    b"canFreeze",
+    b"canWipe",
+    b"canPause",
+    b"canMint",
+    b"canBurn",
+    b"canChangeOwner",
+    b"canUpgrade",
+    b"canAddSpecialRoles",
+];
+
+/// What the system smart contract knows about an issued token.
+///
+/// It is kept in the storage of the system smart contract, one key per field,
+/// of the form `<token identifier>.<field name>`, so it can be inspected in denali.
+#[derive(Clone, Debug)]
+pub struct TokenInfo {
+    pub owner: Address,
+    pub name: Vec<u8>,
+    pub token_type: DctTokenType,
+    pub num_decimals: usize,
+    /// Properties that are not set are false.
+    pub properties: Vec<Vec<u8>>,
+}
+
+impl TokenInfo {
+    pub fn has_property(&self, property_name: &[u8]) -> bool {
+        self.properties
+            .iter()
+            .any(|property| property.as_slice() == property_name)
+    }
+
+    pub fn load(tx_cache: &TxCache, token_identifier: &[u8]) -> Option<Self> {
+        let owner_bytes = load_field(tx_cache, token_identifier, OWNER_FIELD);
+        if owner_bytes.is_empty() {
+            return None;
+        }
+
+        let num_decimals_bytes = load_field(tx_cache, token_identifier, NUM_DECIMALS_FIELD);
+        let properties = TOKEN_PROPERTIES
+            .iter()
+            .filter(|property| load_field(tx_cache, token_identifier, property) == TRUE_BYTES)
+            .map(|property| property.to_vec())
+            .collect();
+        Some(TokenInfo {
+            owner: Address::from_slice(owner_bytes.as_slice()),
+            name: load_field(tx_cache, token_identifier, NAME_FIELD),
+            token_type: DctTokenType::from(
+                load_field(tx_cache, token_identifier, TYPE_FIELD).as_slice(),
+            ),
+            num_decimals: usize::top_decode(num_decimals_bytes.as_slice()).unwrap(),
+            properties,
+        })
+    }
+
+    pub fn store(&self, tx_cache: &TxCache, token_identifier: &[u8]) {
+        store_field(
+            tx_cache,
+            token_identifier,
+            OWNER_FIELD,
+            self.owner.as_bytes().to_vec(),
+        );
+        store_field(tx_cache, token_identifier, NAME_FIELD, self.name.clone());
+        store_field(
+            tx_cache,
+            token_identifier,
+            TYPE_FIELD,
+            self.token_type.as_type_name().to_vec(),
+        );
+        store_field(
+            tx_cache,
+            token_identifier,
+            NUM_DECIMALS_FIELD,
+            top_encode_to_vec_u8(&self.num_decimals).unwrap(),
+        );
+        for property in TOKEN_PROPERTIES.iter() {
+            let value = if self.has_property(property) {
+                TRUE_BYTES
+            } else {
+                FALSE_BYTES
+            };
+            store_field(tx_cache, token_identifier, property, value.to_vec());
+        }
+    }
+}
+
+pub fn system_sc_address() -> Address {
+    Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY)
+}
+
+fn load_field(tx_cache: &TxCache, token_identifier: &[u8], field_name: &[u8]) -> Vec<u8> {
+    let key = dct_system_sc_token_field_key(token_identifier, field_name);
+    tx_cache.with_account(&system_sc_address(), |account| {
+        account.storage.get(&key).cloned().unwrap_or_default()
+    })
+}
+
+fn store_field(tx_cache: &TxCache, token_identifier: &[u8], field_name: &[u8], value: Vec<u8>) {
+    let key = dct_system_sc_token_field_key(token_identifier, field_name);
+    tx_cache.with_account_mut(&system_sc_address(), |account| {
+        account.storage.insert(key, value);
+    });
+}
@@ -0,0 +1,119 @@
+use dharitri_wasm::types::{Address, DctTokenType};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+use super::{
+    dct_system_sc_exec::{require_no_payment, send_tokens_to_caller, SystemScResult},
+    dct_system_sc_token_info::{system_sc_address, TokenInfo},
+};
+
+const CAN_CHANGE_OWNER_PROPERTY: &[u8] = b"canChangeOwner";
+const CAN_MINT_PROPERTY: &[u8] = b"canMint";
+const CAN_PAUSE_PROPERTY: &[u8] = b"canPause";
+
+/// Arguments: `token identifier`, `new owner address`.
+pub fn transfer_ownership(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    require_no_payment(tx_input)?;
+    if tx_input.args.len() != 2 {
+        return Err("wrong number of arguments".to_string());
+    }
+
+    let token_identifier = &tx_input.args[0];
+    let mut token_info = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !token_info.has_property(CAN_CHANGE_OWNER_PROPERTY) {
+        return Err("cannot change owner of the token".to_string());
+    }
+    if tx_input.args[1].len() != Address::len_bytes() {
+        return Err("invalid address".to_string());
+    }
+
+    token_info.owner = Address::from_slice(tx_input.args[1].as_slice());
+    token_info.store(tx_cache, token_identifier);
+
+    Ok(TxResult::empty())
+}
+
+/// Arguments: `token identifier`, `amount`. The new tokens are sent to the owner.
+pub fn mint(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    require_no_payment(tx_input)?;
+    if tx_input.args.len() != 2 {
+        return Err("wrong number of arguments".to_string());
+    }
+
+    let token_identifier = &tx_input.args[0];
+    let token_info = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if token_info.token_type != DctTokenType::Fungible {
+        return Err("only fungible tokens can be minted".to_string());
+    }
+    if !token_info.has_property(CAN_MINT_PROPERTY) {
+        return Err("token is not mintable".to_string());
+    }
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    if amount.is_zero() {
+        return Err("negative or zero mint value".to_string());
+    }
+
+    Ok(send_tokens_to_caller(
+        tx_input,
+        tx_cache,
+        token_identifier,
+        &amount,
+    ))
+}
+
+/// Arguments: `token identifier`. No transfers of the token are allowed while it is paused.
+pub fn pause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_paused(tx_input, tx_cache, true)
+}
+
+/// Arguments: `token identifier`.
+pub fn unpause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_paused(tx_input, tx_cache, false)
+}
+
+/// The paused flag is part of the token properties, which the transfer checks read.
+fn set_paused(tx_input: &TxInput, tx_cache: &TxCache, paused: bool) -> SystemScResult {
+    require_no_payment(tx_input)?;
+    if tx_input.args.len() != 1 {
+        return Err("wrong number of arguments".to_string());
+    }
+
+    let token_identifier = &tx_input.args[0];
+    let token_info = load_owned_token(tx_input, tx_cache, token_identifier)?;
+    if !token_info.has_property(CAN_PAUSE_PROPERTY) {
+        return Err("cannot pause/un-pause".to_string());
+    }
+
+    let mut properties = tx_cache
+        .get_dct_token_properties(token_identifier)
+        .unwrap_or_default();
+    if properties.paused == paused {
+        return Err(if paused {
+            "cannot pause an already paused contract".to_string()
+        } else {
+            "cannot unPause an already un-paused contract".to_string()
+        });
+    }
+
+    properties.paused = paused;
+    tx_cache.with_account_mut(&system_sc_address(), |account| {
+        properties.store_in_system_sc_storage(&mut account.storage, token_identifier);
+    });
+
+    Ok(TxResult::empty())
+}
+
+pub fn load_owned_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<TokenInfo, String> {
+    let token_info =
+        TokenInfo::load(tx_cache, token_identifier).ok_or("no ticker with given name")?;
+    if token_info.owner != tx_input.from {
+        return Err("can be called by owner only".to_string());
+    }
+    Ok(token_info)
+}
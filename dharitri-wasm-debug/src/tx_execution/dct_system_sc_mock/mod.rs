@@ -0,0 +1,8 @@
+mod dct_system_sc_exec;
+mod dct_system_sc_freeze;
+mod dct_system_sc_issue;
+mod dct_system_sc_roles;
+mod dct_system_sc_token_info;
+mod dct_system_sc_token_management;
+
+pub use dct_system_sc_exec::execute_dct_system_sc;
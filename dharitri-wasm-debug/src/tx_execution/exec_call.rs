@@ -1,11 +1,19 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+};
+
+use dharitri_wasm::types::{Address, CodeMetadata};
+use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     tx_mock::{
-        async_call_tx_input, async_callback_tx_input, merge_results, TxCache, TxContext, TxInput,
-        TxResult, TxResultCalls,
+        async_call_tx_input, async_callback_tx_input, async_callback_with_payment_tx_input,
+        merge_results, AsyncCallTxData, CrossShardCall, TxCache, TxContext, TxInput, TxResult,
+        TxResultCalls,
     },
-    world_mock::{AccountData, AccountDct, BlockchainMock},
+    world_mock::{is_dct_system_sc_address, AccountData, AccountDct, BlockchainMock},
 };
 
 use super::{execute_builtin_function_or_default, execute_tx_context};
@@ -27,6 +35,14 @@ pub fn sc_call(
         state.increase_account_nonce(&tx_input.from);
     }
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+    let sender = tx_input.from.clone();
+    let contract = tx_input.to.clone();
+    let (gas_limit, gas_price) = (tx_input.gas_limit, tx_input.gas_price);
+    if is_dct_system_sc_address(&tx_input.to) {
+        Rc::get_mut(state)
+            .unwrap()
+            .create_dct_system_sc_account_if_missing();
+    }
 
     let tx_cache = TxCache::new(state.clone());
     let (tx_result, blockchain_updates) = execute_builtin_function_or_default(tx_input, tx_cache);
@@ -34,11 +50,20 @@ pub fn sc_call(
     if tx_result.result_status == 0 {
         blockchain_updates.apply(Rc::get_mut(state).unwrap());
     }
+    state.settle_tx_fee(&sender, &contract, gas_limit, gas_price, &tx_result);
 
     tx_result
 }
 
-// TODO: refactor
+/// Calls produced by a transaction, waiting to be executed after it.
+enum PendingCall {
+    Async(AsyncCallTxData),
+    TransferExecute(AsyncCallTxData),
+}
+
+/// Executes a transaction, then all calls it produced, in order: the async call, then the transfer-execute calls.
+/// Each of these calls (callbacks included) can produce calls of its own, which are processed the same way,
+/// so async chains of any depth are supported.
 pub fn sc_call_with_async_and_callback(
     tx_input: TxInput,
     state: &mut Rc<BlockchainMock>,
@@ -47,47 +72,174 @@ pub fn sc_call_with_async_and_callback(
     let contract_address = tx_input.to.clone();
     let mut tx_result = sc_call(tx_input, state, increase_nonce);
     let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
-    if tx_result.result_status == 0 {
-        if let Some(async_data) = result_calls.async_call {
-            if state.accounts.contains_key(&async_data.to) {
-                let async_input = async_call_tx_input(&async_data);
-
-                let async_result = sc_call_with_async_and_callback(async_input, state, false);
-
-                tx_result = merge_results(tx_result, async_result.clone());
-
-                let callback_input = async_callback_tx_input(&async_data, &async_result);
-                let callback_result = sc_call(callback_input, state, false);
-                assert!(
-                    tx_result.result_calls.async_call.is_none(),
-                    "successive asyncs currently not supported"
-                );
-                tx_result = merge_results(tx_result, callback_result);
-            } else {
-                let tx_cache = TxCache::new(state.clone());
-                tx_cache.subtract_moax_balance(&contract_address, &async_data.call_value);
-                tx_cache.insert_account(AccountData {
-                    address: async_data.to.clone(),
-                    nonce: 0,
-                    moax_balance: async_data.call_value,
-                    dct: AccountDct::default(),
-                    username: Vec::new(),
-                    storage: HashMap::new(),
-                    contract_path: None,
-                    contract_owner: None,
-                });
-                state.commit_tx_cache(tx_cache);
-            }
-        }
+    if tx_result.result_status != 0 {
+        return tx_result;
+    }
 
-        for te_call in result_calls.transfer_execute {
-            let te_input = async_call_tx_input(&te_call);
+    execute_result_calls(tx_result, &contract_address, result_calls, state)
+}
 
-            let te_result = sc_call(te_input, state, false);
+pub(crate) fn execute_result_calls(
+    mut tx_result: TxResult,
+    contract_address: &Address,
+    result_calls: TxResultCalls,
+    state: &mut Rc<BlockchainMock>,
+) -> TxResult {
+    let mut pending_calls = VecDeque::new();
+    if let Some(async_data) = result_calls.async_call {
+        pending_calls.push_back(PendingCall::Async(async_data));
+    }
+    pending_calls.extend(
+        result_calls
+            .transfer_execute
+            .into_iter()
+            .map(PendingCall::TransferExecute),
+    );
 
-            tx_result = merge_results(tx_result, te_result.clone());
-        }
+    while let Some(pending_call) = pending_calls.pop_front() {
+        tx_result = match pending_call {
+            PendingCall::Async(async_data) => {
+                execute_async_call_and_callback(tx_result, contract_address, async_data, state)
+            },
+            PendingCall::TransferExecute(te_call) => {
+                let (recipient, _) = te_call.recipient_and_endpoint();
+                if state.in_same_shard(contract_address, &recipient) {
+                    let te_input = async_call_tx_input(&te_call);
+                    let te_result = sc_call_with_async_and_callback(te_input, state, false);
+                    merge_results(tx_result, te_result)
+                } else {
+                    Rc::get_mut(state)
+                        .unwrap()
+                        .queue_cross_shard_call(CrossShardCall::TransferExecute(te_call));
+                    tx_result
+                }
+            },
+        };
     }
 
     tx_result
 }
+
+/// The async call is fully resolved, including its own async calls, before the callback runs.
+/// A failed async call does not change the state, but the callback is still executed, with the error,
+/// and receives the MOAX value of the async call back.
+/// Payments sent back to the caller by the async call are received by the callback.
+fn execute_async_call_and_callback(
+    tx_result: TxResult,
+    contract_address: &Address,
+    async_data: AsyncCallTxData,
+    state: &mut Rc<BlockchainMock>,
+) -> TxResult {
+    if !state.accounts.contains_key(&async_data.to) && !is_dct_system_sc_address(&async_data.to) {
+        let tx_cache = TxCache::new(state.clone());
+        tx_cache.subtract_moax_balance(contract_address, &async_data.call_value);
+        tx_cache.insert_account(AccountData {
+            address: async_data.to.clone(),
+            nonce: 0,
+            moax_balance: async_data.call_value,
+            dct: AccountDct::default(),
+            username: Vec::new(),
+            storage: HashMap::new(),
+            contract_path: None,
+            code_metadata: CodeMetadata::DEFAULT,
+            contract_owner: None,
+            developer_rewards: BigUint::zero(),
+        });
+        state.commit_tx_cache(tx_cache);
+        return tx_result;
+    }
+
+    let (callee, _) = async_data.recipient_and_endpoint();
+    if !state.in_same_shard(&async_data.from, &callee) {
+        Rc::get_mut(state)
+            .unwrap()
+            .queue_cross_shard_call(CrossShardCall::AsyncCall(async_data));
+        return tx_result;
+    }
+
+    let (async_result, callback_input) = execute_async_call(&async_data, state);
+    let tx_result = merge_results(tx_result, async_result);
+
+    let callback_result = sc_call_with_async_and_callback(callback_input, state, false);
+    merge_results(tx_result, callback_result)
+}
+
+/// Executes the async call, along with all the calls it produced.
+/// Returns its result and the input of the callback, which also carries the payments sent back to the caller.
+fn execute_async_call(
+    async_data: &AsyncCallTxData,
+    state: &mut Rc<BlockchainMock>,
+) -> (TxResult, TxInput) {
+    let (callee, _) = async_data.recipient_and_endpoint();
+    let async_input = async_call_tx_input(async_data);
+    let mut async_result = sc_call(async_input, state, false);
+    let mut async_result_calls =
+        std::mem::replace(&mut async_result.result_calls, TxResultCalls::empty());
+
+    let mut back_transfer = None;
+    let mut moax_back_transfer = BigUint::zero();
+    if async_result.result_status == 0 {
+        if let Some(callee_async_call) = &async_result_calls.async_call {
+            if callee_async_call.is_transfer_without_execution_to(&async_data.from) {
+                back_transfer = async_result_calls.async_call.take();
+            }
+        }
+        if back_transfer.is_none() {
+            // the MOAX was already sent, the callback only receives it as call value
+            moax_back_transfer = std::mem::take(&mut async_result_calls.moax_back_transfer);
+        }
+
+        async_result = execute_result_calls(async_result, &callee, async_result_calls, state);
+    } else {
+        // the value never left the caller, the callback only receives it as call value
+        moax_back_transfer = async_data.call_value.clone();
+    }
+
+    let mut callback_input = async_callback_tx_input(async_data, &async_result);
+    if let Some(back_transfer) = &back_transfer {
+        callback_input = async_callback_with_payment_tx_input(callback_input, back_transfer);
+    } else {
+        callback_input.moax_value = moax_back_transfer;
+        callback_input.moax_value_transferred = true;
+    }
+
+    (async_result, callback_input)
+}
+
+/// Executes all cross-shard calls queued so far, in order, and returns their results.
+/// Calls queued in the meantime, such as the callbacks of the async calls executed now,
+/// are left for the next round, so transactions can be interleaved with them.
+pub fn execute_cross_shard_calls(state: &mut Rc<BlockchainMock>) -> Vec<TxResult> {
+    let queued_calls = std::mem::take(&mut Rc::get_mut(state).unwrap().cross_shard_calls);
+    queued_calls
+        .into_iter()
+        .map(|call| match call {
+            CrossShardCall::AsyncCall(async_data) => {
+                let (async_result, callback_input) = execute_async_call(&async_data, state);
+                if callback_input.moax_value_transferred {
+                    // the MOAX sent back to the caller only arrives together with the callback
+                    let tx_cache = TxCache::new(state.clone());
+                    tx_cache.subtract_moax_balance(&callback_input.to, &callback_input.moax_value);
+                    state.commit_tx_cache(tx_cache);
+                }
+                Rc::get_mut(state)
+                    .unwrap()
+                    .queue_cross_shard_call(CrossShardCall::Callback(callback_input));
+                async_result
+            },
+            CrossShardCall::Callback(callback_input) => {
+                if callback_input.moax_value_transferred {
+                    // received even if the callback fails
+                    let tx_cache = TxCache::new(state.clone());
+                    tx_cache.increase_moax_balance(&callback_input.to, &callback_input.moax_value);
+                    state.commit_tx_cache(tx_cache);
+                }
+                sc_call_with_async_and_callback(callback_input, state, false)
+            },
+            CrossShardCall::TransferExecute(te_call) => {
+                let te_input = async_call_tx_input(&te_call);
+                sc_call_with_async_and_callback(te_input, state, false)
+            },
+        })
+        .collect()
+}
@@ -4,8 +4,10 @@ use alloc::boxed::Box;
 use dharitri_wasm::contract_base::CallableContract;
 
 use crate::{
-    address_hex,
+    address_bech32,
     tx_mock::{TxContext, TxContextStack, TxPanic, TxResult},
+    wasm_executor::WasmContract,
+    world_mock::BlockchainMock,
     DebugApi,
 };
 
@@ -27,13 +29,17 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
 
     let func_name = tx_context_ref.tx_input_box.func_name.as_slice();
     let contract_identifier = get_contract_identifier(&tx_context_ref);
-    let contract_map = &tx_context_rc.blockchain_ref().contract_map;
 
-    let contract_instance =
-        contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone());
+    let contract_instance = new_contract_instance(
+        tx_context_rc.blockchain_ref(),
+        contract_identifier.as_slice(),
+        tx_context_ref.clone(),
+    );
 
     TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    let mut tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    tx_result.gas_used = tx_context_rc.gas_used_for_result(&tx_result);
+    tx_result.print_messages = tx_context_rc.extract_print_messages();
 
     let tx_context_rc = TxContextStack::static_pop();
     (tx_context_rc, tx_result)
@@ -46,12 +52,27 @@ fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
             account.contract_path.clone().unwrap_or_else(|| {
                 panic!(
                     "Recipient account is not a smart contract {}",
-                    address_hex(&tx_context.tx_input_box.to)
+                    address_bech32(&tx_context.tx_input_box.to)
                 )
             })
         })
 }
 
+/// Contracts are either taken from the contract map, or interpreted from their wasm bytecode.
+fn new_contract_instance(
+    blockchain: &BlockchainMock,
+    contract_identifier: &[u8],
+    debug_api: DebugApi,
+) -> Box<dyn CallableContract<DebugApi>> {
+    if blockchain.wasm_execution {
+        Box::new(WasmContract::new(contract_identifier, debug_api))
+    } else {
+        blockchain
+            .contract_map
+            .new_contract_instance(contract_identifier, debug_api)
+    }
+}
+
 /// The actual execution and the extraction/wrapping of results.
 fn execute_contract_instance_endpoint(
     contract_instance: Box<dyn CallableContract<DebugApi>>,
@@ -70,11 +91,15 @@ fn execute_contract_instance_endpoint(
     }));
     match result {
         Ok(tx_output) => tx_output,
-        Err(panic_any) => panic_result(panic_any),
+        Err(panic_any) => interpret_panic_as_tx_result(panic_any),
     }
 }
 
-fn panic_result(panic_any: Box<dyn std::any::Any + std::marker::Send>) -> TxResult {
+/// Converts a panic caught during contract execution into a transaction result.
+/// Also used by the Rust testing framework, which runs contract code directly.
+pub fn interpret_panic_as_tx_result(
+    panic_any: Box<dyn std::any::Any + std::marker::Send>,
+) -> TxResult {
     if panic_any.downcast_ref::<TxResult>().is_some() {
         // async calls panic with the tx output directly
         // it is not a failure, simply a way to kill the execution
@@ -1,8 +1,10 @@
 use std::rc::Rc;
 
+use dharitri_wasm::types::Address;
+
 use crate::{
     tx_mock::{TxCache, TxInput, TxResult},
-    world_mock::BlockchainMock,
+    world_mock::{default_contract_code_metadata, BlockchainMock},
 };
 
 use super::deploy_contract;
@@ -17,11 +19,20 @@ pub fn sc_create(
     state.increase_account_nonce(&tx_input.from);
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
+    let sender = tx_input.from.clone();
+    let (gas_limit, gas_price) = (tx_input.gas_limit, tx_input.gas_price);
+
     let tx_cache = TxCache::new(state.clone());
-    let (tx_result, blockchain_updates, _) =
-        deploy_contract(tx_input, contract_path.to_vec(), tx_cache);
+    let (tx_result, blockchain_updates, _) = deploy_contract(
+        tx_input,
+        contract_path.to_vec(),
+        default_contract_code_metadata(),
+        tx_cache,
+    );
 
     blockchain_updates.apply(Rc::get_mut(state).unwrap());
+    // no developer fees for deploys, the new contract did not exist when the fee was paid
+    state.settle_tx_fee(&sender, &Address::zero(), gas_limit, gas_price, &tx_result);
 
     tx_result
 }
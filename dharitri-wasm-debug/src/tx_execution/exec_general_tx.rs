@@ -1,4 +1,6 @@
-use dharitri_wasm::types::Address;
+use dharitri_wasm::types::{Address, CodeMetadata};
+use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxContext, TxInput, TxResult},
@@ -10,14 +12,42 @@ use super::execute_tx_context;
 pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     let mut tx_context = TxContext::new(tx_input, tx_cache);
 
-    tx_context.tx_cache.subtract_moax_balance(
-        &tx_context.tx_input_box.from,
-        &tx_context.tx_input_box.moax_value,
-    );
-    tx_context.tx_cache.increase_moax_balance(
-        &tx_context.tx_input_box.to,
-        &tx_context.tx_input_box.moax_value,
-    );
+    // checked before any balance changes, so that the transaction fails instead of panicking
+    for dct_transfer in tx_context.tx_input_box.dct_values.iter() {
+        if let Err(message) = tx_context.tx_cache.check_dct_transfer(
+            &tx_context.tx_input_box.from,
+            &tx_context.tx_input_box.to,
+            &dct_transfer.token_identifier,
+            dct_transfer.nonce,
+        ) {
+            let err_result = TxResult::from_vm_error(message.to_string());
+            return (err_result, BlockchainUpdate::empty());
+        }
+    }
+
+    let is_direct_transfer = tx_context.tx_input_box.func_name.is_empty()
+        && (tx_context.tx_input_box.moax_value > BigUint::zero()
+            || !tx_context.tx_input_box.dct_values.is_empty());
+    if is_direct_transfer {
+        if let Err(message) = tx_context
+            .tx_cache
+            .check_payable(&tx_context.tx_input_box.from, &tx_context.tx_input_box.to)
+        {
+            let err_result = TxResult::from_vm_error(message.to_string());
+            return (err_result, BlockchainUpdate::empty());
+        }
+    }
+
+    if !tx_context.tx_input_box.moax_value_transferred {
+        tx_context.tx_cache.subtract_moax_balance(
+            &tx_context.tx_input_box.from,
+            &tx_context.tx_input_box.moax_value,
+        );
+        tx_context.tx_cache.increase_moax_balance(
+            &tx_context.tx_input_box.to,
+            &tx_context.tx_input_box.moax_value,
+        );
+    }
 
     // TODO: temporary, will convert to explicit builtin function first
     for dct_transfer in tx_context.tx_input_box.dct_values.iter() {
@@ -49,6 +79,7 @@ pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
 pub fn deploy_contract(
     mut tx_input: TxInput,
     contract_path: Vec<u8>,
+    code_metadata: CodeMetadata,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate, Address) {
     let new_address = tx_cache.get_new_address(&tx_input.from);
@@ -60,7 +91,12 @@ pub fn deploy_contract(
     tx_context
         .tx_cache
         .subtract_moax_balance(&tx_input_ref.from, &tx_input_ref.moax_value);
-    tx_context.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
+    tx_context.create_new_contract(
+        &new_address,
+        contract_path,
+        code_metadata,
+        tx_input_ref.from.clone(),
+    );
     tx_context
         .tx_cache
         .increase_moax_balance(&new_address, &tx_input_ref.moax_value);
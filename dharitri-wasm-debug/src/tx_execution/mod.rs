@@ -1,10 +1,12 @@
 mod builtin_function_mocks;
+mod dct_system_sc_mock;
 mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
 
 pub use builtin_function_mocks::*;
+pub use dct_system_sc_mock::*;
 pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
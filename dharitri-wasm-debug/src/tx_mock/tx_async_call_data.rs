@@ -1,5 +1,6 @@
 use crate::tx_mock::{TxInput, TxResult};
 use dharitri_wasm::{
+    api::{DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME},
     dharitri_codec::*,
     types::{Address, H256},
 };
@@ -16,6 +17,51 @@ pub struct AsyncCallTxData {
     pub endpoint_name: Vec<u8>,
     pub arguments: Vec<Vec<u8>>,
     pub tx_hash: H256,
+    pub gas_limit: u64,
+    /// Gas reserved for the callback, on top of whatever the async call does not use.
+    pub callback_gas_lock: u64,
+}
+
+impl AsyncCallTxData {
+    /// DCT transfers are performed via builtin functions, which take the actual recipient
+    /// and the endpoint to call there as arguments.
+    /// Returns the account that ends up being called and the endpoint, empty for plain transfers.
+    pub fn recipient_and_endpoint(&self) -> (Address, Vec<u8>) {
+        let args = &self.arguments;
+        match self.endpoint_name.as_slice() {
+            DCT_TRANSFER_FUNC_NAME => (self.to.clone(), arg_or_empty(args, 2)),
+            DCT_NFT_TRANSFER_FUNC_NAME => (
+                decode_address_arg(args, 3).unwrap_or_else(|| self.to.clone()),
+                arg_or_empty(args, 4),
+            ),
+            DCT_MULTI_TRANSFER_FUNC_NAME => {
+                let num_payments = args
+                    .get(1)
+                    .and_then(|arg| usize::top_decode(arg.as_slice()).ok())
+                    .unwrap_or_default();
+                (
+                    decode_address_arg(args, 0).unwrap_or_else(|| self.to.clone()),
+                    arg_or_empty(args, 2 + num_payments * 3),
+                )
+            },
+            _ => (self.to.clone(), self.endpoint_name.clone()),
+        }
+    }
+
+    /// Transfers tokens to the given address, without calling any endpoint.
+    pub fn is_transfer_without_execution_to(&self, address: &Address) -> bool {
+        let (recipient, endpoint_name) = self.recipient_and_endpoint();
+        &recipient == address && endpoint_name.is_empty()
+    }
+}
+
+fn arg_or_empty(args: &[Vec<u8>], index: usize) -> Vec<u8> {
+    args.get(index).cloned().unwrap_or_default()
+}
+
+fn decode_address_arg(args: &[Vec<u8>], index: usize) -> Option<Address> {
+    args.get(index)
+        .and_then(|arg| Address::top_decode(arg.as_slice()).ok())
 }
 
 pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
@@ -23,10 +69,11 @@ pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
         from: async_data.from.clone(),
         to: async_data.to.clone(),
         moax_value: async_data.call_value.clone(),
+        moax_value_transferred: false,
         dct_values: Vec::new(),
         func_name: async_data.endpoint_name.clone(),
         args: async_data.arguments.clone(),
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
     }
@@ -41,26 +88,59 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
     } else {
         args.push(async_result.result_message.clone().into_bytes());
     }
+    let (callee, _) = async_data.recipient_and_endpoint();
     TxInput {
-        from: async_data.to.clone(),
+        from: callee,
         to: async_data.from.clone(),
         moax_value: 0u32.into(),
+        moax_value_transferred: false,
         dct_values: Vec::new(),
         func_name: b"callBack".to_vec(),
         args,
-        gas_limit: 1000,
+        gas_limit: async_data.callback_gas_lock
+            + async_data.gas_limit.saturating_sub(async_result.gas_used),
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
     }
 }
 
+/// Tokens sent back by the async call are delivered together with the callback,
+/// which gets called through the same builtin transfer function.
+pub fn async_callback_with_payment_tx_input(
+    callback_input: TxInput,
+    back_transfer: &AsyncCallTxData,
+) -> TxInput {
+    let mut args = back_transfer.arguments.clone();
+    args.push(callback_input.func_name);
+    args.extend(callback_input.args);
+    TxInput {
+        from: back_transfer.from.clone(),
+        to: back_transfer.to.clone(),
+        moax_value: 0u32.into(),
+        moax_value_transferred: false,
+        dct_values: Vec::new(),
+        func_name: back_transfer.endpoint_name.clone(),
+        args,
+        gas_limit: callback_input.gas_limit,
+        gas_price: 0,
+        tx_hash: callback_input.tx_hash,
+    }
+}
+
 pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
+    // print output is kept even for failed calls, since it is only used for debugging
+    original.print_messages.append(&mut new.print_messages);
     if original.result_status == 0 {
         original.result_values.append(&mut new.result_values);
-        original.result_logs.append(&mut new.result_logs);
+        if new.result_status == 0 {
+            // the logs of failed calls are discarded, along with their state changes
+            original.result_logs.append(&mut new.result_logs);
+        }
         original.result_message = new.result_message;
+        original.gas_used += new.gas_used;
         original
     } else {
+        new.print_messages = original.print_messages;
         new
     }
 }
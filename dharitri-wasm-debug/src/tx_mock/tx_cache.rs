@@ -8,7 +8,7 @@ use std::{
 use dharitri_wasm::types::Address;
 
 use crate::{
-    address_hex,
+    address_bech32,
     world_mock::{AccountData, BlockchainMock},
 };
 
@@ -39,7 +39,7 @@ impl TxCache {
         self.source_ref.blockchain_ref()
     }
 
-    fn load_account_if_necessary(&self, address: &Address) {
+    pub(super) fn load_account_if_necessary(&self, address: &Address) {
         let mut accounts_mut = self.accounts.borrow_mut();
         if !accounts_mut.contains_key(address) {
             if let Some(blockchain_account) = self.source_ref.load_account(address) {
@@ -56,7 +56,7 @@ impl TxCache {
         let accounts = self.accounts.borrow();
         let account = accounts
             .get(address)
-            .unwrap_or_else(|| panic!("Account {} not found", address_hex(address)));
+            .unwrap_or_else(|| panic!("Account {} not found", address_bech32(address)));
         f(account)
     }
 
@@ -68,7 +68,7 @@ impl TxCache {
         let mut accounts = self.accounts.borrow_mut();
         let account = accounts
             .get_mut(address)
-            .unwrap_or_else(|| panic!("Account {} not found", address_hex(address)));
+            .unwrap_or_else(|| panic!("Account {} not found", address_bech32(address)));
         f(account)
     }
 
@@ -123,6 +123,10 @@ impl BlockchainUpdate {
     }
 
     pub fn apply(self, blockchain: &mut BlockchainMock) {
-        blockchain.accounts.extend(self.accounts.into_iter());
+        blockchain.accounts.extend(
+            self.accounts
+                .into_iter()
+                .map(|(address, account)| (address, Rc::new(account))),
+        );
     }
 }
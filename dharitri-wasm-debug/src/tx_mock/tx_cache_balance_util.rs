@@ -1,17 +1,28 @@
-use dharitri_wasm::types::Address;
+use dharitri_wasm::{
+    dct::DCT_SYSTEM_SC_ADDRESS_ARRAY,
+    types::{Address, DctLocalRole},
+};
 use num_bigint::BigUint;
 
-use crate::{tx_mock::TxPanic, world_mock::DctInstanceMetadata};
+use crate::{
+    tx_mock::TxPanic,
+    world_mock::{
+        is_dct_system_sc_address, is_smart_contract_address, DctInstanceMetadata,
+        DctTokenProperties,
+    },
+};
 
 use super::TxCache;
 
 impl TxCache {
     pub fn subtract_moax_balance(&self, address: &Address, call_value: &BigUint) {
         self.with_account_mut(address, |account| {
-            assert!(
-                &account.moax_balance >= call_value,
-                "failed transfer (insufficient funds)"
-            );
+            if &account.moax_balance < call_value {
+                std::panic::panic_any(TxPanic {
+                    status: 10,
+                    message: b"failed transfer (insufficient funds)".to_vec(),
+                });
+            }
             account.moax_balance -= call_value;
         })
     }
@@ -80,6 +91,83 @@ impl TxCache {
         });
     }
 
+    /// Same checks as the node performs before moving tokens:
+    /// the token must not be paused, neither account can be frozen,
+    /// and limited transfers require the transfer role on one of the two sides.
+    /// Reads the token properties as currently seen by the transaction,
+    /// from the storage of the DCT system smart contract.
+    pub fn get_dct_token_properties(&self, token_identifier: &[u8]) -> Option<DctTokenProperties> {
+        let system_sc_address = Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY);
+        self.load_account_if_necessary(&system_sc_address);
+        self.accounts
+            .borrow()
+            .get(&system_sc_address)
+            .and_then(|system_sc_account| {
+                DctTokenProperties::load_from_system_sc_storage(
+                    &system_sc_account.storage,
+                    token_identifier,
+                )
+            })
+    }
+
+    pub fn check_dct_transfer(
+        &self,
+        from: &Address,
+        to: &Address,
+        dct_token_identifier: &[u8],
+        nonce: u64,
+    ) -> Result<(), &'static str> {
+        let properties = self
+            .get_dct_token_properties(dct_token_identifier)
+            .unwrap_or_default();
+        if properties.paused {
+            return Err("dct token is paused");
+        }
+
+        for address in [from, to] {
+            let frozen = self.with_account(address, |account| {
+                account.dct.is_frozen(dct_token_identifier, nonce)
+            });
+            if frozen {
+                return Err("account is frozen for this dct token");
+            }
+        }
+
+        if properties.limited_transfer && !is_dct_system_sc_address(from) {
+            let transfer_role = DctLocalRole::Transfer.as_role_name().to_vec();
+            let has_transfer_role = |address: &Address| {
+                self.with_account(address, |account| {
+                    account
+                        .dct
+                        .get_roles(dct_token_identifier)
+                        .contains(&transfer_role)
+                })
+            };
+            if !has_transfer_role(from) && !has_transfer_role(to) {
+                return Err("action is not allowed");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Value sent to a contract without calling an endpoint requires the contract to be payable.
+    /// Contracts marked as payable by SC only accept such transfers from other contracts.
+    pub fn check_payable(&self, from: &Address, to: &Address) -> Result<(), &'static str> {
+        if !is_smart_contract_address(to) {
+            return Ok(());
+        }
+
+        let code_metadata = self.with_account(to, |account| account.code_metadata);
+        if code_metadata.is_payable()
+            || (code_metadata.is_payable_by_sc() && is_smart_contract_address(from))
+        {
+            Ok(())
+        } else {
+            Err("sending value to non payable contract")
+        }
+    }
+
     pub fn transfer_dct_balance(
         &self,
         from: &Address,
@@ -88,6 +176,13 @@ impl TxCache {
         nonce: u64,
         value: &BigUint,
     ) {
+        if let Err(message) = self.check_dct_transfer(from, to, dct_token_identifier, nonce) {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: message.as_bytes().to_vec(),
+            });
+        }
+
         let metadata = self.subtract_dct_balance(from, dct_token_identifier, nonce, value);
 
         self.increase_dct_balance(to, dct_token_identifier, nonce, value, metadata);
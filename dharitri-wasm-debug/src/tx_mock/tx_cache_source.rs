@@ -12,7 +12,8 @@ pub trait TxCacheSource {
 
 impl TxCacheSource for TxCache {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
-        Some(self.with_account(address, AccountData::clone))
+        self.load_account_if_necessary(address);
+        self.accounts.borrow().get(address).map(AccountData::clone)
     }
 
     fn blockchain_ref(&self) -> &BlockchainMock {
@@ -22,7 +23,9 @@ impl TxCacheSource for TxCache {
 
 impl TxCacheSource for BlockchainMock {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
-        self.accounts.get(address).map(AccountData::clone)
+        self.accounts
+            .get(address)
+            .map(|account| AccountData::clone(account))
     }
 
     fn blockchain_ref(&self) -> &BlockchainMock {
@@ -1,7 +1,7 @@
 use crate::world_mock::{AccountData, AccountDct, BlockchainMock};
 use alloc::vec::Vec;
-use core::cell::RefCell;
-use dharitri_wasm::types::Address;
+use core::cell::{Cell, RefCell};
+use dharitri_wasm::types::{Address, CodeMetadata};
 use num_bigint::BigUint;
 use num_traits::Zero;
 use std::{
@@ -10,7 +10,7 @@ use std::{
     rc::Rc,
 };
 
-use super::{BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult};
+use super::{BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxPanic, TxResult};
 
 #[derive(Debug)]
 pub struct TxContext {
@@ -18,6 +18,8 @@ pub struct TxContext {
     pub tx_cache: Rc<TxCache>,
     pub managed_types: RefCell<TxManagedTypes>,
     pub tx_result_cell: RefCell<TxResult>,
+    pub gas_used: Cell<u64>,
+    pub print_messages: RefCell<Vec<String>>,
 }
 
 impl TxContext {
@@ -27,6 +29,8 @@ impl TxContext {
             tx_cache: Rc::new(tx_cache),
             managed_types: RefCell::new(TxManagedTypes::new()),
             tx_result_cell: RefCell::new(TxResult::empty()),
+            gas_used: Cell::new(0),
+            print_messages: RefCell::new(Vec::new()),
         }
     }
 
@@ -41,13 +45,16 @@ impl TxContext {
             dct: AccountDct::default(),
             username: Vec::new(),
             contract_path: None,
+            code_metadata: CodeMetadata::DEFAULT,
             contract_owner: None,
+            developer_rewards: BigUint::zero(),
         });
         TxContext {
             tx_input_box: Box::new(TxInput {
                 from: contract_address.clone(),
                 to: contract_address,
                 moax_value: 0u32.into(),
+                moax_value_transferred: false,
                 dct_values: Vec::new(),
                 func_name: Vec::new(),
                 args: Vec::new(),
@@ -58,6 +65,8 @@ impl TxContext {
             tx_cache: Rc::new(tx_cache),
             managed_types: RefCell::new(TxManagedTypes::new()),
             tx_result_cell: RefCell::new(TxResult::empty()),
+            gas_used: Cell::new(0),
+            print_messages: RefCell::new(Vec::new()),
         }
     }
 
@@ -121,10 +130,54 @@ impl TxContext {
         self.tx_result_cell.replace(TxResult::empty())
     }
 
+    /// Records a message printed by the contract, instead of writing it to stdout.
+    pub fn push_print_message(&self, message: String) {
+        self.print_messages.borrow_mut().push(message);
+    }
+
+    pub fn extract_print_messages(&self) -> Vec<String> {
+        self.print_messages.replace(Vec::new())
+    }
+
+    pub fn gas_left(&self) -> u64 {
+        self.tx_input_box
+            .gas_limit
+            .saturating_sub(self.gas_used.get())
+    }
+
+    /// Fails the transaction with "not enough gas" if the gas limit is exceeded.
+    pub fn use_gas(&self, gas: u64) {
+        let gas_used = self.gas_used.get().saturating_add(gas);
+        if gas_used > self.tx_input_box.gas_limit {
+            self.gas_used.set(self.tx_input_box.gas_limit);
+            std::panic::panic_any(TxPanic::out_of_gas());
+        }
+        self.gas_used.set(gas_used);
+    }
+
+    /// Failed transactions consume all the gas they were given.
+    pub fn gas_used_for_result(&self, tx_result: &TxResult) -> u64 {
+        if tx_result.result_status == 0 {
+            self.gas_used.get()
+        } else {
+            self.tx_input_box.gas_limit
+        }
+    }
+
+    /// Charges the cost of an operation, as configured in the blockchain mock gas schedule.
+    pub fn use_gas_for(&self, section: &str, op_name: &str) {
+        let cost = self
+            .blockchain_ref()
+            .gas_schedule
+            .get_cost(section, op_name);
+        self.use_gas(cost);
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &Address,
         contract_path: Vec<u8>,
+        code_metadata: CodeMetadata,
         contract_owner: Address,
     ) {
         assert!(
@@ -140,7 +193,9 @@ impl TxContext {
             dct: AccountDct::default(),
             username: Vec::new(),
             contract_path: Some(contract_path),
+            code_metadata,
             contract_owner: Some(contract_owner),
+            developer_rewards: BigUint::zero(),
         });
     }
 
@@ -17,6 +17,13 @@ impl TxContextStack {
         })
     }
 
+    pub fn static_is_empty() -> bool {
+        API_STACK.with(|cell| {
+            let stack = cell.borrow();
+            stack.0.is_empty()
+        })
+    }
+
     pub fn static_push(tx_context_rc: Rc<TxContext>) {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
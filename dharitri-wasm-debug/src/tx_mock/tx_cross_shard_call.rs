@@ -0,0 +1,15 @@
+use super::{AsyncCallTxData, TxInput};
+
+/// A call that crosses shards, waiting for its destination shard to process it.
+#[derive(Debug, Clone)]
+pub enum CrossShardCall {
+    /// The async call leg, which gets executed on the destination shard.
+    /// Its callback is then sent back to the caller's shard.
+    AsyncCall(AsyncCallTxData),
+
+    /// The callback of an async call, executed back on the shard of the original caller.
+    Callback(TxInput),
+
+    /// Transfer-execute calls don't have a callback.
+    TransferExecute(AsyncCallTxData),
+}
@@ -10,6 +10,9 @@ pub struct TxInput {
     pub from: Address,
     pub to: Address,
     pub moax_value: BigUint,
+    /// The MOAX value has already reached the recipient, so it is only reported by the call value API.
+    /// Such is the MOAX returned to the caller of an async call, received by the callback.
+    pub moax_value_transferred: bool,
     pub dct_values: Vec<TxInputDCT>,
     pub func_name: Vec<u8>,
     pub args: Vec<Vec<u8>>,
@@ -20,13 +23,13 @@ pub struct TxInput {
 
 impl fmt::Display for TxInput {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "TxInput {{ func: {}, args: {:?}, call_value: {}, dct_value: {:?}, from: 0x{}, to: 0x{}\n}}", 
+        write!(f, "TxInput {{ func: {}, args: {:?}, call_value: {}, dct_value: {:?}, from: {}, to: {}\n}}", 
             String::from_utf8(self.func_name.clone()).unwrap(),
             self.args,
             self.moax_value,
             self.dct_values,
-            address_hex(&self.from),
-            address_hex(&self.to))
+            address_bech32(&self.from),
+            address_bech32(&self.to))
     }
 }
 
@@ -40,6 +43,7 @@ impl TxInput {
             from: Address::zero(),
             to: Address::zero(),
             moax_value: BigUint::zero(),
+            moax_value_transferred: false,
             dct_values: Vec::new(),
             func_name: Vec::new(),
             args: Vec::new(),
@@ -1,6 +1,13 @@
 use alloc::vec::Vec;
-use dharitri_wasm::types::Address;
 use denali::model::Checkable;
+use dharitri_wasm::{
+    dharitri_codec::{DecodeError, TopDecode},
+    types::{Address, ManagedBuffer},
+};
+
+use crate::DebugApi;
+
+use super::TxContextStack;
 
 #[derive(Clone, Debug)]
 pub struct TxLog {
@@ -27,4 +34,51 @@ impl TxLog {
             false
         }
     }
+
+    /// The event identifier, i.e. the name given in `#[event("...")]`. It is always the first topic.
+    pub fn event_identifier(&self) -> &[u8] {
+        self.topics
+            .first()
+            .map(|topic| topic.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Decodes the topic corresponding to the `#[indexed]` event argument at position `index`.
+    /// Managed types can only be decoded while a transaction context is active, e.g. in a query closure.
+    pub fn decode_topic<T: TopDecode>(&self, index: usize) -> T {
+        let topic = self.topics.get(index + 1).unwrap_or_else(|| {
+            panic!(
+                "log has no topic at index {}, only {} indexed topics available",
+                index,
+                self.topics.len().saturating_sub(1)
+            )
+        });
+        decode_log_bytes::<T>(topic.as_slice()).unwrap_or_else(|err| {
+            panic!(
+                "error decoding log topic {}: {}",
+                index,
+                String::from_utf8_lossy(err.message_bytes())
+            )
+        })
+    }
+
+    /// Decodes the non-indexed event argument.
+    /// Managed types can only be decoded while a transaction context is active, e.g. in a query closure.
+    pub fn decode_data<T: TopDecode>(&self) -> T {
+        decode_log_bytes::<T>(self.data.as_slice()).unwrap_or_else(|err| {
+            panic!(
+                "error decoding log data: {}",
+                String::from_utf8_lossy(err.message_bytes())
+            )
+        })
+    }
+}
+
+/// Managed types need to be decoded from a managed buffer, which requires an active transaction context.
+fn decode_log_bytes<T: TopDecode>(bytes: &[u8]) -> Result<T, DecodeError> {
+    if TxContextStack::static_is_empty() {
+        T::top_decode(bytes)
+    } else {
+        T::top_decode(ManagedBuffer::<DebugApi>::new_from_bytes(bytes))
+    }
 }
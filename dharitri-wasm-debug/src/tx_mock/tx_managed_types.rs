@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use dharitri_wasm::{api::Handle, types::LockableStaticBuffer};
 use num_bigint::BigInt;
 
+use crate::crypto::EllipticCurve;
+
 type ManagedBufferImpl = Vec<u8>;
 
 #[derive(Debug)]
@@ -51,6 +53,7 @@ impl<V> HandleMap<V> {
 pub struct TxManagedTypes {
     pub(crate) big_int_map: HandleMap<BigInt>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
+    pub(crate) elliptic_curve_map: HandleMap<EllipticCurve>,
     pub(crate) lockable_static_buffer: LockableStaticBuffer,
 }
 
@@ -59,6 +62,7 @@ impl TxManagedTypes {
         TxManagedTypes {
             big_int_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
+            elliptic_curve_map: HandleMap::new(),
             lockable_static_buffer: LockableStaticBuffer::new(),
         }
     }
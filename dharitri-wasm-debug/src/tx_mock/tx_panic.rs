@@ -2,3 +2,12 @@ pub struct TxPanic {
     pub status: u64,
     pub message: Vec<u8>,
 }
+
+impl TxPanic {
+    pub fn out_of_gas() -> Self {
+        TxPanic {
+            status: 5,
+            message: b"not enough gas".to_vec(),
+        }
+    }
+}
@@ -5,12 +5,17 @@ use std::fmt;
 use super::{TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Default, Debug)]
+#[must_use]
 pub struct TxResult {
     pub result_status: u64,
     pub result_message: String,
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
     pub result_calls: TxResultCalls,
+    pub gas_used: u64,
+
+    /// Everything the contract printed during the transaction, including in synchronous calls, in order.
+    pub print_messages: Vec<String>,
 }
 
 impl fmt::Display for TxResult {
@@ -22,8 +27,8 @@ impl fmt::Display for TxResult {
             .collect();
         write!(
             f,
-            "TxResult {{\n\tresult_status: {},\n\tresult_values:{:?}\n}}",
-            self.result_status, results_hex
+            "TxResult {{\n\tresult_status: {},\n\tresult_values:{:?},\n\tgas_used: {}\n}}",
+            self.result_status, results_hex, self.gas_used
         )
     }
 }
@@ -36,6 +41,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            print_messages: Vec::new(),
         }
     }
 
@@ -50,6 +57,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            print_messages: Vec::new(),
         }
     }
 
@@ -61,6 +70,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            print_messages: Vec::new(),
         }
     }
 
@@ -75,6 +86,48 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            print_messages: Vec::new(),
+        }
+    }
+
+    /// Asserts that the transaction was successful.
+    /// Mostly used in tests written with the Rust testing framework.
+    pub fn assert_ok(&self) {
+        self.assert_error(0, "");
+    }
+
+    /// Asserts that the transaction failed with a user error (status 4), with the given message.
+    pub fn assert_user_error(&self, expected_message: &str) {
+        self.assert_error(4, expected_message);
+    }
+
+    pub fn assert_error(&self, expected_status: u64, expected_message: &str) {
+        if self.result_status != expected_status || self.result_message != expected_message {
+            self.print_messages_to_stderr();
+        }
+        assert_eq!(
+            self.result_status, expected_status,
+            "result status mismatch. Want: {}. Have: {}. Message: {}",
+            expected_status, self.result_status, self.result_message,
+        );
+        assert_eq!(
+            self.result_message.as_str(),
+            expected_message,
+            "result message mismatch. Want: {}. Have: {}",
+            expected_message,
+            self.result_message,
+        );
+    }
+
+    /// Echoes the contract print output, to help debug failing tests.
+    pub fn print_messages_to_stderr(&self) {
+        if self.print_messages.is_empty() {
+            return;
+        }
+        eprintln!("Contract print output:");
+        for message in &self.print_messages {
+            eprintln!("\t{}", message);
         }
     }
 
@@ -1,9 +1,15 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
 use super::AsyncCallTxData;
 
 #[derive(Clone, Default, Debug)]
 pub struct TxResultCalls {
     pub async_call: Option<AsyncCallTxData>,
     pub transfer_execute: Vec<AsyncCallTxData>,
+    /// MOAX sent directly back to the caller.
+    /// When executing an async call, it becomes the call value of the callback.
+    pub moax_back_transfer: BigUint,
 }
 
 impl TxResultCalls {
@@ -11,6 +17,7 @@ impl TxResultCalls {
         TxResultCalls {
             async_call: None,
             transfer_execute: Vec::new(),
+            moax_back_transfer: BigUint::zero(),
         }
     }
 
@@ -0,0 +1,9 @@
+//! Runs the compiled wasm bytecode of the contracts, instead of their Rust debug build.
+//!
+//! The bytecode is executed by an embedded interpreter,
+//! with the VM hooks implemented on top of the same transaction context as the debug contracts.
+
+mod vm_hooks;
+mod wasm_contract;
+
+pub use wasm_contract::WasmContract;
@@ -0,0 +1,108 @@
+use core::cmp::Ordering;
+
+use dharitri_wasm::api::{BigIntApi, Handle, Sign};
+use wasmi::RuntimeArgs;
+
+use crate::DebugApi;
+
+use super::{arg_i32, arg_i64, ret_bool, ret_i32, ret_i64, vm_error, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn big_int_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "bigIntNew" => ret_i32(api.bi_new(arg_i64(args, 0))),
+            "bigIntUnsignedByteLength" => {
+                ret_i32(api.bi_unsigned_byte_length(arg_i32(args, 0)) as i32)
+            },
+            "bigIntGetUnsignedBytes" => {
+                let bytes = api.bi_get_unsigned_bytes(arg_i32(args, 0));
+                self.mem_store(arg_i32(args, 1), bytes.as_slice());
+                ret_i32(bytes.len() as i32)
+            },
+            "bigIntSetUnsignedBytes" => {
+                let bytes = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                api.bi_set_unsigned_bytes(arg_i32(args, 0), bytes.as_slice());
+                None
+            },
+            "bigIntSignedByteLength" => ret_i32(api.bi_signed_byte_length(arg_i32(args, 0)) as i32),
+            "bigIntGetSignedBytes" => {
+                let bytes = api.bi_get_signed_bytes(arg_i32(args, 0));
+                self.mem_store(arg_i32(args, 1), bytes.as_slice());
+                ret_i32(bytes.len() as i32)
+            },
+            "bigIntSetSignedBytes" => {
+                let bytes = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                api.bi_set_signed_bytes(arg_i32(args, 0), bytes.as_slice());
+                None
+            },
+            "bigIntIsInt64" => ret_bool(api.bi_to_i64(arg_i32(args, 0)).is_some()),
+            "bigIntGetInt64" => ret_i64(
+                api.bi_to_i64(arg_i32(args, 0))
+                    .unwrap_or_else(|| vm_error("big int cannot be represented as int64")),
+            ),
+            "bigIntAdd" => self.big_int_binary_op(args, DebugApi::bi_add),
+            "bigIntSub" => self.big_int_binary_op(args, DebugApi::bi_sub),
+            "bigIntMul" => self.big_int_binary_op(args, DebugApi::bi_mul),
+            "bigIntTDiv" => self.big_int_binary_op(args, DebugApi::bi_t_div),
+            "bigIntTMod" => self.big_int_binary_op(args, DebugApi::bi_t_mod),
+            "bigIntPow" => self.big_int_binary_op(args, DebugApi::bi_pow),
+            "bigIntAnd" => self.big_int_binary_op(args, DebugApi::bi_and),
+            "bigIntOr" => self.big_int_binary_op(args, DebugApi::bi_or),
+            "bigIntXor" => self.big_int_binary_op(args, DebugApi::bi_xor),
+            "bigIntAbs" => {
+                api.bi_abs(arg_i32(args, 0), arg_i32(args, 1));
+                None
+            },
+            "bigIntNeg" => {
+                api.bi_neg(arg_i32(args, 0), arg_i32(args, 1));
+                None
+            },
+            "bigIntSqrt" => {
+                api.bi_sqrt(arg_i32(args, 0), arg_i32(args, 1));
+                None
+            },
+            "bigIntSign" => ret_i32(match api.bi_sign(arg_i32(args, 0)) {
+                Sign::Minus => -1,
+                Sign::NoSign => 0,
+                Sign::Plus => 1,
+            }),
+            "bigIntCmp" => ret_i32(match api.bi_cmp(arg_i32(args, 0), arg_i32(args, 1)) {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            }),
+            "bigIntLog2" => ret_i32(api.bi_log2(arg_i32(args, 0)) as i32),
+            "bigIntShr" => {
+                api.bi_shr(
+                    arg_i32(args, 0),
+                    arg_i32(args, 1),
+                    arg_i32(args, 2) as usize,
+                );
+                None
+            },
+            "bigIntShl" => {
+                api.bi_shl(
+                    arg_i32(args, 0),
+                    arg_i32(args, 1),
+                    arg_i32(args, 2) as usize,
+                );
+                None
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn big_int_binary_op(&self, args: &RuntimeArgs, op: BinaryOp) -> HookReturn {
+        op(
+            &self.api,
+            arg_i32(args, 0),
+            arg_i32(args, 1),
+            arg_i32(args, 2),
+        );
+        None
+    }
+}
+
+type BinaryOp = fn(&DebugApi, Handle, Handle, Handle);
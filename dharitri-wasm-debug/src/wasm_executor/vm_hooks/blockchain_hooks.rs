@@ -0,0 +1,147 @@
+use dharitri_wasm::{
+    api::{BlockchainApi, ManagedBufferApi},
+    types::{Address, ManagedAddress, ManagedType, TokenIdentifier},
+};
+use wasmi::RuntimeArgs;
+
+use crate::DebugApi;
+
+use super::{arg_i32, arg_i64, ret_bool, ret_i32, ret_i64, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn blockchain_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "getSCAddress" => {
+                self.mem_store(arg_i32(args, 0), api.get_sc_address_legacy().as_bytes());
+                None
+            },
+            "managedSCAddress" => {
+                api.mb_overwrite(arg_i32(args, 0), api.get_sc_address_legacy().as_bytes());
+                None
+            },
+            "getOwnerAddress" => {
+                self.mem_store(arg_i32(args, 0), api.get_owner_address_legacy().as_bytes());
+                None
+            },
+            "managedOwnerAddress" => {
+                api.mb_overwrite(arg_i32(args, 0), api.get_owner_address_legacy().as_bytes());
+                None
+            },
+            "getCaller" => {
+                self.mem_store(arg_i32(args, 0), api.get_caller_legacy().as_bytes());
+                None
+            },
+            "managedCaller" => {
+                api.mb_overwrite(arg_i32(args, 0), api.get_caller_legacy().as_bytes());
+                None
+            },
+            "getShardOfAddress" => {
+                let address = self.mem_load_address(arg_i32(args, 0));
+                ret_i32(api.get_shard_of_address_legacy(&address) as i32)
+            },
+            "isSmartContract" => {
+                let address = self.mem_load_address(arg_i32(args, 0));
+                ret_bool(api.is_smart_contract_legacy(&address))
+            },
+            "getFunction" => {
+                let function = api.input_ref().func_name.clone();
+                self.mem_store(arg_i32(args, 0), function.as_slice());
+                ret_i32(function.len() as i32)
+            },
+            "getGasLeft" => ret_i64(api.get_gas_left() as i64),
+            "getBlockTimestamp" => ret_i64(api.get_block_timestamp() as i64),
+            "getBlockNonce" => ret_i64(api.get_block_nonce() as i64),
+            "getBlockRound" => ret_i64(api.get_block_round() as i64),
+            "getBlockEpoch" => ret_i64(api.get_block_epoch() as i64),
+            "getPrevBlockTimestamp" => ret_i64(api.get_prev_block_timestamp() as i64),
+            "getPrevBlockNonce" => ret_i64(api.get_prev_block_nonce() as i64),
+            "getPrevBlockRound" => ret_i64(api.get_prev_block_round() as i64),
+            "getPrevBlockEpoch" => ret_i64(api.get_prev_block_epoch() as i64),
+            "getBlockRandomSeed" => {
+                self.mem_store(arg_i32(args, 0), &api.get_block_random_seed_legacy()[..]);
+                None
+            },
+            "managedGetBlockRandomSeed" => {
+                api.mb_overwrite(arg_i32(args, 0), &api.get_block_random_seed_legacy()[..]);
+                None
+            },
+            "getPrevBlockRandomSeed" => {
+                self.mem_store(
+                    arg_i32(args, 0),
+                    &api.get_prev_block_random_seed_legacy()[..],
+                );
+                None
+            },
+            "managedGetPrevBlockRandomSeed" => {
+                api.mb_overwrite(
+                    arg_i32(args, 0),
+                    &api.get_prev_block_random_seed_legacy()[..],
+                );
+                None
+            },
+            "getStateRootHash" => {
+                self.mem_store(
+                    arg_i32(args, 0),
+                    api.get_state_root_hash_legacy().as_bytes(),
+                );
+                None
+            },
+            "managedGetStateRootHash" => {
+                api.mb_overwrite(
+                    arg_i32(args, 0),
+                    api.get_state_root_hash_legacy().as_bytes(),
+                );
+                None
+            },
+            "getOriginalTxHash" => {
+                self.mem_store(arg_i32(args, 0), api.get_tx_hash_legacy().as_bytes());
+                None
+            },
+            "managedGetOriginalTxHash" => {
+                api.mb_overwrite(arg_i32(args, 0), api.get_tx_hash_legacy().as_bytes());
+                None
+            },
+            "bigIntGetExternalBalance" => {
+                let address = self.mem_load_address(arg_i32(args, 0));
+                let balance = api.get_balance_legacy(&address);
+                self.big_int_copy(balance.get_raw_handle(), arg_i32(args, 1));
+                None
+            },
+            "bigIntGetDCTExternalBalance" => {
+                let address = self.mem_load_managed_address(arg_i32(args, 0));
+                let token = self.mem_load_token_identifier(arg_i32(args, 1), arg_i32(args, 2));
+                let balance = api.get_dct_balance(&address, &token, arg_i64(args, 3) as u64);
+                self.big_int_copy(balance.get_raw_handle(), arg_i32(args, 4));
+                None
+            },
+            "getCurrentDCTNFTNonce" => {
+                let address = self.mem_load_managed_address(arg_i32(args, 0));
+                let token = self.mem_load_token_identifier(arg_i32(args, 1), arg_i32(args, 2));
+                ret_i64(api.get_current_dct_nft_nonce(&address, &token) as i64)
+            },
+            "getDCTLocalRoles" => {
+                let token = TokenIdentifier::from_raw_handle(arg_i32(args, 0));
+                ret_i64(api.get_dct_local_roles(&token).bits() as i64)
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn mem_load_address(&self, offset: i32) -> Address {
+        Address::from_slice(self.mem_load(offset, 32).as_slice())
+    }
+
+    pub(super) fn mem_load_managed_address(&self, offset: i32) -> ManagedAddress<DebugApi> {
+        ManagedAddress::from_address(&self.mem_load_address(offset))
+    }
+
+    pub(super) fn mem_load_token_identifier(
+        &self,
+        offset: i32,
+        length: i32,
+    ) -> TokenIdentifier<DebugApi> {
+        TokenIdentifier::from_dct_bytes(self.mem_load(offset, length).as_slice())
+    }
+}
@@ -0,0 +1,75 @@
+use dharitri_wasm::{
+    api::{CallValueApi, ManagedBufferApi},
+    types::ManagedType,
+};
+use wasmi::RuntimeArgs;
+
+use super::{arg_i32, ret_i32, ret_i64, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn call_value_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "checkNoPayment" => {
+                api.check_not_payable();
+                None
+            },
+            "bigIntGetCallValue" => {
+                let value = api.moax_value();
+                self.big_int_copy(value.get_raw_handle(), arg_i32(args, 0));
+                None
+            },
+            "bigIntGetDCTCallValue" => {
+                let value = api.dct_value();
+                self.big_int_copy(value.get_raw_handle(), arg_i32(args, 0));
+                None
+            },
+            "getDCTTokenName" => {
+                let token_name = api.token().to_dct_identifier();
+                self.mem_store(arg_i32(args, 0), token_name.as_slice());
+                ret_i32(token_name.len() as i32)
+            },
+            "getDCTTokenNonce" => ret_i64(api.dct_token_nonce() as i64),
+            "getDCTTokenType" => ret_i32(api.dct_token_type().as_u8() as i32),
+            "getNumDCTTransfers" => ret_i32(api.dct_num_transfers() as i32),
+            "bigIntGetDCTCallValueByIndex" => {
+                let value = api.dct_value_by_index(arg_i32(args, 1) as usize);
+                self.big_int_copy(value.get_raw_handle(), arg_i32(args, 0));
+                None
+            },
+            "getDCTTokenNameByIndex" => {
+                let token_name = api
+                    .token_by_index(arg_i32(args, 1) as usize)
+                    .to_dct_identifier();
+                self.mem_store(arg_i32(args, 0), token_name.as_slice());
+                ret_i32(token_name.len() as i32)
+            },
+            "getDCTTokenNonceByIndex" => {
+                ret_i64(api.dct_token_nonce_by_index(arg_i32(args, 0) as usize) as i64)
+            },
+            "getDCTTokenTypeByIndex" => ret_i32(
+                api.dct_token_type_by_index(arg_i32(args, 0) as usize)
+                    .as_u8() as i32,
+            ),
+            "managedGetMultiDCTCallValue" => {
+                let transfers = api.get_all_dct_transfers();
+                let transfers_bytes = api.mb_to_boxed_bytes(transfers.get_raw_handle());
+                api.mb_overwrite(arg_i32(args, 0), transfers_bytes.as_slice());
+                None
+            },
+            "getCallValueTokenName" => {
+                let (value, token) = if api.dct_num_transfers() > 0 {
+                    (api.dct_value(), api.token())
+                } else {
+                    (api.moax_value(), api.token_by_index(0))
+                };
+                self.mem_store_big_uint(arg_i32(args, 0), value.get_raw_handle());
+                let token_name = token.to_dct_identifier();
+                self.mem_store(arg_i32(args, 1), token_name.as_slice());
+                ret_i32(token_name.len() as i32)
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+}
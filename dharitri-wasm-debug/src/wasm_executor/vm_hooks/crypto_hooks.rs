@@ -0,0 +1,102 @@
+use dharitri_wasm::{
+    api::{CryptoApi, ManagedBufferApi},
+    types::MessageHashType,
+};
+use wasmi::RuntimeArgs;
+
+use crate::crypto::{
+    BLS_PUBLIC_KEY_LENGTH, BLS_SIGNATURE_LENGTH, ED25519_PUBLIC_KEY_LENGTH,
+    ED25519_SIGNATURE_LENGTH,
+};
+
+use super::{arg_i32, ret_i32, HookReturn, VMHooks};
+
+/// The signature verification hooks return 0 for a valid signature and -1 otherwise.
+fn verify_result(valid: bool) -> HookReturn {
+    ret_i32(if valid { 0 } else { -1 })
+}
+
+impl VMHooks {
+    pub(super) fn crypto_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "sha256" => {
+                let data = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                self.mem_store(
+                    arg_i32(args, 2),
+                    api.sha256_legacy(data.as_slice()).as_bytes(),
+                );
+                ret_i32(0)
+            },
+            "managedSha256" => {
+                let data = api.mb_to_boxed_bytes(arg_i32(args, 0));
+                api.mb_overwrite(
+                    arg_i32(args, 1),
+                    api.sha256_legacy(data.as_slice()).as_bytes(),
+                );
+                ret_i32(0)
+            },
+            "keccak256" => {
+                let data = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                self.mem_store(
+                    arg_i32(args, 2),
+                    api.keccak256_legacy(data.as_slice()).as_bytes(),
+                );
+                ret_i32(0)
+            },
+            "managedKeccak256" => {
+                let data = api.mb_to_boxed_bytes(arg_i32(args, 0));
+                api.mb_overwrite(
+                    arg_i32(args, 1),
+                    api.keccak256_legacy(data.as_slice()).as_bytes(),
+                );
+                ret_i32(0)
+            },
+            "ripemd160" => {
+                let data = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                self.mem_store(arg_i32(args, 2), &api.ripemd160(data.as_slice())[..]);
+                ret_i32(0)
+            },
+            "verifyBLS" => {
+                let key = self.mem_load(arg_i32(args, 0), BLS_PUBLIC_KEY_LENGTH as i32);
+                let message = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                let signature = self.mem_load(arg_i32(args, 3), BLS_SIGNATURE_LENGTH as i32);
+                verify_result(api.verify_bls(&key, &message, &signature))
+            },
+            "verifyEd25519" => {
+                let key = self.mem_load(arg_i32(args, 0), ED25519_PUBLIC_KEY_LENGTH as i32);
+                let message = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                let signature = self.mem_load(arg_i32(args, 3), ED25519_SIGNATURE_LENGTH as i32);
+                verify_result(api.verify_ed25519(&key, &message, &signature))
+            },
+            "verifySecp256k1" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let message = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                let signature = self.mem_load_der_signature(arg_i32(args, 4));
+                verify_result(api.verify_secp256k1(&key, &message, &signature))
+            },
+            "verifyCustomSecp256k1" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let message = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                let signature = self.mem_load_der_signature(arg_i32(args, 4));
+                let hash_type = MessageHashType::from(arg_i32(args, 5) as u8);
+                verify_result(api.verify_custom_secp256k1(&key, &message, &signature, hash_type))
+            },
+            "encodeSecp256k1DerSignature" => {
+                let r = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let s = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                let signature = api.encode_secp256k1_der_signature(&r, &s);
+                self.mem_store(arg_i32(args, 4), signature.as_slice());
+                ret_i32(0)
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// DER signatures carry their own length in the second byte.
+    fn mem_load_der_signature(&self, offset: i32) -> Vec<u8> {
+        let header = self.mem_load(offset, 2);
+        self.mem_load(offset, 2 + header[1] as i32)
+    }
+}
@@ -0,0 +1,69 @@
+use dharitri_wasm::api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi, ManagedBufferApi};
+use wasmi::RuntimeArgs;
+
+use super::{arg_i32, arg_i64, ret_i32, ret_i64, HookReturn, VMHooks};
+
+/// Endpoint arguments, results and errors.
+impl VMHooks {
+    pub(super) fn endpoint_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "getNumArguments" => ret_i32(api.get_num_arguments()),
+            "getArgumentLength" => ret_i32(api.get_argument_len(arg_i32(args, 0)) as i32),
+            "getArgument" => {
+                let argument = api.get_argument_vec_u8(arg_i32(args, 0));
+                self.mem_store(arg_i32(args, 1), argument.as_slice());
+                ret_i32(argument.len() as i32)
+            },
+            "bigIntGetUnsignedArgument" => {
+                let argument_handle = api.get_argument_big_uint_raw(arg_i32(args, 0));
+                self.big_int_copy(argument_handle, arg_i32(args, 1));
+                None
+            },
+            "bigIntGetSignedArgument" => {
+                let argument_handle = api.get_argument_big_int_raw(arg_i32(args, 0));
+                self.big_int_copy(argument_handle, arg_i32(args, 1));
+                None
+            },
+            "smallIntGetUnsignedArgument" => ret_i64(api.get_argument_u64(arg_i32(args, 0)) as i64),
+            "smallIntGetSignedArgument" => ret_i64(api.get_argument_i64(arg_i32(args, 0))),
+            "mBufferGetArgument" => {
+                let argument = api.get_argument_vec_u8(arg_i32(args, 0));
+                api.mb_overwrite(arg_i32(args, 1), argument.as_slice());
+                ret_i32(0)
+            },
+            "finish" => {
+                let data = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                api.finish_slice_u8(data.as_slice());
+                None
+            },
+            "bigIntFinishUnsigned" => {
+                api.finish_big_uint_raw(arg_i32(args, 0));
+                None
+            },
+            "bigIntFinishSigned" => {
+                api.finish_big_int_raw(arg_i32(args, 0));
+                None
+            },
+            "smallIntFinishUnsigned" => {
+                api.finish_u64(arg_i64(args, 0) as u64);
+                None
+            },
+            "smallIntFinishSigned" => {
+                api.finish_i64(arg_i64(args, 0));
+                None
+            },
+            "mBufferFinish" => {
+                api.finish_managed_buffer_raw(arg_i32(args, 0));
+                ret_i32(0)
+            },
+            "signalError" => {
+                let message = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                api.signal_error(message.as_slice())
+            },
+            "managedSignalError" => api.signal_error_from_buffer(arg_i32(args, 0)),
+            _ => return None,
+        };
+        Some(result)
+    }
+}
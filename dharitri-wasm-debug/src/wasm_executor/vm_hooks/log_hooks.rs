@@ -0,0 +1,43 @@
+use dharitri_wasm::{api::LogApi, types::ArgBuffer};
+use wasmi::RuntimeArgs;
+
+use super::{arg_i32, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn log_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "writeLog" => {
+                let data = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let topics_bytes = self.mem_load(arg_i32(args, 2), arg_i32(args, 3) * 32);
+                let topics: Vec<[u8; 32]> = topics_bytes
+                    .chunks(32)
+                    .map(|chunk| {
+                        let mut topic = [0u8; 32];
+                        topic.copy_from_slice(chunk);
+                        topic
+                    })
+                    .collect();
+                api.write_legacy_log(topics.as_slice(), data.as_slice());
+                None
+            },
+            "writeEventLog" => {
+                let topics =
+                    self.mem_load_arguments(arg_i32(args, 0), arg_i32(args, 1), arg_i32(args, 2));
+                let mut topics_buffer = ArgBuffer::new();
+                for topic in topics.iter() {
+                    topics_buffer.push_argument_bytes(topic.as_slice());
+                }
+                let data = self.mem_load(arg_i32(args, 3), arg_i32(args, 4));
+                api.write_event_log(&topics_buffer, data.as_slice());
+                None
+            },
+            "managedWriteLog" => {
+                api.managed_write_log(arg_i32(args, 0), arg_i32(args, 1));
+                None
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+}
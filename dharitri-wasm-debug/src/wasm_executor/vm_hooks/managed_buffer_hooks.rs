@@ -0,0 +1,92 @@
+use dharitri_wasm::api::{BigIntApi, ManagedBufferApi, ManagedTypeApi};
+use wasmi::RuntimeArgs;
+
+use super::{arg_i32, ret_bool, ret_i32, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn managed_buffer_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "mBufferNew" => ret_i32(api.mb_new_empty()),
+            "mBufferNewFromBytes" => {
+                ret_i32(self.mem_load_buffer(arg_i32(args, 0), arg_i32(args, 1)))
+            },
+            "mBufferGetLength" => ret_i32(api.mb_len(arg_i32(args, 0)) as i32),
+            "mBufferGetBytes" => {
+                let bytes = api.mb_to_boxed_bytes(arg_i32(args, 0));
+                self.mem_store(arg_i32(args, 1), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferGetByteSlice" => {
+                let starting_position = arg_i32(args, 1);
+                let slice_length = arg_i32(args, 2);
+                if starting_position < 0 || slice_length < 0 {
+                    return Some(ret_i32(1));
+                }
+                let mut slice = vec![0u8; slice_length as usize];
+                let load_result = api.mb_load_slice(
+                    arg_i32(args, 0),
+                    starting_position as usize,
+                    slice.as_mut_slice(),
+                );
+                if load_result.is_err() {
+                    return Some(ret_i32(1));
+                }
+                self.mem_store(arg_i32(args, 3), slice.as_slice());
+                ret_i32(0)
+            },
+            "mBufferCopyByteSlice" => {
+                let starting_position = arg_i32(args, 1);
+                let slice_length = arg_i32(args, 2);
+                if starting_position < 0 || slice_length < 0 {
+                    return Some(ret_i32(1));
+                }
+                let copy_result = api.mb_copy_slice(
+                    arg_i32(args, 0),
+                    starting_position as usize,
+                    slice_length as usize,
+                    arg_i32(args, 3),
+                );
+                ret_i32(copy_result.is_err() as i32)
+            },
+            "mBufferEq" => ret_bool(api.mb_eq(arg_i32(args, 0), arg_i32(args, 1))),
+            "mBufferSetBytes" => {
+                let bytes = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                api.mb_overwrite(arg_i32(args, 0), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferAppend" => {
+                api.mb_append(arg_i32(args, 0), arg_i32(args, 1));
+                ret_i32(0)
+            },
+            "mBufferAppendBytes" => {
+                let bytes = self.mem_load(arg_i32(args, 1), arg_i32(args, 2));
+                api.mb_append_bytes(arg_i32(args, 0), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferToBigIntUnsigned" => {
+                let bytes = api.mb_to_boxed_bytes(arg_i32(args, 0));
+                api.bi_set_unsigned_bytes(arg_i32(args, 1), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferToBigIntSigned" => {
+                let bytes = api.mb_to_boxed_bytes(arg_i32(args, 0));
+                api.bi_set_signed_bytes(arg_i32(args, 1), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferFromBigIntUnsigned" => {
+                let bytes = api.bi_get_unsigned_bytes(arg_i32(args, 1));
+                api.mb_overwrite(arg_i32(args, 0), bytes.as_slice());
+                ret_i32(0)
+            },
+            "mBufferFromBigIntSigned" => {
+                let bytes = api.bi_get_signed_bytes(arg_i32(args, 1));
+                api.mb_overwrite(arg_i32(args, 0), bytes.as_slice());
+                ret_i32(0)
+            },
+            "validateTokenIdentifier" => ret_bool(api.validate_token_identifier(arg_i32(args, 0))),
+            _ => return None,
+        };
+        Some(result)
+    }
+}
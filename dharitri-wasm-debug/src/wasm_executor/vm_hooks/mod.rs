@@ -0,0 +1,198 @@
+mod big_int_hooks;
+mod blockchain_hooks;
+mod call_value_hooks;
+mod crypto_hooks;
+mod endpoint_hooks;
+mod log_hooks;
+mod managed_buffer_hooks;
+mod send_hooks;
+mod storage_hooks;
+
+use std::cell::RefCell;
+
+use dharitri_wasm::api::{BigIntApi, Handle, ManagedBufferApi};
+use num_bigint::BigInt;
+use wasmi::{
+    Error, Externals, FuncInstance, FuncRef, MemoryRef, ModuleImportResolver, RuntimeArgs,
+    RuntimeValue, Signature, Trap,
+};
+
+use crate::{tx_mock::TxPanic, DebugApi};
+
+/// The module name under which the VM exposes its hooks.
+pub(crate) const VM_HOOKS_MODULE_NAME: &str = "env";
+
+/// The value returned to the contract by a VM hook, if any.
+type HookReturn = Option<RuntimeValue>;
+
+/// Collects the names of the VM hooks imported by a contract.
+/// Every import is accepted, unknown hooks only fail the transaction if they are actually called.
+#[derive(Default)]
+pub(crate) struct VMHooksResolver {
+    hook_names: RefCell<Vec<String>>,
+}
+
+impl VMHooksResolver {
+    pub(crate) fn into_hook_names(self) -> Vec<String> {
+        self.hook_names.into_inner()
+    }
+}
+
+impl ModuleImportResolver for VMHooksResolver {
+    fn resolve_func(&self, field_name: &str, signature: &Signature) -> Result<FuncRef, Error> {
+        let mut hook_names = self.hook_names.borrow_mut();
+        let index = hook_names.len();
+        hook_names.push(field_name.to_string());
+        Ok(FuncInstance::alloc_host(signature.clone(), index))
+    }
+}
+
+/// Implements the VM hooks called by the wasm contract on top of the debug API,
+/// so they operate on the same transaction context as the contracts running in debug mode.
+pub(crate) struct VMHooks {
+    api: DebugApi,
+    memory: MemoryRef,
+    hook_names: Vec<String>,
+}
+
+impl VMHooks {
+    pub(crate) fn new(api: DebugApi, memory: MemoryRef, hook_names: Vec<String>) -> Self {
+        VMHooks {
+            api,
+            memory,
+            hook_names,
+        }
+    }
+
+    fn mem_load(&self, offset: i32, length: i32) -> Vec<u8> {
+        if length < 0 {
+            vm_error("mem load: negative length");
+        }
+        self.memory
+            .get(offset as u32, length as usize)
+            .unwrap_or_else(|_| vm_error("mem load: bad bounds"))
+    }
+
+    fn mem_store(&self, offset: i32, bytes: &[u8]) {
+        self.memory
+            .set(offset as u32, bytes)
+            .unwrap_or_else(|_| vm_error("mem store: bad bounds"))
+    }
+
+    /// Loads the arguments serialized by the contract as an array of `i32` lengths, followed by the concatenated data.
+    fn mem_load_arguments(
+        &self,
+        num_arguments: i32,
+        arguments_length_offset: i32,
+        data_offset: i32,
+    ) -> Vec<Vec<u8>> {
+        let lengths_bytes = self.mem_load(arguments_length_offset, num_arguments * 4);
+        let mut current_offset = data_offset;
+        lengths_bytes
+            .chunks(4)
+            .map(|length_bytes| {
+                let length = i32::from_le_bytes([
+                    length_bytes[0],
+                    length_bytes[1],
+                    length_bytes[2],
+                    length_bytes[3],
+                ]);
+                let argument = self.mem_load(current_offset, length);
+                current_offset += length;
+                argument
+            })
+            .collect()
+    }
+
+    fn big_int_get(&self, handle: Handle) -> BigInt {
+        self.api.m_types_borrow().big_int_map.get(handle).clone()
+    }
+
+    fn big_int_set(&self, handle: Handle, value: BigInt) {
+        self.api
+            .m_types_borrow_mut()
+            .big_int_map
+            .insert(handle, value);
+    }
+
+    /// Copies the value of a newly created big int handle to the handle provided by the contract.
+    fn big_int_copy(&self, source: Handle, dest: Handle) {
+        let value = self.big_int_get(source);
+        self.big_int_set(dest, value);
+    }
+
+    /// Big unsigned integers are passed through memory as 32-byte big endian values.
+    fn mem_load_big_uint(&self, offset: i32) -> Handle {
+        let bytes = self.mem_load(offset, 32);
+        let handle = self.api.bi_new_zero();
+        self.api.bi_set_unsigned_bytes(handle, bytes.as_slice());
+        handle
+    }
+
+    fn mem_store_big_uint(&self, offset: i32, handle: Handle) {
+        let bytes = self.api.bi_get_unsigned_bytes(handle);
+        if bytes.len() > 32 {
+            vm_error("big int does not fit in 32 bytes");
+        }
+        let mut padded = [0u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(bytes.as_slice());
+        self.mem_store(offset, &padded[..]);
+    }
+
+    fn mem_load_buffer(&self, offset: i32, length: i32) -> Handle {
+        let bytes = self.mem_load(offset, length);
+        self.api.mb_new_from_bytes(bytes.as_slice())
+    }
+
+    fn invoke_hook(&self, name: &str, args: &RuntimeArgs) -> HookReturn {
+        self.big_int_hook(name, args)
+            .or_else(|| self.managed_buffer_hook(name, args))
+            .or_else(|| self.endpoint_hook(name, args))
+            .or_else(|| self.storage_hook(name, args))
+            .or_else(|| self.blockchain_hook(name, args))
+            .or_else(|| self.call_value_hook(name, args))
+            .or_else(|| self.crypto_hook(name, args))
+            .or_else(|| self.log_hook(name, args))
+            .or_else(|| self.send_hook(name, args))
+            .unwrap_or_else(|| {
+                vm_error(format!("VM hook not supported by the wasm executor: {}", name).as_str())
+            })
+    }
+}
+
+impl Externals for VMHooks {
+    fn invoke_index(&mut self, index: usize, args: RuntimeArgs) -> Result<HookReturn, Trap> {
+        let name = self.hook_names[index].clone();
+        Ok(self.invoke_hook(name.as_str(), &args))
+    }
+}
+
+/// Fails the transaction with an execution error, the same way the VM does for invalid hook calls.
+fn vm_error(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.as_bytes().to_vec(),
+    })
+}
+
+fn arg_i32(args: &RuntimeArgs, index: usize) -> i32 {
+    args.nth_checked(index)
+        .unwrap_or_else(|_| vm_error("invalid VM hook argument"))
+}
+
+fn arg_i64(args: &RuntimeArgs, index: usize) -> i64 {
+    args.nth_checked(index)
+        .unwrap_or_else(|_| vm_error("invalid VM hook argument"))
+}
+
+fn ret_i32(value: i32) -> HookReturn {
+    Some(RuntimeValue::I32(value))
+}
+
+fn ret_i64(value: i64) -> HookReturn {
+    Some(RuntimeValue::I64(value))
+}
+
+fn ret_bool(value: bool) -> HookReturn {
+    ret_i32(value as i32)
+}
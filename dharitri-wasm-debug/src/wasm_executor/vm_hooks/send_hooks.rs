@@ -0,0 +1,193 @@
+use dharitri_wasm::{
+    api::{ManagedBufferApi, SendApi},
+    types::{
+        BigUint, DctTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedType,
+        ManagedVec,
+    },
+    HexCallDataDeserializer,
+};
+use wasmi::RuntimeArgs;
+
+use crate::{tx_mock::TxPanic, DebugApi};
+
+use super::{arg_i32, arg_i64, ret_i32, HookReturn, VMHooks};
+
+/// The transfer hooks return 0 on success and 1 on failure.
+fn transfer_result(result: Result<(), &'static [u8]>) -> HookReturn {
+    ret_i32(result.is_err() as i32)
+}
+
+impl VMHooks {
+    pub(super) fn send_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "transferValue" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 0));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 1));
+                let data = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                api.direct_moax(&to, &amount, data.as_slice());
+                ret_i32(0)
+            },
+            "transferValueExecute" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 0));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 1));
+                let endpoint_name =
+                    self.mem_load_managed_buffer(arg_i32(args, 3), arg_i32(args, 4));
+                let arg_buffer =
+                    self.mem_load_arg_buffer(arg_i32(args, 5), arg_i32(args, 6), arg_i32(args, 7));
+                transfer_result(api.direct_moax_execute(
+                    &to,
+                    &amount,
+                    arg_i64(args, 2) as u64,
+                    &endpoint_name,
+                    &arg_buffer,
+                ))
+            },
+            "transferDCTExecute" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 0));
+                let token = self.mem_load_token_identifier(arg_i32(args, 1), arg_i32(args, 2));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 3));
+                let endpoint_name =
+                    self.mem_load_managed_buffer(arg_i32(args, 5), arg_i32(args, 6));
+                let arg_buffer =
+                    self.mem_load_arg_buffer(arg_i32(args, 7), arg_i32(args, 8), arg_i32(args, 9));
+                transfer_result(api.direct_dct_execute(
+                    &to,
+                    &token,
+                    &amount,
+                    arg_i64(args, 4) as u64,
+                    &endpoint_name,
+                    &arg_buffer,
+                ))
+            },
+            "transferDCTNFTExecute" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 0));
+                let token = self.mem_load_token_identifier(arg_i32(args, 1), arg_i32(args, 2));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 3));
+                let endpoint_name =
+                    self.mem_load_managed_buffer(arg_i32(args, 6), arg_i32(args, 7));
+                let arg_buffer =
+                    self.mem_load_arg_buffer(arg_i32(args, 8), arg_i32(args, 9), arg_i32(args, 10));
+                transfer_result(api.direct_dct_nft_execute(
+                    &to,
+                    &token,
+                    arg_i64(args, 4) as u64,
+                    &amount,
+                    arg_i64(args, 5) as u64,
+                    &endpoint_name,
+                    &arg_buffer,
+                ))
+            },
+            "managedTransferValueExecute" => transfer_result(api.direct_moax_execute(
+                &ManagedAddress::from_raw_handle(arg_i32(args, 0)),
+                &BigUint::from_raw_handle(arg_i32(args, 1)),
+                arg_i64(args, 2) as u64,
+                &ManagedBuffer::from_raw_handle(arg_i32(args, 3)),
+                &ManagedArgBuffer::from_raw_handle(arg_i32(args, 4)),
+            )),
+            "managedMultiTransferDCTNFTExecute" => {
+                let payments: ManagedVec<DebugApi, DctTokenPayment<DebugApi>> =
+                    ManagedVec::from_raw_handle(arg_i32(args, 1));
+                transfer_result(api.direct_multi_dct_transfer_execute(
+                    &ManagedAddress::from_raw_handle(arg_i32(args, 0)),
+                    &payments,
+                    arg_i64(args, 2) as u64,
+                    &ManagedBuffer::from_raw_handle(arg_i32(args, 3)),
+                    &ManagedArgBuffer::from_raw_handle(arg_i32(args, 4)),
+                ))
+            },
+            "executeOnDestContext" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 1));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 2));
+                let endpoint_name =
+                    self.mem_load_managed_buffer(arg_i32(args, 3), arg_i32(args, 4));
+                let arg_buffer =
+                    self.mem_load_arg_buffer(arg_i32(args, 5), arg_i32(args, 6), arg_i32(args, 7));
+                // the results are also added to the return data of the caller
+                let _ = api.execute_on_dest_context_raw(
+                    arg_i64(args, 0) as u64,
+                    &to,
+                    &amount,
+                    &endpoint_name,
+                    &arg_buffer,
+                );
+                ret_i32(0)
+            },
+            "managedExecuteOnDestContext" => {
+                let results = api.execute_on_dest_context_raw(
+                    arg_i64(args, 0) as u64,
+                    &ManagedAddress::from_raw_handle(arg_i32(args, 1)),
+                    &BigUint::from_raw_handle(arg_i32(args, 2)),
+                    &ManagedBuffer::from_raw_handle(arg_i32(args, 3)),
+                    &ManagedArgBuffer::from_raw_handle(arg_i32(args, 4)),
+                );
+                let results_bytes = api.mb_to_boxed_bytes(results.get_raw_handle());
+                api.mb_overwrite(arg_i32(args, 5), results_bytes.as_slice());
+                ret_i32(0)
+            },
+            "asyncCall" => {
+                let to = self.mem_load_managed_address(arg_i32(args, 0));
+                let amount = self.mem_load_big_uint_for_send(arg_i32(args, 1));
+                let call_data = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                let mut deserializer = HexCallDataDeserializer::new(call_data.as_slice());
+                let endpoint_name = ManagedBuffer::new_from_bytes(deserializer.get_func_name());
+                let mut arg_buffer = ManagedArgBuffer::new_empty();
+                while let Some(argument) = deserializer.next_argument().unwrap_or_else(|_| {
+                    std::panic::panic_any(TxPanic {
+                        status: 10,
+                        message: b"invalid async call data".to_vec(),
+                    })
+                }) {
+                    arg_buffer.push_arg_raw(ManagedBuffer::new_from_bytes(argument.as_slice()));
+                }
+                api.async_call_raw(&to, &amount, &endpoint_name, &arg_buffer)
+            },
+            "getNumReturnData" => ret_i32(api.result_borrow_mut().result_values.len() as i32),
+            "getReturnDataSize" => ret_i32(self.return_data(arg_i32(args, 0)).len() as i32),
+            "getReturnData" => {
+                let data = self.return_data(arg_i32(args, 0));
+                self.mem_store(arg_i32(args, 1), data.as_slice());
+                ret_i32(data.len() as i32)
+            },
+            "managedGetReturnData" => {
+                let data = self.return_data(arg_i32(args, 0));
+                api.mb_overwrite(arg_i32(args, 1), data.as_slice());
+                None
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn mem_load_big_uint_for_send(&self, offset: i32) -> BigUint<DebugApi> {
+        BigUint::from_raw_handle(self.mem_load_big_uint(offset))
+    }
+
+    fn mem_load_managed_buffer(&self, offset: i32, length: i32) -> ManagedBuffer<DebugApi> {
+        ManagedBuffer::from_raw_handle(self.mem_load_buffer(offset, length))
+    }
+
+    fn mem_load_arg_buffer(
+        &self,
+        num_arguments: i32,
+        arguments_length_offset: i32,
+        data_offset: i32,
+    ) -> ManagedArgBuffer<DebugApi> {
+        let mut arg_buffer = ManagedArgBuffer::new_empty();
+        for argument in self.mem_load_arguments(num_arguments, arguments_length_offset, data_offset)
+        {
+            arg_buffer.push_arg_raw(ManagedBuffer::new_from_bytes(argument.as_slice()));
+        }
+        arg_buffer
+    }
+
+    /// The results of the current execution so far, including the ones of the finished synchronous calls.
+    fn return_data(&self, index: i32) -> Vec<u8> {
+        self.api
+            .result_borrow_mut()
+            .result_values
+            .get(index as usize)
+            .cloned()
+            .unwrap_or_else(|| super::vm_error("invalid return data index"))
+    }
+}
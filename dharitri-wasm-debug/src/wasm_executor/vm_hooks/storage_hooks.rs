@@ -0,0 +1,69 @@
+use dharitri_wasm::api::{BigIntApi, ManagedBufferApi, StorageReadApi, StorageWriteApi};
+use wasmi::RuntimeArgs;
+
+use super::{arg_i32, arg_i64, ret_i32, ret_i64, HookReturn, VMHooks};
+
+impl VMHooks {
+    pub(super) fn storage_hook(&self, name: &str, args: &RuntimeArgs) -> Option<HookReturn> {
+        let api = &self.api;
+        let result = match name {
+            "storageStore" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let value = self.mem_load(arg_i32(args, 2), arg_i32(args, 3));
+                api.storage_store_slice_u8(key.as_slice(), value.as_slice());
+                ret_i32(0)
+            },
+            "storageLoadLength" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                ret_i32(api.storage_load_len(key.as_slice()) as i32)
+            },
+            "storageLoad" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let value = api.storage_load_vec_u8(key.as_slice());
+                self.mem_store(arg_i32(args, 2), value.as_slice());
+                ret_i32(value.len() as i32)
+            },
+            "bigIntStorageStoreUnsigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                api.storage_store_big_uint_raw(key.as_slice(), arg_i32(args, 2));
+                ret_i32(0)
+            },
+            "bigIntStorageLoadUnsigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                let value_handle = api.storage_load_big_uint_raw(key.as_slice());
+                self.big_int_copy(value_handle, arg_i32(args, 2));
+                ret_i32(api.bi_unsigned_byte_length(value_handle) as i32)
+            },
+            "smallIntStorageStoreUnsigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                api.storage_store_u64(key.as_slice(), arg_i64(args, 2) as u64);
+                ret_i32(0)
+            },
+            "smallIntStorageStoreSigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                api.storage_store_i64(key.as_slice(), arg_i64(args, 2));
+                ret_i32(0)
+            },
+            "smallIntStorageLoadUnsigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                ret_i64(api.storage_load_u64(key.as_slice()) as i64)
+            },
+            "smallIntStorageLoadSigned" => {
+                let key = self.mem_load(arg_i32(args, 0), arg_i32(args, 1));
+                ret_i64(api.storage_load_i64(key.as_slice()))
+            },
+            "mBufferStorageStore" => {
+                api.storage_store_managed_buffer_raw(arg_i32(args, 0), arg_i32(args, 1));
+                ret_i32(0)
+            },
+            "mBufferStorageLoad" => {
+                let value_handle = api.storage_load_managed_buffer_raw(arg_i32(args, 0));
+                let value = api.mb_to_boxed_bytes(value_handle);
+                api.mb_overwrite(arg_i32(args, 1), value.as_slice());
+                ret_i32(0)
+            },
+            _ => return None,
+        };
+        Some(result)
+    }
+}
@@ -0,0 +1,76 @@
+use dharitri_wasm::contract_base::CallableContract;
+use wasmi::{ExternVal, ImportsBuilder, Module, ModuleInstance};
+
+use crate::{tx_mock::TxPanic, DebugApi};
+
+use super::vm_hooks::{VMHooks, VMHooksResolver, VM_HOOKS_MODULE_NAME};
+
+/// A contract whose compiled wasm bytecode gets interpreted.
+/// It plugs into the execution the same way as the contracts built in debug mode.
+pub struct WasmContract {
+    module: Module,
+    api: DebugApi,
+}
+
+impl WasmContract {
+    /// Parses the contract code. Panics if the code is missing or is not valid wasm bytecode.
+    pub fn new(contract_code: &[u8], api: DebugApi) -> Self {
+        let module = Module::from_buffer(contract_code).unwrap_or_else(|err| {
+            panic!(
+                "Invalid wasm contract code ({}): {}",
+                err,
+                String::from_utf8_lossy(&contract_code[..contract_code.len().min(100)])
+            )
+        });
+        WasmContract { module, api }
+    }
+}
+
+impl CallableContract<DebugApi> for WasmContract {
+    fn call(&self, fn_name: &[u8]) -> bool {
+        let fn_name = match core::str::from_utf8(fn_name) {
+            Ok(fn_name) => fn_name,
+            Err(_) => return false,
+        };
+
+        let resolver = VMHooksResolver::default();
+        let not_started_instance = ModuleInstance::new(
+            &self.module,
+            &ImportsBuilder::new().with_resolver(VM_HOOKS_MODULE_NAME, &resolver),
+        )
+        .unwrap_or_else(|err| execution_failed(err));
+        let memory = match not_started_instance
+            .not_started_instance()
+            .export_by_name("memory")
+        {
+            Some(ExternVal::Memory(memory)) => memory,
+            _ => execution_failed("contract does not export its memory"),
+        };
+
+        let mut vm_hooks = VMHooks::new(self.api.clone(), memory, resolver.into_hook_names());
+        let instance = not_started_instance
+            .run_start(&mut vm_hooks)
+            .unwrap_or_else(|err| execution_failed(err));
+
+        if !matches!(instance.export_by_name(fn_name), Some(ExternVal::Func(_))) {
+            return false;
+        }
+
+        instance
+            .invoke_export(fn_name, &[], &mut vm_hooks)
+            .unwrap_or_else(|err| execution_failed(err));
+        true
+    }
+
+    fn into_api(self: Box<Self>) -> DebugApi {
+        self.api
+    }
+}
+
+/// Traps and instantiation errors fail the transaction the same way they do in the VM.
+fn execution_failed<E: core::fmt::Display>(err: E) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: format!("execution failed: {}", err).into_bytes(),
+    })
+}
@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use dharitri_wasm::types::Address;
+use dharitri_wasm::types::{Address, CodeMetadata};
 use num_bigint::BigUint;
 use std::{collections::HashMap, fmt, fmt::Write};
 
@@ -18,7 +18,18 @@ pub struct AccountData {
     pub storage: AccountStorage,
     pub username: Vec<u8>,
     pub contract_path: Option<Vec<u8>>,
+    pub code_metadata: CodeMetadata,
     pub contract_owner: Option<Address>,
+    pub developer_rewards: BigUint,
+}
+
+/// Used for contracts whose code metadata is not specified, e.g. in denali `setState` and `scDeploy` steps.
+/// Everything is allowed, so that scenarios written before code metadata was enforced keep working.
+pub fn default_contract_code_metadata() -> CodeMetadata {
+    CodeMetadata::UPGRADEABLE
+        | CodeMetadata::PAYABLE
+        | CodeMetadata::PAYABLE_BY_SC
+        | CodeMetadata::READABLE
 }
 
 impl fmt::Display for AccountData {
@@ -127,6 +127,63 @@ impl AccountDct {
         }
     }
 
+    /// Freezes or unfreezes all the tokens of a kind held by the account.
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        let dct_data = self
+            .0
+            .entry(token_identifier.to_vec())
+            .or_insert_with(|| DctData {
+                token_identifier: token_identifier.to_vec(),
+                instances: DctInstances::new(),
+                last_nonce: 0,
+                roles: DctRoles::default(),
+                frozen: false,
+            });
+        dct_data.frozen = frozen;
+    }
+
+    /// Freezes or unfreezes a single NFT/SFT instance.
+    /// Returns false if the account does not hold the instance.
+    pub fn set_instance_frozen(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+        frozen: bool,
+    ) -> bool {
+        if let Some(instance) = self
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_mut_by_nonce(nonce))
+        {
+            instance.frozen = frozen;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// True if either the whole token or the specific instance is frozen.
+    pub fn is_frozen(&self, token_identifier: &[u8], nonce: u64) -> bool {
+        if let Some(dct_data) = self.get_by_identifier(token_identifier) {
+            dct_data.frozen
+                || dct_data
+                    .instances
+                    .get_by_nonce(nonce)
+                    .map_or(false, |instance| instance.frozen)
+        } else {
+            false
+        }
+    }
+
+    /// Removes the balance of a frozen token (nonce 0) or of a frozen single instance.
+    /// Returns false if there is nothing frozen to wipe.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: u64) -> bool {
+        if !self.is_frozen(token_identifier, nonce) {
+            return false;
+        }
+        let dct_data = self.get_mut_by_identifier(token_identifier).unwrap();
+        dct_data.instances.remove(nonce).is_some()
+    }
+
     pub fn iter(&self) -> Iter<Vec<u8>, DctData> {
         self.0.iter()
     }
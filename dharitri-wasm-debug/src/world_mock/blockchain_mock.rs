@@ -2,25 +2,57 @@ use dharitri_wasm::types::Address;
 use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::{collections::HashMap, path::PathBuf, rc::Rc};
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    rc::Rc,
+};
 
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache},
+    tx_mock::{BlockchainUpdate, CrossShardCall, TxCache, TxResult},
     ContractMap, DebugApi,
 };
 
-use super::{AccountData, BlockInfo};
+use super::{
+    is_smart_contract_address, AccountData, BlockInfo, BlockchainSnapshot, GasSchedule,
+};
 
 const DHARITRI_REWARD_KEY: &[u8] = b"DHARITRIreward";
 
-#[derive(Debug)]
+/// 0.05 MOAX, same as on the real network.
+const DCT_ISSUE_COST_DEFAULT: u64 = 50_000_000_000_000_000;
+
+/// 30%, same as on the real network.
+const DEVELOPER_FEES_PERCENTAGE_DEFAULT: u64 = 30;
+
+#[derive(Clone, Debug)]
 pub struct BlockchainMock {
-    pub accounts: HashMap<Address, AccountData>,
+    pub accounts: HashMap<Address, Rc<AccountData>>,
     pub new_addresses: HashMap<(Address, u64), Address>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub contract_map: ContractMap<DebugApi>,
+    /// Files the registered contracts were loaded from, so that state dumps can reference them.
+    pub contract_file_paths: HashMap<Vec<u8>, PathBuf>,
     pub current_dir: PathBuf,
+    pub gas_schedule: GasSchedule,
+    /// Gas schedules that denali scenarios can refer to by name, e.g. `"v3"`.
+    pub named_gas_schedules: HashMap<String, GasSchedule>,
+    pub snapshots: HashMap<String, BlockchainSnapshot>,
+    pub num_shards: u32,
+    pub cross_shard_calls: VecDeque<CrossShardCall>,
+    /// The MOAX value the DCT system smart contract requires for issuing a token.
+    pub dct_issue_cost: BigUint,
+    /// The share of the fee paid for the gas used by a contract call that goes to the called contract.
+    pub developer_fees_percentage: u64,
+    /// When enabled, the unused gas is refunded to the sender after each transaction,
+    /// and the developer share of the fee is added to the developer rewards of the called contract.
+    /// Disabled by default, in which case the entire gas limit is paid.
+    /// Requires a metered gas schedule, otherwise all transactions would be free.
+    pub tx_fee_settlement: bool,
+    /// When enabled, contracts are executed by interpreting their wasm bytecode,
+    /// instead of using the contracts registered in the contract map.
+    pub wasm_execution: bool,
 }
 
 impl BlockchainMock {
@@ -31,7 +63,17 @@ impl BlockchainMock {
             previous_block_info: BlockInfo::new(),
             current_block_info: BlockInfo::new(),
             contract_map: ContractMap::default(),
+            contract_file_paths: HashMap::new(),
             current_dir: std::env::current_dir().unwrap(),
+            gas_schedule: GasSchedule::zero(),
+            named_gas_schedules: HashMap::new(),
+            snapshots: HashMap::new(),
+            num_shards: 1,
+            cross_shard_calls: VecDeque::new(),
+            dct_issue_cost: BigUint::from(DCT_ISSUE_COST_DEFAULT),
+            developer_fees_percentage: DEVELOPER_FEES_PERCENTAGE_DEFAULT,
+            tx_fee_settlement: false,
+            wasm_execution: false,
         }
     }
 }
@@ -43,10 +85,45 @@ impl Default for BlockchainMock {
 }
 
 impl BlockchainMock {
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) {
+        self.gas_schedule = gas_schedule;
+    }
+
+    /// Makes a gas schedule available to scenarios under the given name, e.g. `"v3"`.
+    pub fn register_gas_schedule(&mut self, name: &str, gas_schedule: GasSchedule) {
+        self.named_gas_schedules
+            .insert(name.to_string(), gas_schedule);
+    }
+
+    pub fn set_dct_issue_cost(&mut self, dct_issue_cost: BigUint) {
+        self.dct_issue_cost = dct_issue_cost;
+    }
+
+    pub fn set_developer_fees_percentage(&mut self, developer_fees_percentage: u64) {
+        assert!(
+            developer_fees_percentage <= 100,
+            "developer fees percentage cannot exceed 100"
+        );
+        self.developer_fees_percentage = developer_fees_percentage;
+    }
+
+    pub fn set_tx_fee_settlement(&mut self, tx_fee_settlement: bool) {
+        self.tx_fee_settlement = tx_fee_settlement;
+    }
+
+    pub fn set_wasm_execution(&mut self, wasm_execution: bool) {
+        self.wasm_execution = wasm_execution;
+    }
+
     pub fn account_exists(&self, address: &Address) -> bool {
         self.accounts.contains_key(address)
     }
 
+    /// Accounts can be shared with snapshots, in which case the account gets copied before being modified.
+    pub fn account_mut(&mut self, address: &Address) -> Option<&mut AccountData> {
+        self.accounts.get_mut(address).map(Rc::make_mut)
+    }
+
     pub fn contains_contract(&self, contract_path_expr: &str) -> bool {
         let contract_bytes = interpret_string(
             contract_path_expr,
@@ -66,7 +143,7 @@ impl BlockchainMock {
 
     pub fn increase_account_nonce(self: &mut Rc<Self>, address: &Address) {
         let self_ref = Rc::get_mut(self).unwrap();
-        let account = self_ref.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self_ref.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
@@ -77,7 +154,7 @@ impl BlockchainMock {
 
     pub fn subtract_tx_gas(self: &mut Rc<Self>, address: &Address, gas_limit: u64, gas_price: u64) {
         let self_ref = Rc::get_mut(self).unwrap();
-        let account = self_ref.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self_ref.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
@@ -91,8 +168,58 @@ impl BlockchainMock {
         account.moax_balance -= &gas_cost;
     }
 
+    /// Called after execution, to complete the payment of the gas paid upfront via `subtract_tx_gas`.
+    /// The gas that was not used is refunded to the sender,
+    /// and the developer share of the fee for the used gas is added to the developer rewards of the called contract,
+    /// from where the owner can claim it via `ClaimDeveloperRewards`. The contract balance is not affected.
+    /// Failed transactions consume their entire gas limit.
+    /// Does nothing unless `tx_fee_settlement` is enabled.
+    pub fn settle_tx_fee(
+        self: &mut Rc<Self>,
+        sender: &Address,
+        contract: &Address,
+        gas_limit: u64,
+        gas_price: u64,
+        tx_result: &TxResult,
+    ) {
+        if !self.tx_fee_settlement || gas_price == 0 {
+            return;
+        }
+        assert!(
+            self.gas_schedule.is_metered(),
+            "tx fee settlement requires a metered gas schedule"
+        );
+
+        let refund = self.tx_gas_refund(gas_limit, gas_price, tx_result);
+        let self_ref = Rc::get_mut(self).unwrap();
+        if let Some(sender_account) = self_ref.account_mut(sender) {
+            sender_account.moax_balance += refund;
+        }
+
+        if tx_result.result_status == 0 && is_smart_contract_address(contract) {
+            let gas_used = tx_gas_used(gas_limit, tx_result);
+            let developer_fees = BigUint::from(gas_used)
+                * BigUint::from(gas_price)
+                * BigUint::from(self_ref.developer_fees_percentage)
+                / 100u32;
+            if let Some(contract_account) = self_ref.account_mut(contract) {
+                contract_account.developer_rewards += developer_fees;
+            }
+        }
+    }
+
+    /// The MOAX value returned to the sender for the gas the transaction did not use.
+    pub fn tx_gas_refund(&self, gas_limit: u64, gas_price: u64, tx_result: &TxResult) -> BigUint {
+        if !self.tx_fee_settlement {
+            return BigUint::zero();
+        }
+
+        let gas_unused = gas_limit - tx_gas_used(gas_limit, tx_result);
+        BigUint::from(gas_unused) * BigUint::from(gas_price)
+    }
+
     pub fn increase_validator_reward(&mut self, address: &Address, amount: &BigUint) {
-        let account = self.accounts.get_mut(address).unwrap_or_else(|| {
+        let account = self.account_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
                 &std::str::from_utf8(address.as_ref()).unwrap()
@@ -111,3 +238,11 @@ impl BlockchainMock {
             .insert(DHARITRI_REWARD_KEY.to_vec(), storage_v_rew.to_bytes_be());
     }
 }
+
+fn tx_gas_used(gas_limit: u64, tx_result: &TxResult) -> u64 {
+    if tx_result.result_status == 0 {
+        tx_result.gas_used.min(gas_limit)
+    } else {
+        gas_limit
+    }
+}
@@ -1,11 +1,15 @@
 use alloc::vec::Vec;
-use dharitri_wasm::types::Address;
+use dharitri_wasm::{
+    dct::DCT_SYSTEM_SC_ADDRESS_ARRAY,
+    types::{Address, CodeMetadata},
+};
+use num_bigint::BigUint;
 
-use std::fmt::Write;
+use std::{collections::HashMap, fmt::Write, rc::Rc};
 
-use crate::address_hex;
+use crate::address_bech32;
 
-use super::AccountData;
+use super::{AccountData, AccountDct};
 
 const SC_ADDRESS_NUM_LEADING_ZEROS: u8 = 8;
 
@@ -13,7 +17,7 @@ use super::BlockchainMock;
 
 impl BlockchainMock {
     pub fn add_account(&mut self, acct: AccountData) {
-        self.accounts.insert(acct.address.clone(), acct);
+        self.accounts.insert(acct.address.clone(), Rc::new(acct));
     }
 
     pub fn validate_and_add_account(&mut self, acct: AccountData) {
@@ -27,7 +31,7 @@ impl BlockchainMock {
             write!(
                 &mut accounts_buf,
                 "\n\t{} -> {}",
-                address_hex(address),
+                address_bech32(address),
                 account
             )
             .unwrap();
@@ -55,8 +59,9 @@ impl BlockchainMock {
         let is_sc = is_smart_contract_address(&account.address);
         let has_code = self.check_account_has_code(account);
 
+        // the DCT system smart contract is mocked, so it has no code
         assert!(
-            !is_sc || has_code,
+            !is_sc || has_code || is_dct_system_sc_address(&account.address),
             "Account has a smart contract address but no code"
         );
 
@@ -66,6 +71,27 @@ impl BlockchainMock {
         );
     }
 
+    /// The DCT system smart contract has no code, its account is created the first time it is called.
+    pub fn create_dct_system_sc_account_if_missing(&mut self) {
+        let address = Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY);
+        if self.account_exists(&address) {
+            return;
+        }
+
+        self.add_account(AccountData {
+            address,
+            nonce: 0,
+            moax_balance: BigUint::default(),
+            dct: AccountDct::default(),
+            storage: HashMap::new(),
+            username: Vec::new(),
+            contract_path: None,
+            code_metadata: CodeMetadata::DEFAULT,
+            contract_owner: None,
+            developer_rewards: BigUint::default(),
+        });
+    }
+
     pub fn check_account_has_code(&self, account: &AccountData) -> bool {
         !account
             .contract_path
@@ -82,3 +108,7 @@ pub fn is_smart_contract_address(address: &Address) -> bool {
         .take(SC_ADDRESS_NUM_LEADING_ZEROS.into())
         .all(|item| item == &0u8)
 }
+
+pub fn is_dct_system_sc_address(address: &Address) -> bool {
+    address.as_bytes() == &DCT_SYSTEM_SC_ADDRESS_ARRAY[..]
+}
@@ -0,0 +1,77 @@
+use std::{collections::BTreeMap, path::Path};
+
+use denali::serde_raw::{NewAddressRaw, ScenarioRaw, StepRaw, ValueSubTree};
+
+use crate::testing_framework::{
+    account_as_raw, address_as_raw, address_to_expr, block_info_as_raw, bytes_to_expr, u64_as_raw,
+    write_scenario_raw,
+};
+
+use super::BlockchainMock;
+
+impl BlockchainMock {
+    /// Writes the entire world state as a scenario with a single `setState` step.
+    /// The output can be loaded back in other scenarios via `externalSteps`.
+    pub fn dump_state_to_file<P: AsRef<Path>>(&self, file_path: P) {
+        let file_path = file_path.as_ref();
+        let output_dir = file_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut accounts_raw = BTreeMap::new();
+        for account in self.accounts.values() {
+            let mut account_raw = account_as_raw(account);
+            account_raw.code = account
+                .contract_path
+                .as_ref()
+                .map(|code| self.contract_code_expr(code, output_dir));
+
+            let address_raw = address_to_expr(&account.address);
+            let _ = accounts_raw.insert(address_raw, account_raw);
+        }
+
+        let mut new_addresses: Vec<_> = self.new_addresses.iter().collect();
+        new_addresses.sort_by_key(|((creator, nonce), _)| (creator.as_bytes(), *nonce));
+        let new_addresses_raw = new_addresses
+            .into_iter()
+            .map(|((creator, nonce), new_address)| NewAddressRaw {
+                creator_address: address_as_raw(creator),
+                creator_nonce: u64_as_raw(*nonce),
+                new_address: address_as_raw(new_address),
+            })
+            .collect();
+
+        let scenario = ScenarioRaw {
+            check_gas: None,
+            trace_gas: None,
+            comment: None,
+            gas_schedule: None,
+            name: None,
+            steps: vec![StepRaw::SetState {
+                comment: None,
+                accounts: accounts_raw,
+                new_addresses: new_addresses_raw,
+                // token properties are part of the DCT system smart contract storage
+                token_properties: BTreeMap::new(),
+                block_hashes: Vec::new(),
+                previous_block_info: Some(block_info_as_raw(&self.previous_block_info)),
+                current_block_info: Some(block_info_as_raw(&self.current_block_info)),
+            }],
+        };
+
+        write_scenario_raw(&scenario, file_path);
+    }
+
+    /// Contracts loaded from an existing file are referenced by their path, relative to the output file.
+    /// Any other code is written as raw bytes.
+    fn contract_code_expr(&self, code: &[u8], output_dir: &Path) -> ValueSubTree {
+        if let Some(contract_file_path) = self.contract_file_paths.get(code) {
+            if !contract_file_path.is_file() {
+                return ValueSubTree::Str(bytes_to_expr(code));
+            }
+            if let Some(relative_path) = pathdiff::diff_paths(contract_file_path, output_dir) {
+                return ValueSubTree::Str(format!("file:{}", relative_path.to_str().unwrap()));
+            }
+        }
+
+        ValueSubTree::Str(bytes_to_expr(code))
+    }
+}
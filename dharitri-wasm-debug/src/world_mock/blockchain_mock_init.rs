@@ -7,6 +7,8 @@ use crate::DebugApi;
 
 use super::BlockchainMock;
 
+const FILE_PREFIX: &str = "file:";
+
 fn is_target(path_buf: &Path) -> bool {
     path_buf.file_name().unwrap() == "target"
 }
@@ -41,6 +43,10 @@ impl BlockchainMock {
             expression,
             &InterpreterContext::new(self.current_dir.clone()),
         );
+        if let Some(file_path) = expression.strip_prefix(FILE_PREFIX) {
+            self.contract_file_paths
+                .insert(contract_bytes.clone(), self.current_dir.join(file_path));
+        }
         self.contract_map
             .register_contract(contract_bytes, new_contract_closure);
     }
@@ -0,0 +1,60 @@
+use dharitri_wasm::types::Address;
+
+use crate::tx_mock::CrossShardCall;
+
+use super::{is_dct_system_sc_address, BlockchainMock};
+
+/// The system smart contracts are all in the metachain.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+impl BlockchainMock {
+    /// Distributes the accounts over several shards, the same way the real network does it,
+    /// based on the last byte of the address (e.g. `address:owner#01` is in shard 1).
+    ///
+    /// With more than one shard, async calls and transfer-execute calls between shards are no longer
+    /// executed right away. They are queued instead, and each call to `execute_cross_shard_calls`
+    /// (or `executeCrossShardCalls` step in denali) advances them by one hop,
+    /// so other transactions can run in between.
+    /// Synchronous calls between shards fail, like on the real network.
+    pub fn set_num_shards(&mut self, num_shards: u32) {
+        assert!(num_shards > 0, "the number of shards must be positive");
+        self.num_shards = num_shards;
+    }
+
+    pub fn is_multi_shard(&self) -> bool {
+        self.num_shards > 1
+    }
+
+    pub fn shard_of_address(&self, address: &Address) -> u32 {
+        if !self.is_multi_shard() {
+            return 0;
+        }
+        if is_dct_system_sc_address(address) {
+            return METACHAIN_SHARD_ID;
+        }
+
+        let num_bits = u32::BITS - (self.num_shards - 1).leading_zeros();
+        let mask_high = (1u32 << num_bits) - 1;
+        let mask_low = (1u32 << (num_bits - 1)) - 1;
+
+        let last_byte = *address.as_bytes().last().unwrap() as u32;
+        let shard = last_byte & mask_high;
+        if shard < self.num_shards {
+            shard
+        } else {
+            last_byte & mask_low
+        }
+    }
+
+    pub fn in_same_shard(&self, address_1: &Address, address_2: &Address) -> bool {
+        self.shard_of_address(address_1) == self.shard_of_address(address_2)
+    }
+
+    pub fn queue_cross_shard_call(&mut self, call: CrossShardCall) {
+        self.cross_shard_calls.push_back(call);
+    }
+
+    pub fn has_pending_cross_shard_calls(&self) -> bool {
+        !self.cross_shard_calls.is_empty()
+    }
+}
@@ -0,0 +1,71 @@
+use dharitri_wasm::types::Address;
+use std::{
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+};
+
+use crate::tx_mock::CrossShardCall;
+
+use super::{AccountData, BlockInfo, BlockchainMock};
+
+/// Copy of the world state that changes during execution:
+/// accounts (including DCT instances and the token properties kept by the DCT system smart contract),
+/// the new address registry, block info, the shard configuration and the cross-shard calls still waiting to be executed.
+/// Registered contracts, the gas schedule and the other snapshots are not part of it.
+///
+/// Accounts are shared with the world state, they only get copied when modified afterwards.
+#[derive(Clone, Debug)]
+pub struct BlockchainSnapshot {
+    pub accounts: HashMap<Address, Rc<AccountData>>,
+    pub new_addresses: HashMap<(Address, u64), Address>,
+    pub previous_block_info: BlockInfo,
+    pub current_block_info: BlockInfo,
+    pub num_shards: u32,
+    pub cross_shard_calls: VecDeque<CrossShardCall>,
+}
+
+impl BlockchainMock {
+    pub fn snapshot(&self) -> BlockchainSnapshot {
+        BlockchainSnapshot {
+            accounts: self.accounts.clone(),
+            new_addresses: self.new_addresses.clone(),
+            previous_block_info: self.previous_block_info.clone(),
+            current_block_info: self.current_block_info.clone(),
+            num_shards: self.num_shards,
+            cross_shard_calls: self.cross_shard_calls.clone(),
+        }
+    }
+
+    pub fn restore_snapshot(&mut self, snapshot: BlockchainSnapshot) {
+        self.accounts = snapshot.accounts;
+        self.new_addresses = snapshot.new_addresses;
+        self.previous_block_info = snapshot.previous_block_info;
+        self.current_block_info = snapshot.current_block_info;
+        self.num_shards = snapshot.num_shards;
+        self.cross_shard_calls = snapshot.cross_shard_calls;
+    }
+
+    /// Saves the current state under a name. An older snapshot with the same name gets overwritten.
+    ///
+    /// Saving and loading only copy the references to the accounts, not their storage and DCT balances.
+    /// An account gets copied the first time it is modified while shared with a snapshot.
+    pub fn save_snapshot(&mut self, name: &str) {
+        let snapshot = self.snapshot();
+        self.snapshots.insert(name.to_string(), snapshot);
+    }
+
+    /// Rolls back to a named snapshot. The snapshot is kept, so it can be loaded again.
+    pub fn load_snapshot(&mut self, name: &str) {
+        let snapshot = self
+            .snapshots
+            .get(name)
+            .unwrap_or_else(|| panic!("snapshot not found: {}", name))
+            .clone();
+        self.restore_snapshot(snapshot);
+    }
+
+    /// Independent copy of the blockchain mock, sharing the registered contracts.
+    pub fn fork(&self) -> BlockchainMock {
+        self.clone()
+    }
+}
@@ -9,6 +9,7 @@ pub struct DctInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: DctInstanceMetadata,
+    pub frozen: bool,
 }
 
 impl DctInstance {
@@ -17,6 +18,7 @@ impl DctInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +27,7 @@ impl DctInstance {
             nonce: 0,
             balance,
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
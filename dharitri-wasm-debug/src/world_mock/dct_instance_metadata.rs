@@ -7,6 +7,6 @@ pub struct DctInstanceMetadata {
     pub creator: Option<Address>,
     pub royalties: u64,
     pub hash: Option<Vec<u8>>,
-    pub uri: Option<Vec<u8>>,
+    pub uris: Vec<Vec<u8>>,
     pub attributes: Vec<u8>,
 }
@@ -44,6 +44,7 @@ impl DctInstances {
             nonce,
             balance: BigUint::zero(),
             metadata,
+            frozen: false,
         });
         instance.balance += value;
     }
@@ -53,6 +54,7 @@ impl DctInstances {
             nonce,
             balance: BigUint::zero(),
             metadata,
+            frozen: false,
         });
         instance.balance = value.clone();
     }
@@ -73,6 +75,10 @@ impl DctInstances {
         self.0.get_mut(&nonce)
     }
 
+    pub fn remove(&mut self, nonce: u64) -> Option<DctInstance> {
+        self.0.remove(&nonce)
+    }
+
     pub fn get_instances(&self) -> &BTreeMap<u64, DctInstance> {
         &self.0
     }
@@ -122,14 +128,12 @@ impl fmt::Display for DctInstances {
                         .unwrap_or(&Vec::new())
                         .as_slice()
                 ),
-                hex::encode(
-                    value
-                        .metadata
-                        .uri
-                        .as_ref()
-                        .unwrap_or(&Vec::new())
-                        .as_slice()
-                ),
+                value
+                    .metadata
+                    .uris
+                    .iter()
+                    .map(|uri| format!(" {}", hex::encode(uri.as_slice())))
+                    .collect::<String>(),
                 hex::encode(value.metadata.attributes.as_slice())
             )?;
         }
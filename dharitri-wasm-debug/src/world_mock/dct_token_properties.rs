@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use dharitri_wasm::{dct::DCT_SYSTEM_SC_ADDRESS_ARRAY, types::Address};
+
+use super::BlockchainMock;
+
+const IS_PAUSED_FIELD: &[u8] = b"isPaused";
+const CAN_FREEZE_FIELD: &[u8] = b"canFreeze";
+const CAN_WIPE_FIELD: &[u8] = b"canWipe";
+const LIMITED_TRANSFER_FIELD: &[u8] = b"limitedTransfer";
+
+const TRUE_BYTES: &[u8] = b"true";
+const FALSE_BYTES: &[u8] = b"false";
+
+/// Token-level settings, shared by all the accounts holding the token.
+///
+/// They live in the storage of the DCT system smart contract, next to the rest of the token info,
+/// under keys of the form `<token identifier>.<field name>`.
+/// This way the properties set when issuing a token are the same ones that get enforced.
+///
+/// Tokens without properties are not restricted in any way.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct DctTokenProperties {
+    /// No transfers are allowed while the token is paused.
+    pub paused: bool,
+    /// Required by the `DCTFreeze` and `DCTUnFreeze` builtin functions.
+    pub can_freeze: bool,
+    /// Required by the `DCTWipe` builtin function.
+    pub can_wipe: bool,
+    /// Only transfers where the sender or the receiver has the transfer role are allowed.
+    pub limited_transfer: bool,
+}
+
+/// Storage key of a token field, in the DCT system smart contract.
+pub fn dct_system_sc_token_field_key(token_identifier: &[u8], field_name: &[u8]) -> Vec<u8> {
+    [token_identifier, b".", field_name].concat()
+}
+
+impl DctTokenProperties {
+    const FIELDS: [&'static [u8]; 4] = [
+        IS_PAUSED_FIELD,
+        CAN_FREEZE_FIELD,
+        CAN_WIPE_FIELD,
+        LIMITED_TRANSFER_FIELD,
+    ];
+
+    /// Reads the properties from the storage of the DCT system smart contract.
+    /// Yields `None` if none of them is set for the token.
+    pub fn load_from_system_sc_storage(
+        storage: &HashMap<Vec<u8>, Vec<u8>>,
+        token_identifier: &[u8],
+    ) -> Option<Self> {
+        let field_value = |field_name: &[u8]| {
+            storage.get(&dct_system_sc_token_field_key(token_identifier, field_name))
+        };
+        if Self::FIELDS
+            .iter()
+            .all(|field_name| field_value(field_name).is_none())
+        {
+            return None;
+        }
+
+        let is_set = |field_name: &[u8]| {
+            field_value(field_name)
+                .map(|value| value.as_slice() == TRUE_BYTES)
+                .unwrap_or_default()
+        };
+        Some(DctTokenProperties {
+            paused: is_set(IS_PAUSED_FIELD),
+            can_freeze: is_set(CAN_FREEZE_FIELD),
+            can_wipe: is_set(CAN_WIPE_FIELD),
+            limited_transfer: is_set(LIMITED_TRANSFER_FIELD),
+        })
+    }
+
+    pub fn store_in_system_sc_storage(
+        &self,
+        storage: &mut HashMap<Vec<u8>, Vec<u8>>,
+        token_identifier: &[u8],
+    ) {
+        let values = [
+            self.paused,
+            self.can_freeze,
+            self.can_wipe,
+            self.limited_transfer,
+        ];
+        for (field_name, value) in Self::FIELDS.iter().zip(values.iter()) {
+            let value_bytes: &[u8] = if *value { TRUE_BYTES } else { FALSE_BYTES };
+            storage.insert(
+                dct_system_sc_token_field_key(token_identifier, field_name),
+                value_bytes.to_vec(),
+            );
+        }
+    }
+}
+
+impl BlockchainMock {
+    pub fn set_dct_token_properties(
+        &mut self,
+        token_identifier: &[u8],
+        properties: DctTokenProperties,
+    ) {
+        self.create_dct_system_sc_account_if_missing();
+        let system_sc_account = self
+            .account_mut(&Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY))
+            .unwrap();
+        properties.store_in_system_sc_storage(&mut system_sc_account.storage, token_identifier);
+    }
+
+    pub fn has_dct_token_properties(&self, token_identifier: &[u8]) -> bool {
+        self.load_dct_token_properties(token_identifier).is_some()
+    }
+
+    /// Will provide a clone.
+    pub fn get_dct_token_properties(&self, token_identifier: &[u8]) -> DctTokenProperties {
+        self.load_dct_token_properties(token_identifier)
+            .unwrap_or_default()
+    }
+
+    fn load_dct_token_properties(&self, token_identifier: &[u8]) -> Option<DctTokenProperties> {
+        self.accounts
+            .get(&Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY))
+            .and_then(|system_sc_account| {
+                DctTokenProperties::load_from_system_sc_storage(
+                    &system_sc_account.storage,
+                    token_identifier,
+                )
+            })
+    }
+}
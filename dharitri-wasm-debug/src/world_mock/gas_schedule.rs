@@ -0,0 +1,128 @@
+use std::{collections::HashMap, convert::TryFrom, path::Path};
+
+pub const BASE_OPERATION_COST: &str = "BaseOperationCost";
+pub const BUILT_IN_COST: &str = "BuiltInCost";
+pub const DHARITRI_API_COST: &str = "DharitriAPICost";
+pub const BIG_INT_API_COST: &str = "BigIntAPICost";
+pub const MANAGED_BUFFER_API_COST: &str = "ManagedBufferAPICost";
+pub const CRYPTO_API_COST: &str = "CryptoAPICost";
+
+const DUMMY_ASYNC_CALLBACK_GAS_LOCK: u64 = 100_000;
+
+/// Gas costs charged by the debug VM.
+///
+/// Costs are grouped in sections, same as in the node gas schedule files,
+/// e.g. `[DharitriAPICost]` / `GetCaller = 100`.
+/// Operations missing from the schedule cost `default_cost`.
+#[derive(Clone, Debug, Default)]
+pub struct GasSchedule {
+    sections: HashMap<String, HashMap<String, u64>>,
+    default_cost: u64,
+}
+
+impl GasSchedule {
+    /// All operations are free, gas is not metered. This is the default.
+    pub fn zero() -> Self {
+        GasSchedule::default()
+    }
+
+    /// Every operation costs 1. Corresponds to the `"dummy"` denali gas schedule.
+    ///
+    /// The only exception is the gas locked for async callbacks,
+    /// which needs to be large enough for the callback to run even if the async call fails.
+    pub fn dummy() -> Self {
+        let mut gas_schedule = GasSchedule {
+            sections: HashMap::new(),
+            default_cost: 1,
+        };
+        gas_schedule.set_cost(
+            DHARITRI_API_COST,
+            "AsyncCallbackGasLock",
+            DUMMY_ASYNC_CALLBACK_GAS_LOCK,
+        );
+        gas_schedule
+    }
+
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, String> {
+        let value: toml::Value = toml::from_str(toml_str).map_err(|err| err.to_string())?;
+        let sections = value
+            .as_table()
+            .ok_or_else(|| "gas schedule must be a table of sections".to_string())?;
+
+        let mut gas_schedule = GasSchedule::zero();
+        for (section_name, section) in sections.iter() {
+            if let Some(section_table) = section.as_table() {
+                for (op_name, cost) in section_table.iter() {
+                    if let Some(cost) = cost.as_integer() {
+                        let cost = u64::try_from(cost)
+                            .map_err(|_| format!("negative gas cost {} for {}", cost, op_name))?;
+                        gas_schedule.set_cost(section_name, op_name, cost);
+                    }
+                }
+            }
+        }
+        Ok(gas_schedule)
+    }
+
+    pub fn from_json_str(json_str: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(json_str).map_err(|err| err.to_string())?;
+        let sections = value
+            .as_object()
+            .ok_or_else(|| "gas schedule must be an object of sections".to_string())?;
+
+        let mut gas_schedule = GasSchedule::zero();
+        for (section_name, section) in sections.iter() {
+            if let Some(section_object) = section.as_object() {
+                for (op_name, cost) in section_object.iter() {
+                    if cost.is_number() {
+                        let cost = cost.as_u64().ok_or_else(|| {
+                            format!(
+                                "gas cost {} for {} is not a non-negative integer",
+                                cost, op_name
+                            )
+                        })?;
+                        gas_schedule.set_cost(section_name, op_name, cost);
+                    }
+                }
+            }
+        }
+        Ok(gas_schedule)
+    }
+
+    /// Loads a gas schedule file. The format is deduced from the extension: `.json`, or TOML otherwise.
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("error reading gas schedule {:?}: {}", path, err));
+        let result = match path.extension() {
+            Some(ext) if ext == "json" => GasSchedule::from_json_str(contents.as_str()),
+            _ => GasSchedule::from_toml_str(contents.as_str()),
+        };
+        result.unwrap_or_else(|err| panic!("error parsing gas schedule {:?}: {}", path, err))
+    }
+
+    pub fn set_cost(&mut self, section: &str, op_name: &str, cost: u64) {
+        self.sections
+            .entry(section.to_string())
+            .or_insert_with(HashMap::new)
+            .insert(op_name.to_string(), cost);
+    }
+
+    pub fn get_cost(&self, section: &str, op_name: &str) -> u64 {
+        self.sections
+            .get(section)
+            .and_then(|section_costs| section_costs.get(op_name))
+            .copied()
+            .unwrap_or(self.default_cost)
+    }
+
+    /// False if all operations are free, in which case gas checks are meaningless.
+    pub fn is_metered(&self) -> bool {
+        self.default_cost > 0
+            || self
+                .sections
+                .values()
+                .any(|section_costs| section_costs.values().any(|cost| *cost > 0))
+    }
+}
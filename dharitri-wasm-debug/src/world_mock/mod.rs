@@ -3,20 +3,28 @@ mod account_dct;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
+mod blockchain_mock_dump;
 mod blockchain_mock_init;
+mod blockchain_mock_shards;
+mod blockchain_mock_snapshot;
 mod blockchain_tx_info;
 mod dct_instance;
 mod dct_instance_metadata;
 mod dct_instances;
 mod dct_roles;
+mod dct_token_properties;
+mod gas_schedule;
 
 pub use account_data::*;
 pub use account_dct::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
-pub use blockchain_mock_account_util::is_smart_contract_address;
+pub use blockchain_mock_snapshot::*;
+pub use blockchain_mock_account_util::{is_dct_system_sc_address, is_smart_contract_address};
 pub use blockchain_tx_info::*;
 pub use dct_instance::*;
 pub use dct_instance_metadata::*;
 pub use dct_instances::*;
 pub use dct_roles::*;
+pub use dct_token_properties::*;
+pub use gas_schedule::*;
@@ -0,0 +1,21 @@
+use dharitri_wasm::types::Address;
+use dharitri_wasm_debug::{address_bech32, bech32};
+
+const DCT_SYSTEM_SC_BECH32: &str = "moa1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls29jpxv";
+
+#[test]
+fn test_bech32_encode_decode() {
+    let address = Address::from(dharitri_wasm::dct::DCT_SYSTEM_SC_ADDRESS_ARRAY);
+    assert_eq!(bech32::encode(&address), DCT_SYSTEM_SC_BECH32);
+    assert_eq!(address_bech32(&address), DCT_SYSTEM_SC_BECH32);
+    assert_eq!(bech32::decode(DCT_SYSTEM_SC_BECH32), address);
+
+    let zero = Address::zero();
+    assert_eq!(bech32::decode(bech32::encode(&zero).as_str()), zero);
+}
+
+#[test]
+#[should_panic]
+fn test_bech32_decode_invalid() {
+    let _ = bech32::decode("moa1invalid");
+}
@@ -0,0 +1,63 @@
+use dharitri_wasm::types::{Address, CodeMetadata};
+use dharitri_wasm_debug::world_mock::{AccountData, AccountDct, BlockchainMock};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::{collections::HashMap, rc::Rc};
+
+fn account(address: &Address, moax_balance: u64) -> AccountData {
+    AccountData {
+        address: address.clone(),
+        nonce: 0,
+        moax_balance: BigUint::from(moax_balance),
+        dct: AccountDct::default(),
+        username: Vec::new(),
+        storage: HashMap::new(),
+        contract_path: None,
+        code_metadata: CodeMetadata::DEFAULT,
+        contract_owner: None,
+        developer_rewards: BigUint::zero(),
+    }
+}
+
+#[test]
+fn test_snapshot_shares_unmodified_accounts() {
+    let first = Address::from([1u8; 32]);
+    let second = Address::from([2u8; 32]);
+    let mut world = BlockchainMock::new();
+    world.add_account(account(&first, 100));
+    world.add_account(account(&second, 200));
+
+    world.save_snapshot("start");
+    let snapshot = &world.snapshots["start"];
+    assert!(Rc::ptr_eq(
+        &world.accounts[&first],
+        &snapshot.accounts[&first]
+    ));
+    assert!(Rc::ptr_eq(
+        &world.accounts[&second],
+        &snapshot.accounts[&second]
+    ));
+
+    // only the modified account gets copied
+    world.account_mut(&first).unwrap().moax_balance = BigUint::from(50u32);
+    let snapshot = &world.snapshots["start"];
+    assert!(!Rc::ptr_eq(
+        &world.accounts[&first],
+        &snapshot.accounts[&first]
+    ));
+    assert!(Rc::ptr_eq(
+        &world.accounts[&second],
+        &snapshot.accounts[&second]
+    ));
+    assert_eq!(
+        snapshot.accounts[&first].moax_balance,
+        BigUint::from(100u32)
+    );
+
+    world.load_snapshot("start");
+    assert_eq!(world.accounts[&first].moax_balance, BigUint::from(100u32));
+    assert!(Rc::ptr_eq(
+        &world.accounts[&first],
+        &world.snapshots["start"].accounts[&first]
+    ));
+}
@@ -0,0 +1,452 @@
+use dharitri_wasm::types::MessageHashType;
+use dharitri_wasm_debug::crypto::*;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Produced by the BLS implementation of the node.
+struct BlsVector {
+    secret_key: &'static str,
+    public_key: &'static str,
+    message: &'static str,
+    signature: &'static str,
+}
+
+const BLS_VECTORS: &[BlsVector] = &[
+    BlsVector {
+        secret_key: "251760cf8f5ef11c6931586d8d6fbc5f2dbee6c3c6df4b05cbd8f2c211704b29",
+        public_key: "401e276f7a5bed1c0b6d56979dc1ce31c83722c1d9f5457bb0a27dfe111c42e633ac82c8d28f5749b70d8f9378e8b70b0217a4915809b163bcf70e537e97832d5aa1e6d925a87fec4ac3b90ffa5e54807060256ebcddaabb21452954c35bb413",
+        message: "",
+        signature: "f420d8fcb8e0455214fbd1993b809cf20f6bbfe276985057d57c3f57ca2efbabf4708d1264ef3a881d60e8e64a256413",
+    },
+    BlsVector {
+        secret_key: "be4fac2c5a5d4ce7541b5ccdef0e443747de4eb113ae7f2ce3c922d031387a10",
+        public_key: "9f9acb7abe398bef5931c51eb39a366799d4a368c6d72580808dff63aada66449d213fcdac0e89540b3e54d9b8f8ae035c5c91bc0da6e679b530966d05fc4860d28a501d9c0219fe5b8ebab02f04b44e9905dffa55ed276c145631e52e5fe010",
+        message: "1f30415263748596a7",
+        signature: "051c4255bde92542b9f64c0fa584d2f8cf3cef4146358762e9a8c7c5593d853c3395fb40531edf51b7fb1a3b67643c19",
+    },
+    BlsVector {
+        secret_key: "03521af6a412cc4b5151816ad3721060eb4945c4c53fedcb594d9faec9ffa049",
+        public_key: "20fad2d8c323b0c97295176a5e90ee70d4e45853bd6e8a7502c29d49d6abd452ab6d75cdd43bd4ea01878da58507b3074cc19d8015ab5d15ed6074518d3fd22379156747fdb85655f70c4063490a961dfcf8deafdc4078df153769d09951b989",
+        message: "3e4f60718293a4b5c6d7e8f90a1b2c3d4e5f",
+        signature: "9a101af0ab22ad58d7cd1e3f4fd5ad56efa831e82047b5ce391cff2a8a51c333239f616293a2f1dae9dcd9bc97199083",
+    },
+    BlsVector {
+        secret_key: "fca7ece22e1efecc61836dc128e49a3bdc92d9811ce468683e1c917eca405c55",
+        public_key: "d5f0718a729e08ae60f00976077c8e5e05e661ebe77e2258e5c9c11b4e4847efbb1cae4514ae525f38f2bdbb5fe55916b64e37572364113fd818347e46b1729faf445c949afbed03c129544584373be66dcfcf5f62ae6819bac1ad343c229882",
+        message: "5d6e7f90a1b2c3d4e5f60718293a4b5c6d7e8fa0b1c2d3e4f50617",
+        signature: "0353811890d97366a4001d18af4ef4fa5f25f5e11afcc4b8edfc41fbbe8b9fa90f1c3a58d99aa884ac83f11043460002",
+    },
+    BlsVector {
+        secret_key: "579402633888ca4d265a7ff38ebcc46d725ae5cccae20939d92018f6dbc85924",
+        public_key: "3ac5598c75e7ac06b4d5f9e6b2032254c264787882b042c9b525ac591b3898f651af4d83eb00ebd941e5956ac0a9690f0cec5207dbb343dbea321defc4c2e9eaa41eabb6e5383159221fd6e70292f098e21927f0879fa555128feb75326a9b96",
+        message: "7c8d9eafc0d1e2f30415263748596a7b8c9daebfd0e1f2031425364758697a8b9cadbecf",
+        signature: "5c12e61f0b191bfa2b2a28e7d0d1c8ef32c97e11697c24702da7cda59ac6754041e1f3888eec6808980dbe9563400609",
+    },
+    BlsVector {
+        secret_key: "f9daa17a9446a816444e10a4e2fd348bd6b4bc156280bd30309bed8124157772",
+        public_key: "5abf9c261b7672ddac49b29aeabf24f34854172f57dfce4f49af466087d98e6b1b22650c195448d8147fdc65fc222f07f84b890f71cc7f9615ccd1afa9652337c69d4e72b9abd444a9785889b848b8b0729617e3ed3d3a139be6bc227c460799",
+        message: "9bacbdcedff00112233445566778899aabbccddeef00112233445566778899aabbccddeeff1021324354657687",
+        signature: "4e2019240316076dce764cef3194af58b14efe64c792937c31685b11a805a6a843fbf88c0211276f94410b902fa5798f",
+    },
+];
+
+#[test]
+fn test_bls_node_vectors() {
+    for vector in BLS_VECTORS {
+        let secret_key = hex::decode(vector.secret_key).unwrap();
+        let public_key = hex::decode(vector.public_key).unwrap();
+        let message = hex::decode(vector.message).unwrap();
+        let signature = hex::decode(vector.signature).unwrap();
+
+        assert_eq!(bls_public_key(&secret_key).unwrap(), public_key);
+        assert_eq!(sign_bls(&secret_key, &message).unwrap(), signature);
+        assert!(verify_bls(&public_key, &message, &signature));
+        assert!(!verify_bls(&public_key, b"another message", &signature));
+    }
+}
+
+#[test]
+fn test_bls_invalid_inputs() {
+    let vector = &BLS_VECTORS[1];
+    let public_key = hex::decode(vector.public_key).unwrap();
+    let message = hex::decode(vector.message).unwrap();
+    let mut signature = hex::decode(vector.signature).unwrap();
+
+    assert!(!verify_bls(&public_key[1..], &message, &signature));
+    assert!(!verify_bls(&public_key, &message, &signature[1..]));
+    assert!(!verify_bls(
+        &public_key,
+        &message,
+        &[0u8; BLS_SIGNATURE_LENGTH]
+    ));
+
+    // flipping the sign of y yields a valid point, but the wrong signature
+    signature[BLS_SIGNATURE_LENGTH - 1] ^= 0x80;
+    assert!(!verify_bls(&public_key, &message, &signature));
+}
+
+#[test]
+fn test_ed25519_rfc8032_vector() {
+    let secret_key =
+        hex::decode("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb").unwrap();
+    let public_key =
+        hex::decode("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c").unwrap();
+    let message = [0x72u8];
+    let signature = hex::decode("92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00").unwrap();
+
+    assert_eq!(ed25519_public_key(&secret_key).unwrap(), public_key);
+    assert_eq!(sign_ed25519(&secret_key, &message).unwrap(), signature);
+    assert!(verify_ed25519(&public_key, &message, &signature));
+    assert!(!verify_ed25519(&public_key, b"another message", &signature));
+    assert!(!verify_ed25519(&public_key[1..], &message, &signature));
+}
+
+#[test]
+fn test_secp256k1_sign_and_verify() {
+    let secret_key = [7u8; SECP256K1_SECRET_KEY_LENGTH];
+    let public_key = secp256k1_public_key(&secret_key).unwrap();
+
+    for hash_type in [
+        MessageHashType::ECDSAPlainMsg,
+        MessageHashType::ECDSASha256,
+        MessageHashType::ECDSADoubleSha256,
+        MessageHashType::ECDSAKeccak256,
+        MessageHashType::ECDSARipemd160,
+    ] {
+        let signature = sign_secp256k1(&secret_key, b"message to sign", hash_type.clone()).unwrap();
+        assert!(verify_secp256k1(
+            &public_key,
+            b"message to sign",
+            &signature,
+            hash_type.clone()
+        ));
+        assert!(!verify_secp256k1(
+            &public_key,
+            b"another message",
+            &signature,
+            hash_type
+        ));
+    }
+
+    assert!(secp256k1_public_key(&[0u8; SECP256K1_SECRET_KEY_LENGTH]).is_none());
+}
+
+const SECP256K1_SECRET_KEY: &str =
+    "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+const SECP256K1_PUBLIC_KEY: &str = "044e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956de";
+const SECP256K1_PUBLIC_KEY_COMPRESSED: &str =
+    "024e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e";
+
+/// Signed with OpenSSL, from the message hashed as indicated by the hash type.
+/// OpenSSL does not normalize `s`, so there is a signature with a low and one with a high `s` for each hash type,
+/// both accepted by the node.
+struct Secp256k1Vector {
+    hash_type: MessageHashType,
+    message: &'static str,
+    signature: &'static str,
+}
+
+const SECP256K1_VECTORS: &[Secp256k1Vector] = &[
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSAPlainMsg,
+        message: "9e6e5e1a7a2f3f2a6ab4d0ef7fa9d7c71fd2e8a6c0d90a3d1bb0c4ba7d2c8e11",
+        signature: "304402204fbff1c246b9206205478d8e404b6a78dd740ded1e9f162d01ab4370306fbee102204bc383264773bcf38bc354387ec94115241608d69fa899f5e5e8b89bb38cfd04",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSAPlainMsg,
+        message: "9e6e5e1a7a2f3f2a6ab4d0ef7fa9d7c71fd2e8a6c0d90a3d1bb0c4ba7d2c8e11",
+        signature: "3046022100b9fb08af0b4e4ae20849f78f0e3a194282d6791bfd891f47f98e04c54eaaf0a00221009f8ca5c817eca7bd46dc2d869c71d35d1c402cea83407cec18225da2fc6cfb55",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSASha256,
+        message: "6d657373616765207369676e6564207769746820736861323536",
+        signature: "3045022100bb9d57604a0b8e2cf9553eed1fbd262711a5f7abbbbc918adb07c0d5440846d9022019390ef1d92cf22cdde88f20515084cf668716ac3579f6955f9f80c30d9bbd86",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSASha256,
+        message: "6d657373616765207369676e6564207769746820736861323536",
+        signature: "3046022100f50e5a32891aae7fd1c833624853113ffcfd5c3dc2dcf046e8999d906701d3d30221008db4f97f0178c208a58ad82585e16e02826725cd6d8e19f6d12535fc32979d08",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSADoubleSha256,
+        message: "6d657373616765207369676e6564207769746820646f75626c6520736861323536",
+        signature: "304402205a8cf7aca0c96a393fd09333347415da7d5ccab114204cb3e09892a65df6b347022012e69d6a892e82bfc297e98343eb3953d178f69531087b2b1d7194753d74fca7",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSADoubleSha256,
+        message: "6d657373616765207369676e6564207769746820646f75626c6520736861323536",
+        signature: "304502201a972be1dfae5dbed5389f289d54d21e5af2e5c0e4d414b284640bf5baf54565022100835d215e57bccf68374fea7d0f2e28873799b6e932634f2af5775965a75a13ad",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSAKeccak256,
+        message: "6d657373616765207369676e65642077697468206b656363616b323536",
+        signature: "3044022044febb1c60249b9efa5995a6ee7c437cc8c307cae65f3aacf75864375941445302206afbf4d16707da41c5dfdd4ebca687534d97d295c1ae9e2d7161d4f47ce5341b",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSAKeccak256,
+        message: "6d657373616765207369676e65642077697468206b656363616b323536",
+        signature: "3046022100edf3b6ad27f4350139647eff5da54e3a4c69741fe563b02ca9af991739baab69022100b9eb7389aed0f07b3f1cdbefb892dbbcdfe01a7da3b36093fc018d17229d5652",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSARipemd160,
+        message: "6d657373616765207369676e6564207769746820726970656d64313630",
+        signature: "3045022100f4b9f8bf8b8b0f3b2759e4bcd4604b658afe304aed0297a4b89fa4e779a7fd8c022025fb754db8f8de01a8a735498c24f9780d205d45d68ec91822fdf04d526ec0fa",
+    },
+    Secp256k1Vector {
+        hash_type: MessageHashType::ECDSARipemd160,
+        message: "6d657373616765207369676e6564207769746820726970656d64313630",
+        signature: "3046022100fcbffc7b1c008ed3ed5fbe9548fe3da4032d6239042984bae8f37adec1df06eb022100aea202a7d1efa9c6123c2190545915f963f48446d0c800ae4951fcf30d29f7d9",
+    },
+];
+
+const ALL_MESSAGE_HASH_TYPES: [MessageHashType; 5] = [
+    MessageHashType::ECDSAPlainMsg,
+    MessageHashType::ECDSASha256,
+    MessageHashType::ECDSADoubleSha256,
+    MessageHashType::ECDSAKeccak256,
+    MessageHashType::ECDSARipemd160,
+];
+
+#[test]
+fn test_secp256k1_openssl_vectors() {
+    let public_key = hex::decode(SECP256K1_PUBLIC_KEY).unwrap();
+    let public_key_compressed = hex::decode(SECP256K1_PUBLIC_KEY_COMPRESSED).unwrap();
+    for vector in SECP256K1_VECTORS {
+        let message = hex::decode(vector.message).unwrap();
+        let signature = hex::decode(vector.signature).unwrap();
+
+        for key in [&public_key, &public_key_compressed] {
+            for hash_type in ALL_MESSAGE_HASH_TYPES {
+                // only the hash the message was signed with verifies
+                assert_eq!(
+                    verify_secp256k1(key, &message, &signature, hash_type.clone()),
+                    hash_type == vector.hash_type
+                );
+            }
+        }
+
+        let mut other_message = message.clone();
+        other_message[0] ^= 1;
+        assert!(!verify_secp256k1(
+            &public_key,
+            &other_message,
+            &signature,
+            vector.hash_type.clone()
+        ));
+    }
+}
+
+/// Produced by OpenSSL with deterministic nonces (RFC 6979), then normalized to low `s`, like libsecp256k1 does.
+#[test]
+fn test_sign_secp256k1_openssl_deterministic_vectors() {
+    let secret_key = hex::decode(SECP256K1_SECRET_KEY).unwrap();
+    assert_eq!(
+        hex::encode(secp256k1_public_key(&secret_key).unwrap()),
+        SECP256K1_PUBLIC_KEY_COMPRESSED
+    );
+
+    let vectors = [
+        (
+            MessageHashType::ECDSAPlainMsg,
+            "9e6e5e1a7a2f3f2a6ab4d0ef7fa9d7c71fd2e8a6c0d90a3d1bb0c4ba7d2c8e11",
+            "304402204efecb894ca7cddc53bbca39bb0c8f6ddd11fb95df397a54685c1c023a99264702205164d9387bc82022dd1c97438a7f908609ac4f95f7543bb5f2d29a6b9363a0b7",
+        ),
+        (
+            MessageHashType::ECDSASha256,
+            "6d657373616765207369676e6564207769746820736861323536",
+            "3044022026b0ae2c001e6795c9a75370405fb111a795546c0d2594591259702862ccbe140220213712ce2fee36356f46fcc007d3b7dbb7b60079f289b39eefc6eca95cba41c4",
+        ),
+        (
+            MessageHashType::ECDSADoubleSha256,
+            "6d657373616765207369676e6564207769746820646f75626c6520736861323536",
+            "30440220012bbccfe0a0e8f04929c93d402a95071268ab779ecc1769951f7706c639163102200c0c128c7a480e3d1dd908da05bbe425edf18c0c5d5168b4c3ff056fd2228b01",
+        ),
+        (
+            MessageHashType::ECDSAKeccak256,
+            "6d657373616765207369676e65642077697468206b656363616b323536",
+            "3045022100f1240c447a0cd8db5a4a185f96911f9b8c51ff21a4b18ab124635565ceb841a802201f4a2d59eeb5f5432c7667ee9a311b10608186833469a54f654bc9f1c057827c",
+        ),
+    ];
+    for (hash_type, message, signature) in vectors {
+        let message = hex::decode(message).unwrap();
+        assert_eq!(
+            hex::encode(sign_secp256k1(&secret_key, &message, hash_type).unwrap()),
+            signature
+        );
+    }
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature() {
+    let r =
+        hex::decode("90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998").unwrap();
+    let s =
+        hex::decode("4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93").unwrap();
+    assert_eq!(
+        hex::encode(encode_secp256k1_der_signature(&r, &s)),
+        "304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93"
+    );
+
+    // high s values are converted to their low form, like the node does
+    let high_s =
+        hex::decode("b596eec652a85c0f46f99c898c55d09ae8b9803508b70667bf337469018864ae").unwrap();
+    assert_eq!(
+        encode_secp256k1_der_signature(&r, &high_s),
+        encode_secp256k1_der_signature(&r, &s)
+    );
+}
+
+#[test]
+fn test_elliptic_curve_group_laws() {
+    for name in [&b"p224"[..], b"p256", b"p384", b"p521"] {
+        let ec = EllipticCurve::from_name(name).unwrap();
+        let (gx, gy) = (ec.x_base_point.clone(), ec.y_base_point.clone());
+        assert!(ec.is_on_curve(&gx, &gy));
+
+        let (double_x, double_y) = ec.double(&gx, &gy);
+        assert_eq!(
+            ec.add(&gx, &gy, &gx, &gy),
+            (double_x.clone(), double_y.clone())
+        );
+        assert_eq!(
+            ec.scalar_base_mult(&[2]),
+            (double_x.clone(), double_y.clone())
+        );
+
+        let (triple_x, triple_y) = ec.add(&double_x, &double_y, &gx, &gy);
+        assert!(ec.is_on_curve(&triple_x, &triple_y));
+        assert_eq!(ec.scalar_base_mult(&[3]), (triple_x, triple_y));
+
+        let infinity = (BigUint::zero(), BigUint::zero());
+        assert_eq!(ec.scalar_base_mult(&[]), infinity);
+        assert_eq!(
+            ec.scalar_base_mult(&ec.base_point_order.to_bytes_be()),
+            infinity
+        );
+        let minus_gy = &ec.field_order - &gy;
+        assert_eq!(ec.add(&gx, &gy, &gx, &minus_gy), infinity);
+    }
+}
+
+#[test]
+fn test_elliptic_curve_marshal_roundtrip() {
+    for name in [&b"p224"[..], b"p256", b"p384", b"p521"] {
+        let ec = EllipticCurve::from_name(name).unwrap();
+        for scalar in [&[1u8][..], &[2], &[7, 0, 255], &[0xf9, 0x3e, 0x4a, 0xe4]] {
+            let (x, y) = ec.scalar_base_mult(scalar);
+
+            let marshaled = ec.marshal(&x, &y);
+            assert_eq!(marshaled.len(), ec.marshal_byte_length());
+            assert_eq!(ec.unmarshal(&marshaled), Some((x.clone(), y.clone())));
+
+            let compressed = ec.marshal_compressed(&x, &y);
+            assert_eq!(compressed.len(), ec.marshal_compressed_byte_length());
+            assert_eq!(ec.unmarshal_compressed(&compressed), Some((x, y)));
+        }
+    }
+}
+
+/// Points are multiples of the base point, computed with OpenSSL from the scalars below.
+struct EllipticCurveVector {
+    curve: &'static [u8],
+    /// `k * G`
+    point: &'static str,
+    point_compressed: &'static str,
+    /// `l * G`
+    other_point: &'static str,
+    /// `(k + l) * G`
+    sum: &'static str,
+    /// `2k * G`
+    double: &'static str,
+    /// `m * k * G`
+    product: &'static str,
+}
+
+const EC_SCALAR_K: &str = "5d2a9bd1c3f0e8a64b7c2e19f08d3a6b5c4e7f1a2b3c4d5e";
+const EC_SCALAR_L: &str = "1f3e5d7c9bab8a796857463524130201f0e1d2c3b4a59687";
+const EC_SCALAR_M: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90";
+
+const ELLIPTIC_CURVE_VECTORS: &[EllipticCurveVector] = &[
+    EllipticCurveVector {
+        curve: b"p224",
+        point: "04379a21f8c6e700f696b9ff3a60db43b6ef99b7d18f3f243ed1103e13120dfa8b30a8a3b530cb432943d88fe371a4cb41bc55d35baaaad92e",
+        point_compressed: "02379a21f8c6e700f696b9ff3a60db43b6ef99b7d18f3f243ed1103e13",
+        other_point: "0436b3ab08e0f74e48a8e2eb0b20d1f657a5e356b56fde2e3f877553ae9be8f08d30730c5983be2ddab1f6363646eb32bc40aed85bfa025471",
+        sum: "04e822289978bb6d9cae39ae209233cd40edc3c758c9484fcc8160f17e8ebc85abe64d16623fa4ec78b410f99ccdeec452b3a048726ba1cffd",
+        double: "046a7b6d7d051b9373eb3904060ab359ab0b2ed0187c22f0f010fc0d4e58d81435282f49a4ffa77dfcf5975efee72c51c6f412bc3fdfb7ea00",
+        product: "046f0fe003e28c8594c0c0bffd3f52f2fda2bdc323937d2865c768146d3dac23201ee8ee7ca346d2489b99320f4f611b11d13e2618e19f5972",
+    },
+    EllipticCurveVector {
+        curve: b"p256",
+        point: "046601d53512d7187d1fb216356b8875c94f52ba2936b88845e57d86ba467cd97949f35112af750b928a4917072f046c403b3717b0ea692f8225bf4d4f3bfa2e98",
+        point_compressed: "026601d53512d7187d1fb216356b8875c94f52ba2936b88845e57d86ba467cd979",
+        other_point: "04075ee06f1b8068ac178f08b777382c93c6bc5254eb18350bdbe79a1e16b97f2c19d6e99520c744b60ef0d20e352c19476a4417e7e4999cbd11f22db83ec87712",
+        sum: "04d93043a3f81cae07cef5a08987bb52fd32986689750606bb5c32a8040171419343e9a6f315a96fd3bbceb9a46f0996a265b7772bf1629bafb82dabfaf38cb0f4",
+        double: "04e188c8ea2c1bdad2495b77230b5e43d52ac352dda6a5631e78a4a7a0454b6b6aa80d211e7a9ed6299f19c7f69c2cd542ccb62d34a36245a633f22ea478141237",
+        product: "04e6604ccc2281b8e3023a6aea7b11493187c69d6ef8ea9c8b6c60e66d1d010cc0e504f6f26fb943084fc27e8d90da4a69ee271fe85f7a064d52f42445982c2c4c",
+    },
+    EllipticCurveVector {
+        curve: b"p384",
+        point: "04408de28b4cb4951c984c25b095f205f2c439ec123d69cda46467eaaa525e787a1dc38432bf38787152b026d9b1e90c4df9fee2876548b64f413fe9a4f32df73bb2f666e2b94e9d399dcdac625d1b4bd08daabdf71f396d859d07fae9359e1304",
+        point_compressed: "02408de28b4cb4951c984c25b095f205f2c439ec123d69cda46467eaaa525e787a1dc38432bf38787152b026d9b1e90c4d",
+        other_point: "0494f11d37697f8157bb109f072c0aa0b1b216b33ce5d22330b38d7b14fc115b09d62de82cad36b4aad7a3fb697ad624ed7f0ad3bb1cbc917902d05cc7278b4ef415304c9a767e898250e391e2b77cedb6fb5f41e47745a53db7061a795a6f50b5",
+        sum: "0401d21a58ef2781d08d61bf48ea1e56ae98f869edc6a615e1f61b8d308741849cff52cdd54588b8626041674f12411828093f0d172ba8057e3f611628940542dc2b11c67e61dc313a3a9eb7864bdfdc18d2fb93d04c70c227d12386b2c39848c1",
+        double: "04f7025c8920e47e15249dc79a34fcb96c2752dde83a42dc51124c05a14aed436d2035867d843344c44f07a1c5ac2b251f0b442542d4311df59abac8d2240746c75a80a860035855565f182e37f2b15d8459ed1040c64cccecf7470ff3298ef082",
+        product: "04655af5a1d7e16909e92828a7eae1e81ddc020b2df3bc8d3f0d7d2dfd3e0f01a70c8519ee31ae293fb42fe0292f1683fb378d2579af469723878fccaf7cf41192080b207dfabcde00fdf978a7a83eb14c3a93dcb27874c05d7c8120c2744a7402",
+    },
+    EllipticCurveVector {
+        curve: b"p521",
+        point: "04015024f6d020b18dff2583005e422e2f92df29b6d8fa24d4dd9b8654146305282bf817d5585d2b3c1db53dfe0bebbb45ee579d43cfb6c0ddd93335f5cbeb917b967c001bd5d019afb4e5d14b103a816fe594efb52e88f343bef49a43491b04f681a62728418dcf0b694ddff1263ac4176b9d0ed08de64fdfd527355905eb6f21fb5f3585",
+        point_compressed: "03015024f6d020b18dff2583005e422e2f92df29b6d8fa24d4dd9b8654146305282bf817d5585d2b3c1db53dfe0bebbb45ee579d43cfb6c0ddd93335f5cbeb917b967c",
+        other_point: "04001af49e0a98ea38c8cb9756478125808baeff2d5e134354ba03174a52a7ed40657b099fb35d194b0c0185694f918508ad6396c3cbd099115adac73cbebce2047a9f0011705866b3101f1dba053c56eeae99aec10f7ebfb68c4ded4c49672d889d13f15906d443cdde51d8ff2540a40c7949941513dcfafb3ba278fba04ce12857cc52a9",
+        sum: "04017d12f6765e2a7261d976d1b179015e3ada811cec5c1821163ea3f40b2c03e538618366b848cf383ac81e2cd56090cf74e172c1acef076e52c2e4f3ab21d53be9ed00d27677aa40be4c985d54fa6a9d5f9e0778677b6e5f0b3af229671a2e6f301ee87edee202996e8f8c66e8cd8e544c953cb5036f934e3bb567a38ba2050431226b44",
+        double: "0401623d63043ef2631deb8a61fb6c15c2fe331c695b70a1ccc2b40965781a43267ad0816191f5f993e14d5a18e48b9b41e24a2b8fdd01e40426b77572d5f6c112eefb00541c29d4bf591775611e79ca551b002661864bebf506abd1b1e9f7ff82b80c6c867f366ce69ec7bb0158a366715c011288ce297bb450d91bdfeba5fac17f3879fc",
+        product: "0400e1df5d59ef26d608bc0e0113ac6eeece9ba983c0716f088574beac015ac43bc0227fec6de99a8a37c988648f3815b2aa30b47828a7845144afa8cf7e9e69711487006fb4ad640c236d2d8d751843d81fe71fbd9d4612e5d5e49e328fd02faa0c7b3f7684d0307911f9a94b4d62b8ea7da568486ecaa74296f564a6e2ba67a9936d32fe",
+    },
+];
+
+#[test]
+fn test_elliptic_curve_openssl_vectors() {
+    for vector in ELLIPTIC_CURVE_VECTORS {
+        let ec = EllipticCurve::from_name(vector.curve).unwrap();
+
+        let (x, y) = ec.scalar_base_mult(&hex::decode(EC_SCALAR_K).unwrap());
+        assert_eq!(hex::encode(ec.marshal(&x, &y)), vector.point);
+        assert_eq!(
+            hex::encode(ec.marshal_compressed(&x, &y)),
+            vector.point_compressed
+        );
+        assert_eq!(
+            ec.unmarshal(&hex::decode(vector.point).unwrap()),
+            Some((x.clone(), y.clone()))
+        );
+        assert_eq!(
+            ec.unmarshal_compressed(&hex::decode(vector.point_compressed).unwrap()),
+            Some((x.clone(), y.clone()))
+        );
+
+        let (other_x, other_y) = ec.scalar_base_mult(&hex::decode(EC_SCALAR_L).unwrap());
+        assert_eq!(
+            hex::encode(ec.marshal(&other_x, &other_y)),
+            vector.other_point
+        );
+
+        let (sum_x, sum_y) = ec.add(&x, &y, &other_x, &other_y);
+        assert_eq!(hex::encode(ec.marshal(&sum_x, &sum_y)), vector.sum);
+
+        let (double_x, double_y) = ec.double(&x, &y);
+        assert_eq!(hex::encode(ec.marshal(&double_x, &double_y)), vector.double);
+
+        let (product_x, product_y) = ec.scalar_mult(&x, &y, &hex::decode(EC_SCALAR_M).unwrap());
+        assert_eq!(
+            hex::encode(ec.marshal(&product_x, &product_y)),
+            vector.product
+        );
+    }
+}
+
+#[test]
+fn test_elliptic_curve_unsupported() {
+    assert!(EllipticCurve::from_name(b"p22424").is_none());
+    assert!(EllipticCurve::from_name(b"P256").is_none());
+}
@@ -15,6 +15,13 @@ fn external_steps_rs() {
     );
 }
 
+/// The state written by `dumpState` can be loaded back via `externalSteps`.
+#[test]
+fn dump_state_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/dump-state/dump-state.scen.json", world());
+    dharitri_wasm_debug::denali_rs("tests/denali/dump-state/load-state.scen.json", world());
+}
+
 #[test]
 #[should_panic]
 fn set_account_addr_len_err1_rs() {
@@ -144,7 +151,9 @@ fn set_check_storage_err4_rs() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(
+    expected = "Want: \"str:another-b\". Have: str:value-b\nByte diff at offset 0:\n\tWant: 0x[616e6f746865722d62]\n\tHave: 0x[76616c75652d62]"
+)]
 fn set_check_storage_err5_rs() {
     dharitri_wasm_debug::denali_rs(
         "tests/denali/set-check/set-check-storage.err5.json",
@@ -160,6 +169,41 @@ fn set_check_storage_rs() {
     );
 }
 
+#[test]
+#[should_panic]
+fn set_check_storage_patterns_err1_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/set-check/set-check-storage-patterns.err1.json",
+        world(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_check_storage_patterns_err2_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/set-check/set-check-storage-patterns.err2.json",
+        world(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_check_storage_patterns_err3_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/set-check/set-check-storage-patterns.err3.json",
+        world(),
+    );
+}
+
+#[test]
+fn set_check_storage_patterns_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/set-check/set-check-storage-patterns.scen.json",
+        world(),
+    );
+}
+
 #[test]
 #[should_panic]
 fn set_check_username_err_rs() {
@@ -210,6 +254,16 @@ fn transfer_moax_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/transfer-moax.scen.json", world());
 }
 
+#[test]
+fn bech32_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/bech32.scen.json", world());
+}
+
+#[test]
+fn snapshot_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/snapshot.scen.json", world());
+}
+
 #[test]
 fn transfer_dct_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/transfer-dct.scen.json", world());
@@ -219,3 +273,23 @@ fn transfer_dct_rs() {
 fn validator_reward_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/validatorReward.scen.json", world());
 }
+
+#[test]
+#[should_panic(expected = "unknown gas schedule")]
+fn gas_schedule_unknown_err_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/gas-schedule-unknown.err.json", world());
+}
+
+#[test]
+#[should_panic(expected = "checks gas; set DENALI_GAS_SCHEDULE_DIR to load it")]
+fn gas_schedule_unavailable_err_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/gas-schedule-unavailable.err.json", world());
+}
+
+#[test]
+#[should_panic(expected = "tx fee settlement requires a metered gas schedule")]
+fn tx_fee_settlement_unmetered_err_rs() {
+    let mut world = world();
+    world.set_tx_fee_settlement(true);
+    dharitri_wasm_debug::denali_rs("tests/denali/tx-fee-settlement-unmetered.err.json", world);
+}
@@ -0,0 +1,68 @@
+use dharitri_wasm_debug::world_mock::{GasSchedule, BUILT_IN_COST, DHARITRI_API_COST};
+
+const GAS_SCHEDULE_TOML: &str = r#"
+[BuiltInCost]
+DCTTransfer = 200000
+
+[DharitriAPICost]
+GetCaller = 100
+StorageLoad = 50000
+"#;
+
+const GAS_SCHEDULE_JSON: &str = r#"{
+    "BuiltInCost": {
+        "DCTTransfer": 200000
+    },
+    "DharitriAPICost": {
+        "GetCaller": 100,
+        "StorageLoad": 50000
+    }
+}"#;
+
+fn check_gas_schedule(gas_schedule: &GasSchedule) {
+    assert!(gas_schedule.is_metered());
+    assert_eq!(gas_schedule.get_cost(BUILT_IN_COST, "DCTTransfer"), 200000);
+    assert_eq!(gas_schedule.get_cost(DHARITRI_API_COST, "GetCaller"), 100);
+    assert_eq!(
+        gas_schedule.get_cost(DHARITRI_API_COST, "StorageLoad"),
+        50000
+    );
+    assert_eq!(gas_schedule.get_cost(DHARITRI_API_COST, "GetGasLeft"), 0);
+}
+
+#[test]
+fn test_gas_schedule_from_toml() {
+    let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML).unwrap();
+    check_gas_schedule(&gas_schedule);
+}
+
+#[test]
+fn test_gas_schedule_from_json() {
+    let gas_schedule = GasSchedule::from_json_str(GAS_SCHEDULE_JSON).unwrap();
+    check_gas_schedule(&gas_schedule);
+}
+
+#[test]
+fn test_gas_schedule_zero_and_dummy() {
+    assert!(!GasSchedule::zero().is_metered());
+    assert_eq!(
+        GasSchedule::zero().get_cost(DHARITRI_API_COST, "GetCaller"),
+        0
+    );
+
+    let dummy = GasSchedule::dummy();
+    assert!(dummy.is_metered());
+    assert_eq!(dummy.get_cost(DHARITRI_API_COST, "GetCaller"), 1);
+}
+
+#[test]
+fn test_gas_schedule_negative_cost() {
+    let result = GasSchedule::from_toml_str("[DharitriAPICost]\nGetCaller = -100\n");
+    assert_eq!(result.unwrap_err(), "negative gas cost -100 for GetCaller");
+
+    let result = GasSchedule::from_json_str(r#"{"DharitriAPICost": {"GetCaller": -100}}"#);
+    assert_eq!(
+        result.unwrap_err(),
+        "gas cost -100 for GetCaller is not a non-negative integer"
+    );
+}
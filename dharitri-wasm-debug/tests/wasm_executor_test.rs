@@ -0,0 +1,17 @@
+use dharitri_wasm_debug::*;
+
+// The compiled contracts used here are the test contracts checked in with the multisig example.
+
+fn world() -> BlockchainMock {
+    BlockchainMock::new()
+}
+
+#[test]
+fn wasm_adder() {
+    dharitri_wasm_debug::denali_wasm("tests/denali/wasm-executor/adder.scen.json", world());
+}
+
+#[test]
+fn wasm_factorial() {
+    dharitri_wasm_debug::denali_wasm("tests/denali/wasm-executor/factorial.scen.json", world());
+}
@@ -1,7 +1,13 @@
-use crate::types::BigUint;
+use crate::types::{BigUint, ManagedBuffer};
 
 use super::ManagedTypeApi;
 
+/// Printing only has an effect in the debugger.
+/// The methods with a default implementation do nothing on the VM, so only the debugger needs to implement them.
 pub trait PrintApi: ManagedTypeApi {
     fn print_biguint(&self, biguint: &BigUint<Self>);
+
+    fn print_managed_buffer(&self, _buffer: &ManagedBuffer<Self>) {}
+
+    fn print_fmt(&self, _args: core::fmt::Arguments) {}
 }
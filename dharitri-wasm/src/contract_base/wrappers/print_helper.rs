@@ -1,8 +1,12 @@
+use core::fmt::Debug;
+
 use crate::{
     api::{BlockchainApi, ManagedTypeApi, PrintApi},
-    types::BigUint,
+    hex_util::encode_bytes_as_hex,
+    types::{BigUint, ManagedAddress, ManagedBuffer},
 };
 
+/// Debugging output. Only the debugger records it, it is ignored on the blockchain.
 pub struct PrintHelper<M: ManagedTypeApi> {
     api: M,
 }
@@ -18,4 +22,27 @@ where
     pub fn print_biguint(&self, biguint: &BigUint<M>) {
         self.api.print_biguint(biguint);
     }
+
+    /// Printed as text if it is valid UTF-8, as hex otherwise.
+    pub fn print_managed_buffer(&self, buffer: &ManagedBuffer<M>) {
+        self.api.print_managed_buffer(buffer);
+    }
+
+    /// Printed as hex, prefixed with `0x`.
+    pub fn print_address(&self, address: &ManagedAddress<M>) {
+        self.print_fmt(format_args!(
+            "0x{}",
+            encode_bytes_as_hex(&address.to_byte_array())
+        ));
+    }
+
+    /// Prints any value using its `Debug` representation.
+    pub fn print_value<T: Debug>(&self, value: &T) {
+        self.print_fmt(format_args!("{:?}", value));
+    }
+
+    /// Used as `self.print().print_fmt(format_args!(...))`.
+    pub fn print_fmt(&self, args: core::fmt::Arguments) {
+        self.api.print_fmt(args);
+    }
 }
@@ -1,9 +1,10 @@
 use crate::{
     api::{
         BlockchainApi, ManagedTypeApi, SendApi, StorageReadApi, CHANGE_OWNER_BUILTIN_FUNC_NAME,
-        DCT_LOCAL_BURN_FUNC_NAME, DCT_LOCAL_MINT_FUNC_NAME, DCT_MULTI_TRANSFER_FUNC_NAME,
-        DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_BURN_FUNC_NAME, DCT_NFT_CREATE_FUNC_NAME,
-        DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME,
+        CLAIM_DEVELOPER_REWARDS_FUNC_NAME, DCT_LOCAL_BURN_FUNC_NAME, DCT_LOCAL_MINT_FUNC_NAME,
+        DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_ADD_URI_FUNC_NAME,
+        DCT_NFT_BURN_FUNC_NAME, DCT_NFT_CREATE_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME,
+        DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, DCT_TRANSFER_FUNC_NAME,
     },
     dct::DCTSystemSmartContractProxy,
     types::{
@@ -209,6 +210,19 @@ where
         contract_call
     }
 
+    /// Sends a synchronous call to claim the developer rewards of a smart contract owned by the current contract.
+    /// The rewards are sent to the current contract.
+    pub fn claim_developer_rewards(
+        &self,
+        child_sc_address: ManagedAddress<A>,
+    ) -> ContractCall<A, ()> {
+        ContractCall::new(
+            self.api.clone(),
+            child_sc_address,
+            ManagedBuffer::new_from_bytes(CLAIM_DEVELOPER_REWARDS_FUNC_NAME),
+        )
+    }
+
     /// Allows synchronously calling a local function by name. Execution is resumed afterwards.
     /// You should never have to call this function directly.
     /// Use the other specific methods instead.
@@ -367,6 +381,55 @@ where
         }
     }
 
+    /// Replaces the attributes of an existing NFT/SFT instance held by the SC.
+    /// `attributes` can be any serializable custom struct.
+    /// This is a built-in function, so the smart contract execution is resumed after.
+    /// Must have DCTRoleNFTUpdateAttributes role set, or this will fail with "action is not allowed".
+    pub fn dct_nft_update_attributes<T: dharitri_codec::TopEncode>(
+        &self,
+        token: &TokenIdentifier<A>,
+        nft_nonce: u64,
+        new_attributes: &T,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new_empty();
+        arg_buffer.push_arg(token);
+        arg_buffer.push_arg(nft_nonce);
+        arg_buffer.push_arg(new_attributes);
+
+        let _ = self.call_local_dct_built_in_function(
+            self.api.get_gas_left(),
+            &ManagedBuffer::new_from_bytes(DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
+    /// Adds URIs to an existing NFT/SFT instance held by the SC.
+    /// This is a built-in function, so the smart contract execution is resumed after.
+    /// Must have DCTRoleNFTAddURI role set, or this will fail with "action is not allowed".
+    pub fn dct_nft_add_uris(
+        &self,
+        token: &TokenIdentifier<A>,
+        nft_nonce: u64,
+        new_uris: &ManagedVec<A, ManagedBuffer<A>>,
+    ) {
+        if new_uris.is_empty() {
+            return;
+        }
+
+        let mut arg_buffer = ManagedArgBuffer::new_empty();
+        arg_buffer.push_arg(token);
+        arg_buffer.push_arg(nft_nonce);
+        for uri in new_uris {
+            arg_buffer.push_arg(uri);
+        }
+
+        let _ = self.call_local_dct_built_in_function(
+            self.api.get_gas_left(),
+            &ManagedBuffer::new_from_bytes(DCT_NFT_ADD_URI_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
     /// Sends thr NFTs to the buyer address and calculates and sends the required royalties to the NFT creator.
     /// Returns the payment amount left after sending royalties.
     #[allow(clippy::too_many_arguments)]
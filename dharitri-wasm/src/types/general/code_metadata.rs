@@ -5,7 +5,7 @@ use dharitri_codec::*;
 
 /// Flags concerning smart contract creation and upgrade.
 /// Currently always represented as a 2-byte bitfield.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct CodeMetadata([u8; 2]);
 
 const METADATA_UPGRADEABLE_BYTE: usize = 0;
@@ -14,12 +14,15 @@ const METADATA_PAYABLE_BYTE: usize = 1;
 const METADATA_PAYABLE_MASK: u8 = 2;
 const METADATA_READABLE_BYTE: usize = 0;
 const METADATA_READABLE_MASK: u8 = 4;
+const METADATA_PAYABLE_BY_SC_BYTE: usize = 1;
+const METADATA_PAYABLE_BY_SC_MASK: u8 = 4;
 
 impl CodeMetadata {
     pub const DEFAULT: CodeMetadata = CodeMetadata([0, 0]);
     pub const UPGRADEABLE: CodeMetadata = CodeMetadata([METADATA_UPGRADEABLE_MASK, 0]);
     pub const PAYABLE: CodeMetadata = CodeMetadata([0, METADATA_PAYABLE_MASK]);
     pub const READABLE: CodeMetadata = CodeMetadata([METADATA_READABLE_MASK, 0]);
+    pub const PAYABLE_BY_SC: CodeMetadata = CodeMetadata([0, METADATA_PAYABLE_BY_SC_MASK]);
 
     pub fn is_upgradeable(&self) -> bool {
         self.0[METADATA_UPGRADEABLE_BYTE] & METADATA_UPGRADEABLE_MASK > 0
@@ -33,6 +36,11 @@ impl CodeMetadata {
         self.0[METADATA_READABLE_BYTE] & METADATA_READABLE_MASK > 0
     }
 
+    /// Payable only by other smart contracts, not by user accounts.
+    pub fn is_payable_by_sc(&self) -> bool {
+        self.0[METADATA_PAYABLE_BY_SC_BYTE] & METADATA_PAYABLE_BY_SC_MASK > 0
+    }
+
     pub fn from_flags(upgradeable: bool, payable: bool, readable: bool) -> CodeMetadata {
         let mut code_metadata = CodeMetadata::DEFAULT;
         if upgradeable {
@@ -184,6 +192,7 @@ mod tests {
         assert!(!CodeMetadata::DEFAULT.is_upgradeable());
         assert!(!CodeMetadata::DEFAULT.is_payable());
         assert!(!CodeMetadata::DEFAULT.is_readable());
+        assert!(!CodeMetadata::DEFAULT.is_payable_by_sc());
     }
 
     #[test]
@@ -191,6 +200,8 @@ mod tests {
         assert!(CodeMetadata::UPGRADEABLE.is_upgradeable());
         assert!(CodeMetadata::PAYABLE.is_payable());
         assert!(CodeMetadata::READABLE.is_readable());
+        assert!(CodeMetadata::PAYABLE_BY_SC.is_payable_by_sc());
+        assert!(!CodeMetadata::PAYABLE_BY_SC.is_payable());
     }
 
     #[test]
@@ -207,6 +218,7 @@ mod tests {
         assert!(CodeMetadata::from([1, 0]).is_upgradeable());
         assert!(!CodeMetadata::from([1, 0]).is_readable());
         assert!(CodeMetadata::from([0, 2]).is_payable());
+        assert!(CodeMetadata::from([0, 4]).is_payable_by_sc());
         assert!(CodeMetadata::from([4, 0]).is_readable());
         assert!(!CodeMetadata::from([4, 0]).is_upgradeable());
         assert!(!CodeMetadata::from([0, 0]).is_upgradeable());